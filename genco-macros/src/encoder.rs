@@ -32,6 +32,9 @@ pub(crate) struct Encoder<'a> {
     last_start_column: Option<usize>,
     /// Indentation columns.
     indents: Vec<(usize, Option<Span>)>,
+    /// Indicates that the current line so far only consists of conditionals,
+    /// in which case spacing is only emitted if they produced any tokens.
+    conditional_line: bool,
     /// Indicates if the encoder has encountered a string which requires eval
     /// support in the target language.
     pub(crate) requirements: Requirements,
@@ -52,13 +55,22 @@ impl<'a> Encoder<'a> {
             last: None,
             last_start_column: None,
             indents: Vec::new(),
+            conditional_line: false,
             requirements: Requirements::default(),
         }
     }
 
     /// Encode a single item into the encoder.
     pub(crate) fn encode(&mut self, cursor: Cursor, ast: Ast) -> Result<()> {
-        self.step(cursor)?;
+        let line_start = self.step(cursor)?;
+
+        if let Ast::Condition { .. } = &ast {
+            if line_start {
+                self.encode_conditional_line();
+            }
+        } else {
+            self.conditional_line = false;
+        }
 
         match ast {
             Ast::Tree { tt, .. } => {
@@ -130,16 +142,35 @@ impl<'a> Encoder<'a> {
         Ok((self.requirements, self.output))
     }
 
-    pub(crate) fn step(&mut self, next: Cursor) -> Result<()> {
-        if let Some(from) = self.from() {
+    /// Step to the next cursor, returning `true` if it's at the start of a
+    /// line.
+    pub(crate) fn step(&mut self, next: Cursor) -> Result<bool> {
+        let line_start = if let Some(from) = self.from() {
             // Insert spacing if appropriate.
             self.tokenize_whitespace(from, next.start, Some(next.span))?;
-        }
+            from.line != next.start.line
+        } else {
+            true
+        };
 
         // Assign the current cursor to the next item.
         // This will then be used to make future indentation decisions.
         self.last = Some(next);
-        Ok(())
+        Ok(line_start)
+    }
+
+    /// Mark the start of a line which starts with a conditional.
+    ///
+    /// If the conditional doesn't produce anything, the spacing which follows
+    /// it would otherwise end up at the start of the line.
+    fn encode_conditional_line(&mut self) {
+        let Ctxt { receiver, .. } = self.cx;
+
+        self.item_buffer.flush(&mut self.output);
+        self.output.extend(q::quote! {
+            let __genco_line_start = #receiver.len();
+        });
+        self.conditional_line = true;
     }
 
     pub(crate) fn encode_open_delimiter(&mut self, d: Delimiter) {
@@ -392,7 +423,16 @@ impl<'a> Encoder<'a> {
             // Same line, but next item doesn't match.
             if from.column < to.column {
                 self.item_buffer.flush(&mut self.output);
-                self.output.extend(q::quote!(#r.space();));
+
+                if self.conditional_line {
+                    self.output.extend(q::quote! {
+                        if #r.len() != __genco_line_start {
+                            #r.space();
+                        }
+                    });
+                } else {
+                    self.output.extend(q::quote!(#r.space();));
+                }
             }
 
             return Ok(());
@@ -401,6 +441,7 @@ impl<'a> Encoder<'a> {
         // Line changed. Determine whether to indent, unindent, or hard break the
         // line.
         self.item_buffer.flush(&mut self.output);
+        self.conditional_line = false;
 
         debug_assert!(from.line < to.line);
 
@@ -0,0 +1,112 @@
+use crate::fmt;
+use std::io;
+
+/// Helper struct to format a token stream to an underlying writer implementing
+/// [io::Write][std::io::Write], while buffering writes.
+///
+/// Formatting performs many small writes, which is slow if each of them is
+/// forwarded directly to something like a [File][std::fs::File]. This writer
+/// batches them in an internal buffer, which is flushed when it's full, when
+/// [flush][BufIoWriter::flush] is called, or when the writer is dropped.
+///
+/// Note that errors which happen while flushing on drop are ignored, so prefer
+/// to call [flush][BufIoWriter::flush] or [into_inner][BufIoWriter::into_inner]
+/// explicitly.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+/// use genco::fmt;
+///
+/// let map = rust::import("std::collections", "HashMap");
+///
+/// let tokens: rust::Tokens = quote! {
+///     let mut m = $map::new();
+///     m.insert(1u32, 2u32);
+/// };
+///
+/// let mut w = fmt::BufIoWriter::with_capacity(Vec::<u8>::new(), 4096);
+///
+/// let fmt = fmt::Config::from_lang::<Rust>();
+/// let config = rust::Config::default();
+///
+/// tokens.format_file(&mut w.as_formatter(&fmt), &config)?;
+///
+/// let vector = w.into_inner()?;
+/// let string = std::str::from_utf8(&vector)?;
+///
+/// assert_eq!("use std::collections::HashMap;\n\nlet mut m = HashMap::new();\nm.insert(1u32, 2u32);\n", string);
+/// # Ok::<_, anyhow::Error>(())
+/// ```
+pub struct BufIoWriter<W>
+where
+    W: io::Write,
+{
+    writer: io::BufWriter<W>,
+}
+
+impl<W> BufIoWriter<W>
+where
+    W: io::Write,
+{
+    /// Construct a new buffered writer from the underlying writer, using the
+    /// default buffer capacity of [BufWriter][std::io::BufWriter].
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer: io::BufWriter::new(writer),
+        }
+    }
+
+    /// Construct a new buffered writer from the underlying writer, with a
+    /// buffer of at least the given capacity.
+    pub fn with_capacity(writer: W, capacity: usize) -> Self {
+        Self {
+            writer: io::BufWriter::with_capacity(capacity, writer),
+        }
+    }
+
+    /// Convert into a formatter.
+    pub fn as_formatter<'a>(&'a mut self, config: &'a fmt::Config) -> fmt::Formatter<'a> {
+        fmt::Formatter::new(self, config)
+    }
+
+    /// Flush any buffered output to the underlying writer.
+    pub fn flush(&mut self) -> io::Result<()> {
+        io::Write::flush(&mut self.writer)
+    }
+
+    /// Flush any buffered output and convert into the inner writer.
+    pub fn into_inner(self) -> io::Result<W> {
+        self.writer
+            .into_inner()
+            .map_err(io::IntoInnerError::into_error)
+    }
+}
+
+impl<W> std::fmt::Write for BufIoWriter<W>
+where
+    W: io::Write,
+{
+    #[inline(always)]
+    fn write_char(&mut self, c: char) -> std::fmt::Result {
+        io::Write::write_all(&mut self.writer, c.encode_utf8(&mut [0; 4]).as_bytes())
+            .map_err(|_| std::fmt::Error)
+    }
+
+    #[inline(always)]
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        io::Write::write_all(&mut self.writer, s.as_bytes()).map_err(|_| std::fmt::Error)
+    }
+}
+
+impl<W> fmt::Write for BufIoWriter<W>
+where
+    W: io::Write,
+{
+    #[inline(always)]
+    fn write_line(&mut self, config: &fmt::Config) -> fmt::Result {
+        io::Write::write_all(&mut self.writer, config.newline.as_bytes())
+            .map_err(|_| fmt::Error::Fmt)
+    }
+}
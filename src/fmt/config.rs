@@ -2,6 +2,13 @@ use crate::lang::Lang;
 
 /// Indentation configuration.
 ///
+/// The [quote!][crate::quote] macro only detects the structure of the
+/// template, where each increase in indentation is one nesting level
+/// regardless of whether it's made up of spaces or tabs, or how wide it is.
+/// Every nesting level is then rendered as configured here, so
+/// [Indentation::Tab] renders exactly one literal tab for each level. This is
+/// useful for formats which require tabs, like the recipes of a Makefile.
+///
 /// ```
 /// use genco::prelude::*;
 /// use genco::fmt;
@@ -38,6 +45,151 @@ pub enum Indentation {
     Tab,
 }
 
+/// The line ending to use in output.
+///
+/// This only affects line endings written by the formatter between lines of
+/// output. Line endings which are part of literal tokens are written as-is.
+/// Writers which collect lines, like [VecWriter], split on logical lines
+/// regardless of the line ending.
+///
+/// [VecWriter]: crate::fmt::VecWriter
+///
+/// ```
+/// use genco::prelude::*;
+/// use genco::fmt;
+///
+/// let tokens: csharp::Tokens = quote! {
+///     class Foo {
+///         int a;
+///
+///         int b;
+///     }
+/// };
+///
+/// let mut w = fmt::FmtWriter::new(String::new());
+///
+/// let fmt = fmt::Config::from_lang::<Csharp>()
+///     .with_line_ending(fmt::LineEnding::CrLf);
+/// let config = csharp::Config::default();
+///
+/// tokens.format_file(&mut w.as_formatter(&fmt), &config)?;
+///
+/// assert_eq! {
+///     "class Foo {\r\n    int a;\r\n\r\n    int b;\r\n}\r\n",
+///     w.into_inner(),
+/// };
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LineEnding {
+    /// Lines end with `\n`.
+    Lf,
+    /// Lines end with `\r\n`, as is common on Windows.
+    CrLf,
+}
+
+impl LineEnding {
+    /// Get the string representation of the line ending.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Lf => "\n",
+            Self::CrLf => "\r\n",
+        }
+    }
+}
+
+/// The delimiter to use for quoted strings.
+///
+/// Only languages which support more than one kind of string delimiter, like
+/// Python, JavaScript or Dart, take this into account. Every other language
+/// ignores it and uses its only valid delimiter.
+///
+/// ```
+/// use genco::prelude::*;
+/// use genco::fmt;
+///
+/// let tokens: python::Tokens = quote!(print($(quoted("it's"))));
+///
+/// let mut w = fmt::VecWriter::new();
+///
+/// let fmt = fmt::Config::from_lang::<Python>()
+///     .with_quote_style(fmt::QuoteStyle::Single);
+/// let config = python::Config::default();
+///
+/// tokens.format_file(&mut w.as_formatter(&fmt), &config)?;
+///
+/// assert_eq! {
+///     vec!["print('it\\'s')"],
+///     w.into_vec(),
+/// };
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuoteStyle {
+    /// Strings are delimited with double quotes, like `"hello"`.
+    Double,
+    /// Strings are delimited with single quotes, like `'hello'`.
+    Single,
+}
+
+impl QuoteStyle {
+    /// Get the delimiter character of the quote style.
+    pub fn as_char(self) -> char {
+        match self {
+            Self::Double => '"',
+            Self::Single => '\'',
+        }
+    }
+}
+
+/// The syntax to use for editor region markers.
+///
+/// These are emitted by [tokens::region()], and are rendered as line comments
+/// using [Lang::line_comment_prefix], or as block comments in languages
+/// without line comments.
+///
+/// [tokens::region()]: crate::tokens::region()
+///
+/// ```
+/// use genco::prelude::*;
+/// use genco::fmt;
+/// use genco::tokens::region;
+///
+/// let tokens: java::Tokens = quote! {
+///     $(region("Generated", quote!(int a = 1;)))
+/// };
+///
+/// let mut w = fmt::VecWriter::new();
+///
+/// let fmt = fmt::Config::from_lang::<Java>()
+///     .with_region_style(fmt::RegionStyle::EditorFold);
+/// let config = java::Config::default();
+///
+/// tokens.format_file(&mut w.as_formatter(&fmt), &config)?;
+///
+/// assert_eq! {
+///     vec![
+///         "// <editor-fold desc=\"Generated\">",
+///         "int a = 1;",
+///         "// </editor-fold>",
+///     ],
+///     w.into_vec(),
+/// };
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RegionStyle {
+    /// Region markers as understood by Visual Studio and Visual Studio Code,
+    /// like `// #region label` and `// #endregion`.
+    Region,
+    /// Region markers as understood by IntelliJ, like
+    /// `// <editor-fold desc="label">` and `// </editor-fold>`.
+    ///
+    /// Quotes, `&`, `<` and line breaks in the label are escaped as XML
+    /// character references, so that the marker stays on a single line.
+    EditorFold,
+}
+
 /// Configuration to use for formatting output.
 #[derive(Debug, Clone)]
 pub struct Config {
@@ -45,18 +197,68 @@ pub struct Config {
     pub(super) indentation: Indentation,
     /// What to use as a newline.
     pub(super) newline: &'static str,
+    /// If files should end with a newline.
+    pub(super) trailing_newline: bool,
+    /// The delimiter to use for quoted strings.
+    pub(super) quote_style: QuoteStyle,
+    /// The syntax to use for editor region markers.
+    pub(super) region_style: RegionStyle,
+    /// The maximum width of a line, if any.
+    pub(super) max_width: Option<usize>,
+    /// The number of columns between tab stops.
+    pub(super) tab_width: usize,
+    /// The maximum number of consecutive empty lines.
+    pub(super) max_blank_lines: usize,
+    /// The maximum nesting depth, if any.
+    pub(super) max_depth: Option<usize>,
+    /// If line breaks and indentation should be collapsed into spaces.
+    pub(super) minify: bool,
 }
 
 impl Config {
     /// Construct a new default formatter configuration for the specified
     /// language.
+    ///
+    /// This picks the idiomatic indentation, newline and string delimiter for
+    /// the language, as provided by [Lang::default_indentation],
+    /// [Lang::default_newline] and [Lang::default_quote_style]. Each of them
+    /// can be overriden afterwards.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    /// use genco::fmt;
+    ///
+    /// let tokens: js::Tokens = quote!(console.log($(quoted("hello"))));
+    ///
+    /// let fmt = fmt::Config::from_lang::<JavaScript>();
+    /// let mut w = fmt::VecWriter::new();
+    /// tokens.format_file(&mut w.as_formatter(&fmt), &js::Config::default())?;
+    /// assert_eq!(vec!["console.log(\"hello\")"], w.into_vec());
+    ///
+    /// let fmt = fmt::Config::from_lang::<JavaScript>()
+    ///     .with_quote_style(fmt::QuoteStyle::Single);
+    /// let mut w = fmt::VecWriter::new();
+    /// tokens.format_file(&mut w.as_formatter(&fmt), &js::Config::default())?;
+    /// assert_eq!(vec!["console.log('hello')"], w.into_vec());
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
     pub fn from_lang<L>() -> Self
     where
         L: Lang,
     {
         Self {
             indentation: L::default_indentation(),
-            newline: "\n",
+            newline: L::default_newline(),
+            trailing_newline: true,
+            quote_style: L::default_quote_style(),
+            region_style: RegionStyle::Region,
+            max_width: None,
+            tab_width: 4,
+            max_blank_lines: usize::MAX,
+            max_depth: None,
+            minify: false,
         }
     }
 
@@ -72,4 +274,312 @@ impl Config {
     pub fn with_newline(self, newline: &'static str) -> Self {
         Self { newline, ..self }
     }
+
+    /// Set the line ending to use.
+    ///
+    /// This is the same as calling [with_newline][Self::with_newline] with
+    /// the string representation of the line ending. See [LineEnding] for
+    /// details.
+    pub fn with_line_ending(self, line_ending: LineEnding) -> Self {
+        self.with_newline(line_ending.as_str())
+    }
+
+    /// Set if formatted files should end with a newline.
+    ///
+    /// This only affects [format_file], which by default ends the last line
+    /// of the file with the configured newline. Writers which produce a
+    /// vector of lines, like [VecWriter][crate::fmt::VecWriter], don't
+    /// include line endings and are not affected.
+    ///
+    /// [format_file]: crate::Tokens::format_file
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    /// use genco::fmt;
+    ///
+    /// let tokens: rust::Tokens = quote! {
+    ///     fn main() {}
+    /// };
+    ///
+    /// let fmt = fmt::Config::from_lang::<Rust>();
+    /// let mut w = fmt::FmtWriter::new(String::new());
+    /// tokens.format_file(&mut w.as_formatter(&fmt), &rust::Config::default())?;
+    /// assert_eq!("fn main() {}\n", w.into_inner());
+    ///
+    /// let fmt = fmt::Config::from_lang::<Rust>().with_trailing_newline(false);
+    /// let mut w = fmt::FmtWriter::new(String::new());
+    /// tokens.format_file(&mut w.as_formatter(&fmt), &rust::Config::default())?;
+    /// assert_eq!("fn main() {}", w.into_inner());
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn with_trailing_newline(self, trailing_newline: bool) -> Self {
+        Self {
+            trailing_newline,
+            ..self
+        }
+    }
+
+    /// Set the delimiter to use for quoted strings.
+    ///
+    /// Languages which only support one kind of string delimiter ignore this.
+    pub fn with_quote_style(self, quote_style: QuoteStyle) -> Self {
+        Self {
+            quote_style,
+            ..self
+        }
+    }
+
+    /// Set the maximum width of a line.
+    ///
+    /// Constructs which know how to wrap themselves, like grouped Rust
+    /// imports, are split over multiple lines if they would otherwise exceed
+    /// this width. So are [soft breaks] in the token stream. By default there
+    /// is no maximum width.
+    ///
+    /// [soft breaks]: crate::Tokens::soft_break
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    /// use genco::fmt;
+    ///
+    /// let a = rust::import("std::collections", "BTreeMap");
+    /// let b = rust::import("std::collections", "HashMap");
+    ///
+    /// let tokens: rust::Tokens = quote!($a $b);
+    ///
+    /// let mut w = fmt::VecWriter::new();
+    ///
+    /// let fmt = fmt::Config::from_lang::<Rust>().with_max_width(40);
+    /// let config = rust::Config::default();
+    ///
+    /// tokens.format_file(&mut w.as_formatter(&fmt), &config)?;
+    ///
+    /// assert_eq! {
+    ///     vec![
+    ///         "use std::collections::{",
+    ///         "    BTreeMap,",
+    ///         "    HashMap,",
+    ///         "};",
+    ///         "",
+    ///         "BTreeMap HashMap",
+    ///     ],
+    ///     w.into_vec(),
+    /// };
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn with_max_width(self, max_width: usize) -> Self {
+        Self {
+            max_width: Some(max_width),
+            ..self
+        }
+    }
+
+    /// Set the number of columns between tab stops. Defaults to `4`.
+    ///
+    /// This doesn't change what's written, since tabs are always written as
+    /// tab characters. It's only used to calculate the width of a line for
+    /// [with_max_width][Self::with_max_width], where each level of
+    /// [Indentation::Tab] and each tab character advances to the next tab
+    /// stop. Indentation is always written using only tabs or only spaces,
+    /// as configured through [with_indentation][Self::with_indentation].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    /// use genco::fmt;
+    /// use genco::tokens::soft_break;
+    ///
+    /// let tokens: rust::Tokens = quote! {
+    ///     fn run() {
+    ///         call(long_argument,$(soft_break())other);
+    ///     }
+    /// };
+    ///
+    /// let fmt = fmt::Config::from_lang::<Rust>()
+    ///     .with_indentation(fmt::Indentation::Tab)
+    ///     .with_max_width(32);
+    ///
+    /// let mut w = fmt::VecWriter::new();
+    /// tokens.format(&mut w.as_formatter(&fmt), &rust::Config::default(), &rust::Format::default())?;
+    /// assert_eq!(vec!["fn run() {", "\tcall(long_argument, other);", "}"], w.into_vec());
+    ///
+    /// let fmt = fmt.with_tab_width(8);
+    ///
+    /// let mut w = fmt::VecWriter::new();
+    /// tokens.format(&mut w.as_formatter(&fmt), &rust::Config::default(), &rust::Format::default())?;
+    /// assert_eq!(vec!["fn run() {", "\tcall(long_argument,", "\t\tother);", "}"], w.into_vec());
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn with_tab_width(self, tab_width: usize) -> Self {
+        Self { tab_width, ..self }
+    }
+
+    /// Set the maximum number of consecutive empty lines. By default this
+    /// is not limited.
+    ///
+    /// Runs of [line][crate::Tokens::line] operations are always collapsed,
+    /// even if they come from different token streams, so they produce at
+    /// most one empty line. More empty lines in a row come from blank lines
+    /// which are added explicitly, like the ones preserved by
+    /// [append_verbatim][crate::Tokens::append_verbatim]. The limit applies
+    /// to both combined, so no more than `max_blank_lines` empty lines are
+    /// ever written in a row. Setting it to `0` removes empty lines entirely,
+    /// which turns every line operation into a plain line break.
+    ///
+    /// Empty lines are never written at the start of the output, and pending
+    /// empty lines at the end of a file are dropped in favor of a single
+    /// trailing newline. Line breaks which are part of literals are written
+    /// as they are.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    /// use genco::fmt;
+    ///
+    /// let mut tokens = rust::Tokens::new();
+    /// tokens.line();
+    /// tokens.append("fn a() {}");
+    /// tokens.line();
+    /// tokens.line();
+    /// tokens.append("fn b() {}");
+    /// tokens.append_verbatim("\n\n\nfn c() {}");
+    ///
+    /// let render = |fmt: &fmt::Config| -> fmt::Result<Vec<String>> {
+    ///     let mut w = fmt::VecWriter::new();
+    ///     tokens.format_file(&mut w.as_formatter(fmt), &rust::Config::default())?;
+    ///     Ok(w.into_vec())
+    /// };
+    ///
+    /// let fmt = fmt::Config::from_lang::<Rust>();
+    /// assert_eq!(vec!["fn a() {}", "", "fn b() {}", "", "", "", "fn c() {}"], render(&fmt)?);
+    ///
+    /// let fmt = fmt.with_max_blank_lines(2);
+    /// assert_eq!(vec!["fn a() {}", "", "fn b() {}", "", "", "fn c() {}"], render(&fmt)?);
+    ///
+    /// let fmt = fmt.with_max_blank_lines(0);
+    /// assert_eq!(vec!["fn a() {}", "fn b() {}", "fn c() {}"], render(&fmt)?);
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn with_max_blank_lines(self, max_blank_lines: usize) -> Self {
+        Self {
+            max_blank_lines,
+            ..self
+        }
+    }
+
+    /// Set the maximum nesting depth permitted while formatting.
+    ///
+    /// Nesting counts both levels of indentation, like nested blocks, and
+    /// nested token streams which are produced at format time or formatted
+    /// by language items. If the limit is exceeded, formatting fails with
+    /// [Error::DepthExceeded] instead of producing unbounded output. This is
+    /// useful for generators which process untrusted input. By default there
+    /// is no limit.
+    ///
+    /// [Error::DepthExceeded]: crate::fmt::Error::DepthExceeded
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    /// use genco::fmt;
+    ///
+    /// fn nested(depth: usize) -> rust::Tokens {
+    ///     if depth == 0 {
+    ///         return quote!(value);
+    ///     }
+    ///
+    ///     quote! {
+    ///         {
+    ///             $(nested(depth - 1))
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let fmt = fmt::Config::from_lang::<Rust>().with_max_depth(2);
+    /// let config = rust::Config::default();
+    ///
+    /// let mut w = fmt::VecWriter::new();
+    /// nested(2).format_file(&mut w.as_formatter(&fmt), &config)?;
+    /// assert_eq!(vec!["{", "    {", "        value", "    }", "}"], w.into_vec());
+    ///
+    /// let mut w = fmt::VecWriter::new();
+    /// let result = nested(3).format_file(&mut w.as_formatter(&fmt), &config);
+    /// assert_eq!(Err(fmt::Error::DepthExceeded), result);
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn with_max_depth(self, max_depth: usize) -> Self {
+        Self {
+            max_depth: Some(max_depth),
+            ..self
+        }
+    }
+
+    /// Set if output should be minified. Defaults to `false`.
+    ///
+    /// When enabled, the formatter writes no indentation, and every line
+    /// break in the token stream, including empty lines and the ones
+    /// introduced by nesting, is collapsed into a single space. Spaces are
+    /// still written where the token stream asks for them, and line breaks
+    /// which are part of literals, like the content of strings, are written
+    /// as they are. Editor region markers are not written at all. This can
+    /// be combined with [with_trailing_newline][Self::with_trailing_newline]
+    /// to produce output on a single line.
+    ///
+    /// Comments produced by [line_comment][crate::tokens::line_comment()]
+    /// and [trailing_comment][crate::tokens::trailing_comment()] are dropped,
+    /// since a line comment runs until the end of the line and would swallow
+    /// whatever follows it. Comments written as plain literals can't be told
+    /// apart from other code, so they should be left out of token streams
+    /// which are minified. The same goes for languages where line breaks are
+    /// significant, like Python or YAML.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    /// use genco::fmt;
+    ///
+    /// let tokens: js::Tokens = quote! {
+    ///     function greet(name) {
+    ///         if (name) {
+    ///             return $(quoted("Hello\n")) + name;
+    ///         }
+    ///
+    ///         return null;
+    ///     }
+    /// };
+    ///
+    /// let fmt = fmt::Config::from_lang::<JavaScript>()
+    ///     .with_minify(true)
+    ///     .with_trailing_newline(false);
+    ///
+    /// let mut w = fmt::FmtWriter::new(String::new());
+    /// tokens.format_file(&mut w.as_formatter(&fmt), &js::Config::default())?;
+    ///
+    /// assert_eq!(
+    ///     "function greet(name) { if (name) { return \"Hello\\n\" + name; } return null; }",
+    ///     w.into_inner(),
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn with_minify(self, minify: bool) -> Self {
+        Self { minify, ..self }
+    }
+
+    /// Set the syntax to use for editor region markers.
+    ///
+    /// This defaults to [RegionStyle::Region].
+    pub fn with_region_style(self, region_style: RegionStyle) -> Self {
+        Self {
+            region_style,
+            ..self
+        }
+    }
 }
@@ -0,0 +1,51 @@
+use std::error;
+use std::fmt;
+
+/// Error for the `fmt` module.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Error {
+    /// Writing to the underlying output failed, or the token stream is
+    /// malformed.
+    Fmt,
+    /// Formatting nested deeper than the limit configured with
+    /// [Config::with_max_depth][super::Config::with_max_depth].
+    DepthExceeded,
+    /// A quoted string contains a character which can't be represented in a
+    /// string literal of the language, or a name contains a character which
+    /// isn't allowed in it, like whitespace in an HTML attribute name.
+    UnsupportedChar {
+        /// The character which couldn't be represented.
+        character: char,
+        /// The byte offset of the character in the string being quoted. For
+        /// interpolated strings, this is relative to the literal part of the
+        /// string which contains it.
+        index: usize,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Fmt => write!(f, "formatting failed"),
+            Error::DepthExceeded => write!(f, "maximum nesting depth exceeded"),
+            Error::UnsupportedChar { character, index } => {
+                write!(f, "unsupported character {character:?} at index {index}")
+            }
+        }
+    }
+}
+
+impl error::Error for Error {}
+
+impl From<fmt::Error> for Error {
+    fn from(_: fmt::Error) -> Self {
+        Error::Fmt
+    }
+}
+
+impl From<Error> for fmt::Error {
+    fn from(_: Error) -> Self {
+        fmt::Error
+    }
+}
@@ -32,6 +32,34 @@ use crate::fmt;
 /// assert_eq!("let mut m = HashMap::new();\nm.insert(1u32, 2u32);", string);
 /// # Ok::<_, genco::fmt::Error>(())
 /// ```
+///
+/// Since [std::fmt::Formatter] also implements [fmt::Write][std::fmt::Write],
+/// this can be used to write tokens from a [Display][std::fmt::Display]
+/// implementation:
+///
+/// ```
+/// use genco::prelude::*;
+/// use genco::fmt;
+///
+/// struct Function(&'static str);
+///
+/// impl std::fmt::Display for Function {
+///     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+///         let tokens: rust::Tokens = quote! {
+///             fn $(self.0)() {
+///                 todo!()
+///             }
+///         };
+///
+///         let mut w = fmt::FmtWriter::new(f);
+///         let fmt = fmt::Config::from_lang::<Rust>();
+///         tokens.format_file(&mut w.as_formatter(&fmt), &rust::Config::default())?;
+///         Ok(())
+///     }
+/// }
+///
+/// assert_eq!("fn run() {\n    todo!()\n}\n", Function("run").to_string());
+/// ```
 pub struct FmtWriter<W>
 where
     W: std::fmt::Write,
@@ -80,6 +108,7 @@ where
 {
     #[inline(always)]
     fn write_line(&mut self, config: &fmt::Config) -> fmt::Result {
-        self.writer.write_str(config.newline)
+        self.writer.write_str(config.newline)?;
+        Ok(())
     }
 }
@@ -1,9 +1,11 @@
 use crate::fmt;
-use crate::fmt::config::{Config, Indentation};
+use crate::fmt::config::{Config, Indentation, RegionStyle};
 use crate::fmt::cursor;
+use crate::fmt::SourceMap;
 use crate::lang::Lang;
-use crate::tokens::Item;
+use crate::tokens::{Item, SourceLocation};
 
+use std::convert::TryFrom as _;
 use std::mem;
 
 /// Buffer used as indentation source.
@@ -35,6 +37,7 @@ impl Whitespace {
     }
 }
 
+#[allow(clippy::derivable_impls)]
 impl Default for Whitespace {
     fn default() -> Self {
         Self::None
@@ -56,8 +59,29 @@ pub struct Formatter<'a> {
     /// This will only be realized if we push non-whitespace, and will be reset
     /// if a new line is pushed or indentation changes.
     spaces: usize,
+    /// If a soft break is pending. It's realized either as a space or a new
+    /// line once we know what the next non-whitespace is.
+    soft_break: bool,
+    /// If the current line is a continuation line produced by a soft break,
+    /// which is indented one extra level.
+    continuation: bool,
+    /// The current column of output.
+    column: usize,
     /// Current indentation level.
     indent: i16,
+    /// Current nesting of token streams produced at format time.
+    nested: usize,
+    /// Current nesting of interpolated expressions in quoted strings.
+    string_evals: usize,
+    /// Number of blank lines to write before the next line, in addition to
+    /// the pending line breaks.
+    blank_lines: usize,
+    /// The current line of output, starting at 1.
+    current_line: usize,
+    /// Source map to populate, if any.
+    source_map: Option<&'a mut SourceMap>,
+    /// Stack of active source locations.
+    sources: Vec<SourceLocation>,
 }
 
 impl<'a> Formatter<'a> {
@@ -67,11 +91,56 @@ impl<'a> Formatter<'a> {
             write,
             line: Whitespace::Initial,
             spaces: 0usize,
+            soft_break: false,
+            continuation: false,
+            column: 0,
             indent: 0i16,
+            nested: 0,
+            string_evals: 0,
+            blank_lines: 0,
             config,
+            current_line: 1,
+            source_map: None,
+            sources: Vec::new(),
         }
     }
 
+    /// Populate the given source map while formatting, recording which
+    /// [SourceLocation] produced each line of output.
+    ///
+    /// Source locations are attached to tokens with
+    /// [Tokens::append_with_source][crate::Tokens::append_with_source].
+    pub fn with_source_map(self, source_map: &'a mut SourceMap) -> Self {
+        Self {
+            source_map: Some(source_map),
+            ..self
+        }
+    }
+
+    /// Get the delimiter which should be used for quoted strings.
+    ///
+    /// This is used by languages which support more than one kind of string
+    /// delimiter.
+    pub fn quote_style(&self) -> fmt::QuoteStyle {
+        self.config.quote_style
+    }
+
+    /// Test if the formatter is inside of an interpolated expression in a
+    /// quoted string.
+    ///
+    /// This is used by languages which write strings nested inside of such
+    /// expressions differently, see [Lang::write_nested_quoted].
+    pub fn in_string_eval(&self) -> bool {
+        self.string_evals > 0
+    }
+
+    /// Get the maximum width of a line, if one is configured.
+    ///
+    /// See [Config::with_max_width][crate::fmt::Config::with_max_width].
+    pub fn max_width(&self) -> Option<usize> {
+        self.config.max_width
+    }
+
     /// Format the given stream of tokens.
     pub(crate) fn format_items<L>(
         &mut self,
@@ -82,31 +151,102 @@ impl<'a> Formatter<'a> {
     where
         L: Lang,
     {
+        // Token streams formatted by language items are nested, which is
+        // only known once they are formatted.
+        self.check_depth()?;
         let mut cursor = cursor::Cursor::new(items);
         self.format_cursor(&mut cursor, config, format, false)
     }
 
+    /// Run the given closure with indentation offset by `base_level` levels.
+    pub(crate) fn with_base_indent<F>(&mut self, base_level: usize, f: F) -> fmt::Result
+    where
+        F: FnOnce(&mut Self) -> fmt::Result,
+    {
+        let base_level = i16::try_from(base_level).unwrap_or(i16::MAX);
+        self.indent = self.indent.saturating_add(base_level);
+        let result = self.check_depth().and_then(|()| f(self));
+        self.indent = self.indent.saturating_sub(base_level);
+        result
+    }
+
     /// Forcibly write a line ending, at the end of a file.
     ///
     /// This will also reset any whitespace we have pending.
     pub(crate) fn write_trailing_line(&mut self) -> fmt::Result {
         self.line = Whitespace::default();
         self.spaces = 0;
-        self.write.write_trailing_line(self.config)?;
+        self.blank_lines = 0;
+        self.soft_break = false;
+        self.continuation = false;
+        self.column = 0;
+
+        if self.config.trailing_newline {
+            self.write.write_trailing_line(self.config)?;
+        }
+
         Ok(())
     }
 
     /// Write the given string.
     fn write_str(&mut self, s: &str) -> fmt::Result {
         if !s.is_empty() {
+            if mem::take(&mut self.soft_break) {
+                if self.exceeds_max_width(s) {
+                    self.line = Whitespace::Push;
+                    self.spaces = 0;
+                    self.continuation = true;
+                } else {
+                    self.spaces = usize::max(self.spaces, 1);
+                }
+            }
+
             self.flush_whitespace()?;
+
+            if let Some(source_map) = &mut self.source_map {
+                if let Some(loc) = self.sources.last() {
+                    source_map.record(self.current_line, loc);
+                }
+
+                self.current_line += s.matches('\n').count();
+            }
+
             self.write.write_str(s)?;
+
+            match s.rfind('\n') {
+                Some(n) => self.column = self.advance(0, &s[n + 1..]),
+                None => self.column = self.advance(self.column, s),
+            }
         }
 
         Ok(())
     }
 
+    /// Test if writing the given string after a space would exceed the
+    /// configured max width.
+    fn exceeds_max_width(&self, s: &str) -> bool {
+        let Some(max_width) = self.config.max_width else {
+            return false;
+        };
+
+        let column = self.column + usize::max(self.spaces, 1);
+        self.advance(column, s.split('\n').next().unwrap_or_default()) > max_width
+    }
+
+    /// Calculate the column after writing the given string at the given
+    /// column, where a tab advances to the next tab stop.
+    fn advance(&self, column: usize, s: &str) -> usize {
+        let tab_width = usize::max(self.config.tab_width, 1);
+
+        s.chars().fold(column, |column, c| match c {
+            '\t' => (column / tab_width + 1) * tab_width,
+            _ => column + 1,
+        })
+    }
+
     fn push(&mut self) {
+        self.soft_break = false;
+
         self.line = match self.line {
             Whitespace::Initial => return,
             Whitespace::Line => return,
@@ -118,6 +258,8 @@ impl<'a> Formatter<'a> {
 
     /// Push a new line.
     fn line(&mut self) {
+        self.soft_break = false;
+
         self.line = match self.line {
             Whitespace::Initial => return,
             _ => Whitespace::Line,
@@ -131,10 +273,39 @@ impl<'a> Formatter<'a> {
         self.spaces += 1;
     }
 
+    /// Push a soft break, which has no effect at the beginning of a line.
+    fn soft_break(&mut self) {
+        if let Whitespace::None = self.line {
+            self.soft_break = true;
+        }
+    }
+
+    /// The number of columns taken up by the current level of indentation.
+    fn indentation_width(&self) -> usize {
+        let level = i16::max(self.indent, 0) as usize;
+
+        match self.config.indentation {
+            Indentation::Space(n) => level * n,
+            Indentation::Tab => level * self.config.tab_width,
+        }
+    }
+
     /// Increase indentation level.
-    fn indentation(&mut self, n: i16) {
+    fn indentation(&mut self, n: i16) -> fmt::Result {
         self.push();
         self.indent += n;
+        self.check_depth()
+    }
+
+    /// Check that we haven't exceeded the configured maximum depth.
+    fn check_depth(&self) -> fmt::Result {
+        if let Some(max_depth) = self.config.max_depth {
+            if self.indent.max(0) as usize + self.nested > max_depth {
+                return Err(fmt::Error::DepthExceeded);
+            }
+        }
+
+        Ok(())
     }
 
     /// Internal function for formatting.
@@ -152,10 +323,16 @@ impl<'a> Formatter<'a> {
 
         let mut buf = String::new();
         let mut stack = smallvec::SmallVec::<[Frame; 4]>::new();
+        // Depth of comments which are being dropped.
+        let mut dropped_comments = 0usize;
 
         stack.push(Frame::default());
 
         while let (Some(item), Some(head)) = (cursor.next(), stack.last_mut()) {
+            if dropped_comments > 0 && !matches!(item, Item::OpenComment | Item::CloseComment) {
+                continue;
+            }
+
             let Frame {
                 in_quote,
                 has_eval,
@@ -166,8 +343,12 @@ impl<'a> Formatter<'a> {
                 Item::Register(..) => (),
                 Item::Indentation(0) => (),
                 Item::Literal(literal) => {
-                    if *in_quote {
-                        L::write_quoted(self, literal)?;
+                    if *in_quote && *has_eval {
+                        L::write_eval_quoted(self, literal)?;
+                    } else if *in_quote && self.in_string_eval() {
+                        L::write_nested_quoted(self, config, format, literal)?;
+                    } else if *in_quote {
+                        L::write_quoted_with_config(self, config, format, literal)?;
                     } else {
                         self.write_str(literal)?;
                     }
@@ -183,7 +364,13 @@ impl<'a> Formatter<'a> {
                 // Evaluating quotes are not supported.
                 Item::OpenQuote(false) if *in_quote => {
                     self.quoted_quote(cursor, &mut buf, config, format)?;
-                    L::write_quoted(self, &buf)?;
+
+                    if *has_eval {
+                        L::write_eval_quoted(self, &buf)?;
+                    } else {
+                        L::write_quoted_with_config(self, config, format, &buf)?;
+                    }
+
                     buf.clear();
                 }
                 Item::CloseQuote if end_on_close_quote => {
@@ -193,8 +380,30 @@ impl<'a> Formatter<'a> {
                     *in_quote = false;
                     L::close_quote(self, config, format, mem::take(has_eval))?;
                 }
+                Item::RawQuoted(input) if !*in_quote => {
+                    if let Some(result) = L::raw_string(self, input) {
+                        result?;
+                    } else {
+                        L::open_quote(self, config, format, false)?;
+                        L::write_quoted_with_config(self, config, format, input)?;
+                        L::close_quote(self, config, format, false)?;
+                    }
+                }
                 Item::Lang(_, lang) => {
+                    self.nested += 1;
                     lang.format(self, config, format)?;
+                    self.nested -= 1;
+                }
+                Item::Deferred(deferred) => {
+                    self.nested += 1;
+                    self.check_depth()?;
+                    let width = self
+                        .config
+                        .max_width
+                        .map(|width| width.saturating_sub(self.indentation_width()));
+                    let tokens = deferred.eval(config, width);
+                    tokens.format(self, config, format)?;
+                    self.nested -= 1;
                 }
                 // whitespace below
                 Item::Push => {
@@ -203,11 +412,21 @@ impl<'a> Formatter<'a> {
                 Item::Line => {
                     self.line();
                 }
+                Item::BlankLine => {
+                    self.push();
+                    self.blank_lines += 1;
+                }
                 Item::Space => {
                     self.space();
                 }
+                Item::SoftBreak if *in_quote => {
+                    self.space();
+                }
+                Item::SoftBreak => {
+                    self.soft_break();
+                }
                 Item::Indentation(n) => {
-                    self.indentation(*n);
+                    self.indentation(*n)?;
                 }
                 Item::OpenEval if *in_quote => {
                     if cursor.peek::<cursor::Literal>() && cursor.peek1::<cursor::CloseEval>() {
@@ -216,6 +435,7 @@ impl<'a> Formatter<'a> {
                         cursor.parse::<cursor::CloseEval>()?;
                     } else {
                         L::start_string_eval(self, config, format)?;
+                        self.string_evals += 1;
 
                         stack.push(Frame {
                             in_quote: false,
@@ -226,12 +446,35 @@ impl<'a> Formatter<'a> {
                 }
                 // Eval are only allowed within quotes.
                 Item::CloseEval if *end_on_eval => {
+                    self.string_evals -= 1;
                     L::end_string_eval(self, config, format)?;
                     stack.pop();
                 }
+                Item::OpenRegion(label) if !*in_quote => {
+                    self.region::<L>(Some(label))?;
+                }
+                Item::CloseRegion if !*in_quote => {
+                    self.region::<L>(None)?;
+                }
+                // Comments can't be put on a single line, so they are dropped
+                // when minifying.
+                Item::OpenComment => {
+                    if self.config.minify {
+                        dropped_comments += 1;
+                    }
+                }
+                Item::CloseComment => {
+                    dropped_comments = dropped_comments.saturating_sub(1);
+                }
+                Item::OpenSource(loc) => {
+                    self.sources.push(SourceLocation::clone(loc));
+                }
+                Item::CloseSource => {
+                    self.sources.pop();
+                }
                 _ => {
                     // Anything else is an illegal state for formatting.
-                    return Err(std::fmt::Error);
+                    return Err(fmt::Error::Fmt);
                 }
             }
         }
@@ -246,6 +489,82 @@ impl<'a> Formatter<'a> {
         }
     }
 
+    /// Write a region marker on its own line, opening a region if a label is
+    /// provided and closing it otherwise.
+    fn region<L>(&mut self, label: Option<&str>) -> fmt::Result
+    where
+        L: Lang,
+    {
+        // Region markers are line comments, and are only useful to editors.
+        if self.config.minify {
+            self.push();
+            return Ok(());
+        }
+
+        self.push();
+
+        let close = match (L::line_comment_prefix(), L::block_comment_delimiters()) {
+            (Some(prefix), _) => {
+                self.write_str(prefix)?;
+                None
+            }
+            (None, Some((open, close))) => {
+                self.write_str(open)?;
+                Some(close)
+            }
+            // Markers can't be written without comments.
+            (None, None) => return Ok(()),
+        };
+
+        self.space();
+
+        match (self.config.region_style, label) {
+            (RegionStyle::Region, Some(label)) => {
+                self.write_str("#region")?;
+
+                if !label.is_empty() {
+                    self.space();
+                    self.write_str(label)?;
+                }
+            }
+            (RegionStyle::Region, None) => {
+                self.write_str("#endregion")?;
+            }
+            (RegionStyle::EditorFold, Some(label)) => {
+                self.write_str("<editor-fold desc=\"")?;
+
+                // The label is an attribute on a single line, so escape
+                // anything which would end it early.
+                for part in label.split_inclusive(['"', '&', '<', '\r', '\n']) {
+                    let (text, escape) = match part.char_indices().last() {
+                        Some((n, '"')) => (&part[..n], "&quot;"),
+                        Some((n, '&')) => (&part[..n], "&amp;"),
+                        Some((n, '<')) => (&part[..n], "&lt;"),
+                        Some((n, '\r')) => (&part[..n], "&#13;"),
+                        Some((n, '\n')) => (&part[..n], "&#10;"),
+                        _ => (part, ""),
+                    };
+
+                    self.write_str(text)?;
+                    self.write_str(escape)?;
+                }
+
+                self.write_str("\">")?;
+            }
+            (RegionStyle::EditorFold, None) => {
+                self.write_str("</editor-fold>")?;
+            }
+        }
+
+        if let Some(close) = close {
+            self.space();
+            self.write_str(close)?;
+        }
+
+        self.push();
+        Ok(())
+    }
+
     /// Support for evaluating an interior quote and returning it as a string.
     fn quoted_quote<L>(
         &mut self,
@@ -271,19 +590,41 @@ impl<'a> Formatter<'a> {
     // item.
     fn flush_whitespace(&mut self) -> fmt::Result {
         let mut spaces = mem::take(&mut self.spaces);
+        let blank_lines = mem::take(&mut self.blank_lines);
 
         if let Some(lines) = mem::take(&mut self.line).into_indent() {
+            if self.config.minify {
+                self.continuation = false;
+                spaces = usize::max(spaces, usize::from(lines > 0));
+                return self.write_spaces(spaces);
+            }
+
+            // The first line break ends the current line, and every one
+            // after it writes an empty line. Empty lines are never written at
+            // the start of the output.
+            let lines = if lines == 0 {
+                0
+            } else {
+                let blank = (lines - 1 + blank_lines).min(self.config.max_blank_lines);
+                1 + blank
+            };
+
             for _ in 0..lines {
                 self.write.write_line(self.config)?;
             }
 
+            self.current_line += lines;
+            self.column = 0;
+
             let level = i16::max(self.indent, 0) as usize;
+            let level = level + usize::from(mem::take(&mut self.continuation) && lines > 0);
 
             match self.config.indentation {
                 Indentation::Space(n) => {
                     spaces += level * n;
                 }
                 Indentation::Tab => {
+                    self.column += level * self.config.tab_width;
                     let mut tabs = level;
 
                     while tabs > 0 {
@@ -295,6 +636,13 @@ impl<'a> Formatter<'a> {
             }
         }
 
+        self.write_spaces(spaces)
+    }
+
+    /// Write the given number of spaces.
+    fn write_spaces(&mut self, mut spaces: usize) -> fmt::Result {
+        self.column += spaces;
+
         while spaces > 0 {
             let len = usize::min(spaces, SPACES.len());
             self.write.write_str(&SPACES[0..len])?;
@@ -306,7 +654,7 @@ impl<'a> Formatter<'a> {
 }
 
 impl std::fmt::Write for Formatter<'_> {
-    fn write_str(&mut self, s: &str) -> fmt::Result {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
         if !s.is_empty() {
             Formatter::write_str(self, s)?;
         }
@@ -87,6 +87,6 @@ where
     fn write_line(&mut self, config: &fmt::Config) -> fmt::Result {
         self.writer
             .write_all(config.newline.as_bytes())
-            .map_err(|_| std::fmt::Error)
+            .map_err(|_| fmt::Error::Fmt)
     }
 }
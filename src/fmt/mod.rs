@@ -10,6 +10,8 @@
 //!   implementing [fmt::Write][std::fmt::Write].
 //! * [fmt::IoWriter][IoWriter]- To write the result into something implementing
 //!   [io::Write][std::io::Write].
+//! * [fmt::BufIoWriter][BufIoWriter]- Like [IoWriter], but buffers writes to
+//!   the underlying writer.
 //!
 //! # Examples
 //!
@@ -47,23 +49,27 @@
 //! # }
 //! ```
 
+mod buf_io_writer;
 mod config;
 mod cursor;
+mod error;
 mod fmt_writer;
 mod formatter;
 mod io_writer;
+mod source_map;
 mod vec_writer;
 
-pub use self::config::{Config, Indentation};
+pub use self::buf_io_writer::BufIoWriter;
+pub use self::config::{Config, Indentation, LineEnding, QuoteStyle, RegionStyle};
+pub use self::error::Error;
 pub use self::fmt_writer::FmtWriter;
 pub use self::formatter::Formatter;
 pub use self::io_writer::IoWriter;
+pub use self::source_map::SourceMap;
 pub use self::vec_writer::VecWriter;
 
 /// Result type for the `fmt` module.
-pub type Result<T = ()> = std::result::Result<T, std::fmt::Error>;
-/// Error for the `fmt` module.
-pub type Error = std::fmt::Error;
+pub type Result<T = ()> = std::result::Result<T, Error>;
 
 /// Trait that defines a line writer.
 pub(crate) trait Write: std::fmt::Write {
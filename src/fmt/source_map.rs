@@ -0,0 +1,90 @@
+use crate::tokens::SourceLocation;
+use std::collections::BTreeMap;
+
+/// A mapping from lines of formatted output to the source locations which
+/// produced them.
+///
+/// This is populated by a formatter configured with
+/// [Formatter::with_source_map], using the locations attached to tokens with
+/// [Tokens::append_with_source].
+///
+/// Output lines are numbered starting at 1. If a single line of output
+/// contains tokens from multiple source locations, the first location written
+/// to it is recorded. Lines which are not produced by tokens with a source
+/// location are not part of the map.
+///
+/// [Formatter::with_source_map]: crate::fmt::Formatter::with_source_map
+/// [Tokens::append_with_source]: crate::Tokens::append_with_source
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+/// use genco::fmt;
+/// use genco::tokens::SourceLocation;
+///
+/// let mut tokens = rust::Tokens::new();
+///
+/// quote_in! { tokens =>
+///     struct Foo {
+///         $(ref t => t.append_with_source(SourceLocation::new("schema.json", 3), quote!(a: u32,)))
+///         $(ref t => t.append_with_source(SourceLocation::new("schema.json", 4), quote!(b: u32,)))
+///     }
+/// };
+///
+/// let mut map = fmt::SourceMap::new();
+/// let mut w = fmt::VecWriter::new();
+///
+/// let fmt = fmt::Config::from_lang::<Rust>();
+/// let config = rust::Config::default();
+///
+/// tokens.format_file(&mut w.as_formatter(&fmt).with_source_map(&mut map), &config)?;
+///
+/// assert_eq! {
+///     vec![
+///         "struct Foo {",
+///         "    a: u32,",
+///         "    b: u32,",
+///         "}",
+///     ],
+///     w.into_vec(),
+/// };
+///
+/// assert_eq!(None, map.get(1));
+/// assert_eq!(Some(&SourceLocation::new("schema.json", 3)), map.get(2));
+/// assert_eq!(Some(&SourceLocation::new("schema.json", 4)), map.get(3));
+/// assert_eq!(None, map.get(4));
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct SourceMap {
+    lines: BTreeMap<usize, SourceLocation>,
+}
+
+impl SourceMap {
+    /// Construct a new empty source map.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get the source location which produced the given line of output.
+    pub fn get(&self, line: usize) -> Option<&SourceLocation> {
+        self.lines.get(&line)
+    }
+
+    /// Iterate over all lines of output which have a source location, in
+    /// order.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, &SourceLocation)> + '_ {
+        self.lines.iter().map(|(line, loc)| (*line, loc))
+    }
+
+    /// Test if the source map is empty.
+    pub fn is_empty(&self) -> bool {
+        self.lines.is_empty()
+    }
+
+    /// Record the given location for a line, unless one is already present.
+    pub(super) fn record(&mut self, line: usize, loc: &SourceLocation) {
+        self.lines.entry(line).or_insert_with(|| loc.clone());
+    }
+}
@@ -49,6 +49,67 @@ impl VecWriter {
         Self::default()
     }
 
+    /// Construct a new writer which appends lines to an existing vector.
+    ///
+    /// This allows a caller-owned vector to be reused, like when collecting
+    /// the output of many token streams.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    /// use genco::fmt;
+    ///
+    /// let fmt = fmt::Config::from_lang::<Rust>();
+    /// let config = rust::Config::default();
+    ///
+    /// let mut lines = vec![String::from("// header")];
+    ///
+    /// for name in vec!["a", "b"] {
+    ///     let tokens: rust::Tokens = quote!(fn $name() {});
+    ///
+    ///     let mut w = fmt::VecWriter::with_target(lines);
+    ///     tokens.format_file(&mut w.as_formatter(&fmt), &config)?;
+    ///     lines = w.into_inner();
+    /// }
+    ///
+    /// assert_eq!(vec!["// header", "fn a() {}", "fn b() {}"], lines);
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn with_target(target: Vec<String>) -> Self {
+        Self {
+            line_buffer: String::new(),
+            target,
+        }
+    }
+
+    /// Access the lines which have been completed so far.
+    ///
+    /// The line currently being written is not included.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    /// use genco::fmt;
+    ///
+    /// let tokens: rust::Tokens = quote! {
+    ///     fn a() {}
+    ///     fn b() {}
+    /// };
+    ///
+    /// let fmt = fmt::Config::from_lang::<Rust>();
+    /// let mut w = fmt::VecWriter::new();
+    /// tokens.format(&mut w.as_formatter(&fmt), &rust::Config::default(), &rust::Format::default())?;
+    ///
+    /// assert_eq!(&["fn a() {}"], w.lines());
+    /// assert_eq!(vec!["fn a() {}", "fn b() {}"], w.into_inner());
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn lines(&self) -> &[String] {
+        &self.target
+    }
+
     /// Convert into a formatter.
     pub fn as_formatter<'a>(&'a mut self, config: &'a fmt::Config) -> fmt::Formatter<'a> {
         fmt::Formatter::new(self, config)
@@ -59,6 +120,14 @@ impl VecWriter {
         self.target.push(self.line_buffer);
         self.target
     }
+
+    /// Convert into the underlying vector of lines.
+    ///
+    /// This is the same as [into_vec][Self::into_vec], and mirrors the
+    /// `into_inner` function of the other writers.
+    pub fn into_inner(self) -> Vec<String> {
+        self.into_vec()
+    }
 }
 
 impl std::fmt::Write for VecWriter {
@@ -0,0 +1,204 @@
+//! Specialization for Bash code generation.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use genco::prelude::*;
+//! use genco::fmt;
+//!
+//! # fn main() -> genco::fmt::Result {
+//! let toks: bash::Tokens = quote! {
+//!     if [ $$# -eq 0 ]; then
+//!         echo $(quoted("usage: run <file>, files can't be empty"))
+//!         exit 1
+//!     fi
+//! };
+//!
+//! let config = bash::Config::default().with_shebang("#!/usr/bin/env bash");
+//! let fmt = fmt::Config::from_lang::<Bash>();
+//!
+//! let mut w = fmt::VecWriter::new();
+//! toks.format_file(&mut w.as_formatter(&fmt), &config)?;
+//!
+//! assert_eq!(
+//!     vec![
+//!         "#!/usr/bin/env bash",
+//!         "",
+//!         "if [ $# -eq 0 ]; then",
+//!         "    echo 'usage: run <file>, files can'\\''t be empty'",
+//!         "    exit 1",
+//!         "fi",
+//!     ],
+//!     w.into_vec(),
+//! );
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # String Quoting in Bash
+//!
+//! Strings are always quoted with single quotes, in which no character has a
+//! special meaning. This makes it safe to quote arguments containing spaces,
+//! `$`, backticks or backslashes. A single quote can't be escaped inside of
+//! single quotes, so it's written as `'\''`, which ends the string, adds an
+//! escaped quote and starts a new string.
+//!
+//! Note that this also applies to string literals in [quote!][crate::quote],
+//! so `"$HOME"` is quoted as `'$HOME'` and is not expanded. Variables that
+//! should be expanded are written without quotes.
+//!
+//! Arguments can't contain NUL characters, so quoting one fails with
+//! [fmt::Error::UnsupportedChar].
+//!
+//! ```rust
+//! use genco::prelude::*;
+//!
+//! # fn main() -> genco::fmt::Result {
+//! let toks: bash::Tokens = quote!(echo $(quoted("it's $5 and `date`")));
+//! assert_eq!("echo 'it'\\''s $5 and `date`'", toks.to_string()?);
+//!
+//! let toks: bash::Tokens = quote!(echo $(quoted("a\0b")));
+//! assert_eq!(
+//!     Err(genco::fmt::Error::UnsupportedChar { character: '\0', index: 1 }),
+//!     toks.to_string()
+//! );
+//! # Ok(())
+//! # }
+//! ```
+
+use crate as genco;
+use crate::fmt;
+use crate::quote_in;
+use crate::tokens::ItemStr;
+use std::fmt::Write as _;
+
+/// Tokens container specialization for Bash.
+pub type Tokens = crate::Tokens<Bash>;
+
+impl_lang! {
+    /// Language specialization for Bash.
+    pub Bash {
+        type Config = Config;
+        type Format = Format;
+        type Item = Any;
+
+        fn capabilities() -> crate::lang::Capabilities {
+            // Shell scripts only have line comments, and no imports.
+            crate::lang::Capabilities::default()
+        }
+
+        fn line_comment_prefix() -> Option<&'static str> {
+            Some("#")
+        }
+
+        fn statement_terminator() -> &'static str {
+            ""
+        }
+
+        fn open_quote(
+            out: &mut fmt::Formatter<'_>,
+            _config: &Self::Config,
+            _format: &Self::Format,
+            _has_eval: bool,
+        ) -> fmt::Result {
+            out.write_char('\'')?;
+            Ok(())
+        }
+
+        fn close_quote(
+            out: &mut fmt::Formatter<'_>,
+            _config: &Self::Config,
+            _format: &Self::Format,
+            _has_eval: bool,
+        ) -> fmt::Result {
+            out.write_char('\'')?;
+            Ok(())
+        }
+
+        fn write_quoted(out: &mut fmt::Formatter<'_>, input: &str) -> fmt::Result {
+            // From: https://www.gnu.org/software/bash/manual/html_node/Single-Quotes.html
+
+            for (index, c) in input.char_indices() {
+                match c {
+                    '\'' => out.write_str("'\\''")?,
+                    '\0' => {
+                        return Err(fmt::Error::UnsupportedChar {
+                            character: c,
+                            index,
+                        })
+                    }
+                    c => out.write_char(c)?,
+                }
+            }
+
+            Ok(())
+        }
+
+        fn format_file(
+            tokens: &Tokens,
+            out: &mut fmt::Formatter<'_>,
+            config: &Self::Config,
+        ) -> fmt::Result {
+            let mut header = Tokens::new();
+
+            if let Some(shebang) = &config.shebang {
+                quote_in!(header => $(shebang.clone()));
+                header.line();
+            }
+
+            let format = Format::default();
+            header.format(out, config, &format)?;
+            tokens.format(out, config, &format)?;
+            Ok(())
+        }
+    }
+}
+
+/// Format state for Bash.
+#[derive(Debug, Default)]
+pub struct Format {}
+
+/// Configuration for Bash.
+#[derive(Debug, Default)]
+pub struct Config {
+    /// The shebang line to start the file with.
+    shebang: Option<ItemStr>,
+}
+
+impl Config {
+    /// Set the shebang line which the file starts with, like
+    /// `#!/usr/bin/env bash`. By default no shebang line is emitted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    /// use genco::fmt;
+    ///
+    /// let toks: bash::Tokens = quote!(set -euo pipefail);
+    ///
+    /// let config = bash::Config::default().with_shebang("#!/bin/bash");
+    /// let fmt = fmt::Config::from_lang::<Bash>();
+    ///
+    /// let mut w = fmt::VecWriter::new();
+    /// toks.format_file(&mut w.as_formatter(&fmt), &config)?;
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "#!/bin/bash",
+    ///         "",
+    ///         "set -euo pipefail",
+    ///     ],
+    ///     w.into_vec(),
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn with_shebang<S>(self, shebang: S) -> Self
+    where
+        S: Into<ItemStr>,
+    {
+        Self {
+            shebang: Some(shebang.into()),
+        }
+    }
+}
@@ -1,4 +1,48 @@
 //! Specialization for C code generation.
+//!
+//! Included headers are collected into two sorted groups, with system
+//! headers like `#include <stdio.h>` first, followed by local headers like
+//! `#include "local.h"`.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use genco::prelude::*;
+//!
+//! # fn main() -> genco::fmt::Result {
+//! let printf = c::include_system("stdio.h", "printf");
+//! let malloc = c::include_system("stdlib.h", "malloc");
+//! let init = c::include("module.h", "module_init");
+//!
+//! let toks: c::Tokens = quote! {
+//!     $(register(c::local_include("config.h")))
+//!
+//!     int main() {
+//!         void *m = $malloc(16);
+//!         $init(m);
+//!         $printf("done\n");
+//!     }
+//! };
+//!
+//! assert_eq!(
+//!     vec![
+//!         "#include <stdio.h>",
+//!         "#include <stdlib.h>",
+//!         "",
+//!         "#include \"config.h\"",
+//!         "#include \"module.h\"",
+//!         "",
+//!         "int main() {",
+//!         "    void *m = malloc(16);",
+//!         "    module_init(m);",
+//!         "    printf(\"done\\n\");",
+//!         "}",
+//!     ],
+//!     toks.to_file_vec()?
+//! );
+//! # Ok(())
+//! # }
+//! ```
 
 use crate as genco;
 use crate::fmt;
@@ -17,6 +61,26 @@ impl_lang! {
         type Format = Format;
         type Item = Import;
 
+        fn capabilities() -> crate::lang::Capabilities {
+            crate::lang::Capabilities {
+                adjacent_string_concatenation: true,
+                block_comments: true,
+                ..Default::default()
+            }
+        }
+
+        fn block_comment_delimiters() -> Option<(&'static str, &'static str)> {
+            Some(("/*", "*/"))
+        }
+
+        fn unit_type() -> Option<&'static str> {
+            Some("void")
+        }
+
+        fn concatenates_adjacent_strings() -> bool {
+            true
+        }
+
         fn write_quoted(out: &mut fmt::Formatter<'_>, input: &str) -> fmt::Result {
             super::c_family_write_quoted(out, input)
         }
@@ -34,10 +98,25 @@ impl_lang! {
             tokens.format(out, config, &format)?;
             Ok(())
         }
+
+        fn format_imports(
+            tokens: &Tokens,
+            out: &mut fmt::Formatter<'_>,
+            config: &Self::Config,
+        ) -> fmt::Result {
+            let mut header = Tokens::new();
+            Self::imports(&mut header, tokens);
+            header.format(out, config, &Format::default())
+        }
     }
 
     Import {
         fn format(&self, out: &mut fmt::Formatter<'_>, _: &Config, _: &Format) -> fmt::Result {
+            if let Some(namespace) = &self.namespace {
+                out.write_str(namespace)?;
+                out.write_str(SEP)?;
+            }
+
             out.write_str(&self.item)?;
             Ok(())
         }
@@ -56,6 +135,45 @@ pub struct Import {
     item: ItemStr,
     /// True if the include is specified as a system header using `<>`, false if a local header using `""`.
     system: bool,
+    /// C++ namespace the item is declared in.
+    namespace: Option<ItemStr>,
+}
+
+impl Import {
+    /// Qualify the item with the C++ namespace it's declared in, like
+    /// `std::vector`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    ///
+    /// let vector = c::include_system("vector", "vector").with_namespace("std");
+    /// let widget = c::include("ui/widget.hpp", "Widget").with_namespace("ui::widgets");
+    ///
+    /// let toks: c::Tokens = quote!($vector<$widget> widgets;);
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "#include <vector>",
+    ///         "",
+    ///         "#include \"ui/widget.hpp\"",
+    ///         "",
+    ///         "std::vector<ui::widgets::Widget> widgets;",
+    ///     ],
+    ///     toks.to_file_vec()?
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn with_namespace<N>(self, namespace: N) -> Self
+    where
+        N: Into<ItemStr>,
+    {
+        Self {
+            namespace: Some(namespace.into()),
+            ..self
+        }
+    }
 }
 
 /// Format for C.
@@ -66,24 +184,30 @@ pub struct Format {}
 #[derive(Debug, Default)]
 pub struct Config {}
 
+const SEP: &str = "::";
+
 impl C {
     fn imports(out: &mut Tokens, tokens: &Tokens) {
-        let mut includes = BTreeSet::new();
+        let mut system = BTreeSet::new();
+        let mut local = BTreeSet::new();
 
         for include in tokens.walk_imports() {
-            includes.insert((&include.path, include.system));
+            if include.system {
+                system.insert(&include.path);
+            } else {
+                local.insert(&include.path);
+            }
         }
 
-        if includes.is_empty() {
-            return;
+        for file in system {
+            quote_in!(*out => #include <$(file)>);
+            out.push();
         }
 
-        for (file, system_header) in includes {
-            if system_header {
-                quote_in!(*out => #include <$(file)>);
-            } else {
-                quote_in!(*out => #include $(quoted(file)));
-            }
+        out.line();
+
+        for file in local {
+            quote_in!(*out => #include $(quoted(file)));
             out.push();
         }
 
@@ -123,6 +247,7 @@ where
         path: path.into(),
         item: item.into(),
         system: false,
+        namespace: None,
     }
 }
 
@@ -158,5 +283,73 @@ where
         path: path.into(),
         item: item.into(),
         system: true,
+        namespace: None,
     }
 }
+
+/// Include a local C header file such as `#include "foo/bar.h"`, without
+/// referencing an item declared in it.
+///
+/// The include doesn't render anything, so it's typically added through
+/// [register][crate::tokens::register()].
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let toks: c::Tokens = quote! {
+///     $(register(c::local_include("config.h")))
+///     $(register(c::local_include("config.h")))
+///     int x;
+/// };
+///
+/// assert_eq!(
+///     vec![
+///        "#include \"config.h\"",
+///        "",
+///        "int x;",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn local_include<M>(path: M) -> Import
+where
+    M: Into<ItemStr>,
+{
+    include(path, "")
+}
+
+/// Include a C system header such as `#include <stdio.h>`, without
+/// referencing an item declared in it.
+///
+/// The include doesn't render anything, so it's typically added through
+/// [register][crate::tokens::register()].
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let toks: c::Tokens = quote! {
+///     $(register(c::system_include("stdint.h")))
+///     int x;
+/// };
+///
+/// assert_eq!(
+///     vec![
+///        "#include <stdint.h>",
+///        "",
+///        "int x;",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn system_include<M>(path: M) -> Import
+where
+    M: Into<ItemStr>,
+{
+    include_system(path, "")
+}
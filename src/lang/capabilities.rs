@@ -0,0 +1,45 @@
+/// The features supported by a language specialization.
+///
+/// This is returned by [Lang::capabilities][super::Lang::capabilities], and
+/// allows generic tooling which targets multiple languages to adapt to what
+/// each of them supports.
+///
+/// New capabilities might be added in the future, so this can't be
+/// constructed with a struct literal outside of genco. Start from
+/// [Capabilities::default], which has every capability disabled.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+/// use genco::lang::Lang;
+///
+/// assert!(Java::capabilities().static_imports);
+/// assert!(!Rust::capabilities().static_imports);
+///
+/// assert!(Python::capabilities().string_interpolation);
+/// assert!(Go::capabilities().raw_strings);
+///
+/// assert!(Sql::capabilities().block_comments);
+/// assert!(!Yaml::capabilities().block_comments);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Capabilities {
+    /// Strings can interpolate expressions, which is indicated by the
+    /// language implementing [LangSupportsEval][super::LangSupportsEval].
+    pub string_interpolation: bool,
+    /// Raw string literals are emitted by
+    /// [raw_quoted][crate::tokens::raw_quoted()].
+    pub raw_strings: bool,
+    /// Adjacent string literals are concatenated by the language, see
+    /// [Lang::concatenates_adjacent_strings][super::Lang::concatenates_adjacent_strings].
+    pub adjacent_string_concatenation: bool,
+    /// Imports can be given an alias.
+    pub import_aliasing: bool,
+    /// Members can be imported statically, like `import static` in Java.
+    pub static_imports: bool,
+    /// The language has block comments, like `/* ... */`, which are written
+    /// using [Lang::block_comment_delimiters][super::Lang::block_comment_delimiters].
+    pub block_comments: bool,
+}
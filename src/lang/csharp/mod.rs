@@ -23,12 +23,13 @@ mod comment;
 use crate as genco;
 use crate::fmt;
 use crate::quote_in;
-use crate::tokens::ItemStr;
+use crate::tokens::{self, FormatInto, ItemStr};
 use std::collections::{BTreeSet, HashMap, HashSet};
 use std::fmt::Write as _;
 
 pub use self::block_comment::BlockComment;
 pub use self::comment::Comment;
+pub use crate::tokens::Nullable;
 
 /// Tokens container specialization for C#.
 pub type Tokens = crate::Tokens<Csharp>;
@@ -40,11 +41,37 @@ impl_lang! {
         type Format = Format;
         type Item = Import;
 
+        fn capabilities() -> crate::lang::Capabilities {
+            crate::lang::Capabilities {
+                raw_strings: true,
+                block_comments: true,
+                import_aliasing: true,
+                ..Default::default()
+            }
+        }
+
+        fn block_comment_delimiters() -> Option<(&'static str, &'static str)> {
+            Some(("/*", "*/"))
+        }
+
+        fn unit_type() -> Option<&'static str> {
+            Some("void")
+        }
+
         fn write_quoted(out: &mut fmt::Formatter<'_>, input: &str) -> fmt::Result {
             // From: https://csharpindepth.com/articles/Strings
             super::c_family_write_quoted(out, input)
         }
 
+        fn raw_string(out: &mut fmt::Formatter<'_>, input: &str) -> Option<fmt::Result> {
+            // Verbatim strings can span lines, but we keep them on one.
+            if input.contains(['\r', '\n']) {
+                return None;
+            }
+
+            Some(write!(out, "@\"{}\"", input.replace('"', "\"\"")).map_err(fmt::Error::from))
+        }
+
         fn format_file(
             tokens: &Tokens,
             out: &mut fmt::Formatter<'_>,
@@ -54,7 +81,7 @@ impl_lang! {
 
             let mut format = Format::default();
 
-            Self::imports(&mut file, tokens, config, &mut format.imported_names);
+            Self::imports(&mut file, tokens, config, &mut format);
 
             if let Some(namespace) = &config.namespace {
                 quote_in! { file =>
@@ -71,10 +98,35 @@ impl_lang! {
 
             Ok(())
         }
+
+        fn format_imports(
+            tokens: &Tokens,
+            out: &mut fmt::Formatter<'_>,
+            config: &Self::Config,
+        ) -> fmt::Result {
+            let mut imports = Tokens::new();
+            let mut format = Format::default();
+            Self::imports(&mut imports, tokens, config, &mut format);
+            imports.format(out, config, &format)
+        }
     }
 
     Import {
         fn format(&self, out: &mut fmt::Formatter<'_>, config: &Config, format: &Format) -> fmt::Result {
+            if let Some(alias) = &self.alias {
+                let imported = format.imported_aliases.get(alias.as_ref());
+
+                if imported == Some(&(self.namespace.to_string(), self.name.to_string())) {
+                    out.write_str(alias)?;
+                } else {
+                    out.write_str(&self.namespace)?;
+                    out.write_str(SEP)?;
+                    out.write_str(&self.name)?;
+                }
+
+                return Ok(());
+            }
+
             {
                 let qualified = self.qualified || is_qualified(config, format, &self.namespace, &self.name);
 
@@ -120,6 +172,9 @@ pub struct Format {
     ///
     /// A missing name means that it has to be used in a qualified manner.
     imported_names: HashMap<String, String>,
+    /// Aliases which have been imported, mapped to the namespace and name of
+    /// the type they refer to.
+    imported_aliases: HashMap<String, (String, String)>,
 }
 
 /// Config data for Csharp formatting.
@@ -127,6 +182,8 @@ pub struct Format {
 pub struct Config {
     /// namespace to use.
     namespace: Option<ItemStr>,
+    /// Emit `global using` directives.
+    global_usings: bool,
 }
 
 impl Config {
@@ -137,6 +194,45 @@ impl Config {
     {
         Self {
             namespace: Some(namespace.into()),
+            ..self
+        }
+    }
+
+    /// Emit imports as C# 10 `global using` directives, which apply to every
+    /// file in the project.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    /// use genco::fmt;
+    ///
+    /// let list = csharp::import("System.Collections.Generic", "List");
+    /// let json = csharp::import("System.Text.Json", "JsonSerializer").with_alias("Json");
+    ///
+    /// let toks: csharp::Tokens = quote!($list $json);
+    ///
+    /// let config = csharp::Config::default().with_global_usings(true);
+    /// let fmt = fmt::Config::from_lang::<Csharp>();
+    ///
+    /// let mut w = fmt::VecWriter::new();
+    /// toks.format_file(&mut w.as_formatter(&fmt), &config)?;
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "global using System.Collections.Generic;",
+    ///         "global using Json = System.Text.Json.JsonSerializer;",
+    ///         "",
+    ///         "List Json",
+    ///     ],
+    ///     w.into_vec(),
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn with_global_usings(self, global_usings: bool) -> Self {
+        Self {
+            global_usings,
+            ..self
         }
     }
 }
@@ -152,6 +248,8 @@ pub struct Import {
     name: ItemStr,
     /// Use as qualified type.
     qualified: bool,
+    /// Alias to import the type as.
+    alias: Option<ItemStr>,
 }
 
 impl Import {
@@ -163,25 +261,101 @@ impl Import {
             ..self
         }
     }
+
+    /// Import the type under an alias, like
+    /// `using Foo = Some.Long.Namespace.Type;`.
+    ///
+    /// Aliases are emitted after the other using directives, sorted by
+    /// alias. If the same alias is used for different types, the one whose
+    /// namespace and name sort first is imported and the others are
+    /// qualified, regardless of the order they're used in. A type can be
+    /// imported both with and without an alias.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    ///
+    /// let a = csharp::import("Some.Long.Namespace", "Type").with_alias("Foo");
+    /// let b = csharp::import("Some.Long.Namespace", "Type");
+    /// let c = csharp::import("Other.Namespace", "Type").with_alias("Foo");
+    /// let d = csharp::import("System.IO", "Path").with_alias("IoPath");
+    ///
+    /// let toks: csharp::Tokens = quote!($a $b $c $d);
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "using Some.Long.Namespace;",
+    ///         "using Foo = Other.Namespace.Type;",
+    ///         "using IoPath = System.IO.Path;",
+    ///         "",
+    ///         "Some.Long.Namespace.Type Type Foo IoPath",
+    ///     ],
+    ///     toks.to_file_vec()?
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn with_alias<A>(self, alias: A) -> Self
+    where
+        A: Into<ItemStr>,
+    {
+        Self {
+            alias: Some(alias.into()),
+            ..self
+        }
+    }
+
+    /// Wrap the type into a nullable type, like `Type?`.
+    ///
+    /// This is the same as calling [nullable()] with the import, which is
+    /// still imported as usual.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    ///
+    /// let uri = csharp::import("System", "Uri");
+    ///
+    /// let toks: csharp::Tokens = quote!($(uri.nullable()) link;);
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "using System;",
+    ///         "",
+    ///         "Uri? link;",
+    ///     ],
+    ///     toks.to_file_vec()?
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn nullable(self) -> Nullable<Self> {
+        nullable(self)
+    }
 }
 
 impl Csharp {
-    fn imports(
-        out: &mut Tokens,
-        tokens: &Tokens,
-        config: &Config,
-        imported_names: &mut HashMap<String, String>,
-    ) {
+    fn imports(out: &mut Tokens, tokens: &Tokens, config: &Config, format: &mut Format) {
         let mut modules = BTreeSet::new();
+        let mut aliases = BTreeSet::new();
 
         for import in tokens.walk_imports() {
-            modules.insert((&*import.namespace, &*import.name));
+            match &import.alias {
+                Some(alias) => {
+                    aliases.insert((&**alias, &*import.namespace, &*import.name));
+                }
+                None => {
+                    modules.insert((&*import.namespace, &*import.name));
+                }
+            }
         }
 
-        if modules.is_empty() {
+        if modules.is_empty() && aliases.is_empty() {
             return;
         }
 
+        let global = config.global_usings.then_some("global ");
+        let imported_names = &mut format.imported_names;
         let mut imported = HashSet::new();
 
         for (namespace, name) in modules {
@@ -198,7 +372,7 @@ impl Csharp {
             }
 
             if !imported.contains(namespace) {
-                quote_in!(*out => using $namespace;);
+                quote_in!(*out => $(global)using $namespace;);
                 out.push();
                 imported.insert(namespace);
             }
@@ -206,6 +380,19 @@ impl Csharp {
             imported_names.insert(name.to_string(), namespace.to_string());
         }
 
+        for (alias, namespace, name) in aliases {
+            if format.imported_aliases.contains_key(alias) {
+                continue;
+            }
+
+            quote_in!(*out => $(global)using $alias = $namespace$(SEP)$name;);
+            out.push();
+
+            format
+                .imported_aliases
+                .insert(alias.to_string(), (namespace.to_string(), name.to_string()));
+        }
+
         out.line();
     }
 }
@@ -248,9 +435,49 @@ where
         namespace: namespace.into(),
         name: name.into(),
         qualified: false,
+        alias: None,
     }
 }
 
+/// A nullable type in C#, like `int?` or `string?`.
+///
+/// For value types like `int` this is the nullable value type
+/// `Nullable<int>`, and for reference types like `string` it's a nullable
+/// reference annotation. Both are written with a `?` suffix, and any type
+/// wrapped by it is imported as usual.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let list = csharp::import("System.Collections.Generic", "List");
+///
+/// let toks: csharp::Tokens = quote! {
+///     $(csharp::nullable("int")) count;
+///     $(csharp::nullable("string")) name;
+///     $(csharp::nullable(quote!($list<$(csharp::nullable("int"))>))) items;
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "using System.Collections.Generic;",
+///         "",
+///         "int? count;",
+///         "string? name;",
+///         "List<int?>? items;",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn nullable<T>(inner: T) -> Nullable<T>
+where
+    T: FormatInto<Csharp>,
+{
+    tokens::nullable(inner)
+}
+
 /// Format a doc comment where each line is preceeded by `///`.
 ///
 /// # Examples
@@ -0,0 +1,569 @@
+//! Specialization for CSS and SCSS code generation.
+//!
+//! Stylesheets are imported through [import()], which adds an `@import` rule
+//! to the top of the file. Rules are built through [rule()], and can be
+//! nested like in SCSS. Nested rules are written as they are if
+//! [Config::with_scss] is enabled, and are otherwise flattened into plain CSS
+//! rules with combined selectors.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use genco::prelude::*;
+//! use genco::fmt;
+//!
+//! # fn main() -> genco::fmt::Result {
+//! let nav = css::rule("nav")
+//!     .decl("background", css::url("bg image.png"))
+//!     .rule(css::rule("a").decl("color", "red").rule(css::rule("&:hover").decl("color", "blue")));
+//!
+//! let toks: css::Tokens = quote! {
+//!     $(css::import("reset.css"))
+//!     $nav
+//! };
+//!
+//! assert_eq!(
+//!     vec![
+//!         "@import \"reset.css\";",
+//!         "",
+//!         "nav {",
+//!         "    background: url(\"bg image.png\");",
+//!         "}",
+//!         "",
+//!         "nav a {",
+//!         "    color: red;",
+//!         "}",
+//!         "",
+//!         "nav a:hover {",
+//!         "    color: blue;",
+//!         "}",
+//!     ],
+//!     toks.to_file_vec()?
+//! );
+//!
+//! let config = css::Config::default().with_scss(true);
+//! let fmt = fmt::Config::from_lang::<Css>();
+//!
+//! let mut w = fmt::VecWriter::new();
+//! toks.format_file(&mut w.as_formatter(&fmt), &config)?;
+//!
+//! assert_eq!(
+//!     vec![
+//!         "@import \"reset.css\";",
+//!         "",
+//!         "nav {",
+//!         "    background: url(\"bg image.png\");",
+//!         "",
+//!         "    a {",
+//!         "        color: red;",
+//!         "",
+//!         "        &:hover {",
+//!         "            color: blue;",
+//!         "        }",
+//!         "    }",
+//!         "}",
+//!     ],
+//!     w.into_vec()
+//! );
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # String Quoting in CSS
+//!
+//! Strings are quoted with double quotes, in which `"` and `\` are escaped
+//! with a backslash. Line breaks and other control characters can't be
+//! escaped that way, so they are written as hexadecimal escapes like `\a `,
+//! where the trailing space terminates the escape.
+//!
+//! ```rust
+//! use genco::prelude::*;
+//!
+//! # fn main() -> genco::fmt::Result {
+//! let toks: css::Tokens = quote!(content: $(quoted("say \"hi\"\n\\o/")););
+//! assert_eq!("content: \"say \\\"hi\\\"\\a \\\\o/\";", toks.to_string()?);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # Comments in CSS
+//!
+//! CSS only has block comments, so line comments and editor region markers
+//! are written as `/* .. */`, which is also valid in SCSS.
+//!
+//! ```rust
+//! use genco::prelude::*;
+//! use genco::tokens::{line_comment, trailing_comment};
+//!
+//! # fn main() -> genco::fmt::Result {
+//! let toks: css::Tokens = quote! {
+//!     $(line_comment(["generated"]))
+//!     a {
+//!         $(trailing_comment(quote!(color: red;), "primary"))
+//!     }
+//! };
+//!
+//! assert_eq!(
+//!     vec![
+//!         "/* generated */",
+//!         "a {",
+//!         "    color: red; /* primary */",
+//!         "}",
+//!     ],
+//!     toks.to_file_vec()?
+//! );
+//! # Ok(())
+//! # }
+//! ```
+
+use crate as genco;
+use crate::fmt;
+use crate::quote_in;
+use crate::tokens::{quoted, static_literal, with_config, FormatInto, ItemStr};
+use std::collections::BTreeSet;
+use std::fmt::Write as _;
+
+/// Tokens container specialization for CSS.
+pub type Tokens = crate::Tokens<Css>;
+
+impl_lang! {
+    /// Language specialization for CSS.
+    pub Css {
+        type Config = Config;
+        type Format = Format;
+        type Item = Import;
+
+        fn capabilities() -> crate::lang::Capabilities {
+            crate::lang::Capabilities {
+                block_comments: true,
+                ..Default::default()
+            }
+        }
+
+        fn line_comment_prefix() -> Option<&'static str> {
+            None
+        }
+
+        fn block_comment_delimiters() -> Option<(&'static str, &'static str)> {
+            Some(("/*", "*/"))
+        }
+
+        fn write_quoted(out: &mut fmt::Formatter<'_>, input: &str) -> fmt::Result {
+            // From: https://www.w3.org/TR/css-syntax-3/#consume-string-token
+
+            for c in input.chars() {
+                match c {
+                    '"' => out.write_str("\\\"")?,
+                    '\\' => out.write_str("\\\\")?,
+                    c if c.is_control() => write!(out, "\\{:x} ", c as u32)?,
+                    c => out.write_char(c)?,
+                }
+            }
+
+            Ok(())
+        }
+
+        fn format_file(
+            tokens: &Tokens,
+            out: &mut fmt::Formatter<'_>,
+            config: &Self::Config,
+        ) -> fmt::Result {
+            let mut header = Tokens::new();
+            Self::imports(&mut header, tokens);
+            let format = Format::default();
+            header.format(out, config, &format)?;
+            tokens.format(out, config, &format)?;
+            Ok(())
+        }
+
+        fn format_imports(
+            tokens: &Tokens,
+            out: &mut fmt::Formatter<'_>,
+            config: &Self::Config,
+        ) -> fmt::Result {
+            let mut imports = Tokens::new();
+            Self::imports(&mut imports, tokens);
+            imports.format(out, config, &Format::default())
+        }
+    }
+
+    Import {
+        fn format(&self, _: &mut fmt::Formatter<'_>, _: &Config, _: &Format) -> fmt::Result {
+            Ok(())
+        }
+    }
+}
+
+/// Format state for CSS.
+#[derive(Debug, Default)]
+pub struct Format {}
+
+/// Configuration for CSS.
+#[derive(Debug, Default)]
+pub struct Config {
+    /// If nested rules should be written using SCSS syntax.
+    scss: bool,
+}
+
+impl Config {
+    /// Write nested rules using SCSS syntax instead of flattening them into
+    /// plain CSS rules. Defaults to `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    /// use genco::fmt;
+    ///
+    /// let toks: css::Tokens = quote! {
+    ///     $(css::rule("ul, ol").rule(css::rule("li").decl("margin", "0")))
+    /// };
+    ///
+    /// assert_eq!(vec!["ul li, ol li {", "    margin: 0;", "}"], toks.to_file_vec()?);
+    ///
+    /// let config = css::Config::default().with_scss(true);
+    /// let fmt = fmt::Config::from_lang::<Css>();
+    ///
+    /// let mut w = fmt::VecWriter::new();
+    /// toks.format_file(&mut w.as_formatter(&fmt), &config)?;
+    ///
+    /// assert_eq!(
+    ///     vec!["ul, ol {", "    li {", "        margin: 0;", "    }", "}"],
+    ///     w.into_vec()
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn with_scss(self, scss: bool) -> Self {
+        Self { scss }
+    }
+}
+
+/// An imported stylesheet, like `@import "reset.css";`.
+///
+/// Created through the [import()] function.
+#[derive(Debug, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct Import {
+    /// The path of the stylesheet.
+    path: ItemStr,
+}
+
+impl Css {
+    fn imports(out: &mut Tokens, tokens: &Tokens) {
+        let mut seen = BTreeSet::new();
+        let mut imports = Vec::new();
+
+        // NB: the order of imports is significant in CSS, so they're written
+        // in the order they are first used.
+        for import in tokens.walk_imports().collect::<Vec<_>>().into_iter().rev() {
+            if seen.insert(&*import.path) {
+                imports.push(&*import.path);
+            }
+        }
+
+        if imports.is_empty() {
+            return;
+        }
+
+        for path in imports {
+            quote_in!(*out => @import $(quoted(path)););
+            out.push();
+        }
+
+        out.line();
+    }
+}
+
+/// A `url(...)` value.
+///
+/// Created through the [url()] function.
+#[derive(Debug, Clone)]
+pub struct Url {
+    url: ItemStr,
+}
+
+impl FormatInto<Css> for Url {
+    fn format_into(self, tokens: &mut Tokens) {
+        quote_in!(*tokens => url($(quoted(self.url))));
+    }
+}
+
+/// A rule with a selector, declarations and nested rules.
+///
+/// Created through the [rule()] function.
+#[derive(Debug, Clone)]
+pub struct Rule {
+    selector: ItemStr,
+    declarations: Vec<(ItemStr, Tokens)>,
+    rules: Vec<Rule>,
+}
+
+impl Rule {
+    /// Add a declaration, like `color: red;`.
+    pub fn decl<P, V>(mut self, property: P, value: V) -> Self
+    where
+        P: Into<ItemStr>,
+        V: FormatInto<Css>,
+    {
+        let mut tokens = Tokens::new();
+        tokens.append(value);
+        self.declarations.push((property.into(), tokens));
+        self
+    }
+
+    /// Add a nested rule.
+    ///
+    /// A `&` in the selector of the nested rule refers to the selector of
+    /// this rule, like in SCSS. Otherwise the nested rule applies to
+    /// descendants of this rule.
+    pub fn rule(mut self, rule: Rule) -> Self {
+        self.rules.push(rule);
+        self
+    }
+
+    /// Write the rule with nested rules inside of it.
+    fn format_nested(self, tokens: &mut Tokens) {
+        let rules = self.rules;
+        let has_declarations = !self.declarations.is_empty();
+
+        Self::block(
+            tokens,
+            self.selector,
+            self.declarations,
+            !rules.is_empty(),
+            |tokens| {
+                for (n, rule) in rules.into_iter().enumerate() {
+                    if has_declarations || n > 0 {
+                        tokens.line();
+                    } else {
+                        tokens.push();
+                    }
+
+                    rule.format_nested(tokens);
+                }
+            },
+        );
+    }
+
+    /// Write the rule followed by its nested rules, with their selectors
+    /// combined with the selector of this rule.
+    fn format_flat(self, parent: Option<&str>, tokens: &mut Tokens) {
+        let selector = match parent {
+            Some(parent) => ItemStr::from(combine(parent, &self.selector)),
+            None => self.selector,
+        };
+
+        let written = !self.declarations.is_empty() || self.rules.is_empty();
+
+        if written {
+            Self::block(tokens, selector.clone(), self.declarations, false, |_| {});
+        }
+
+        for (n, rule) in self.rules.into_iter().enumerate() {
+            if written || n > 0 {
+                tokens.line();
+            }
+
+            rule.format_flat(Some(&selector), tokens);
+        }
+    }
+
+    fn block<F>(
+        tokens: &mut Tokens,
+        selector: ItemStr,
+        declarations: Vec<(ItemStr, Tokens)>,
+        has_rules: bool,
+        nested: F,
+    ) where
+        F: FnOnce(&mut Tokens),
+    {
+        tokens.append(selector);
+        tokens.space();
+
+        if declarations.is_empty() && !has_rules {
+            tokens.append(static_literal("{}"));
+            return;
+        }
+
+        tokens.append(static_literal("{"));
+        tokens.indent();
+
+        for (property, value) in declarations {
+            tokens.push();
+            quote_in!(*tokens => $property: $value;);
+        }
+
+        nested(tokens);
+        tokens.unindent();
+        tokens.append(static_literal("}"));
+    }
+}
+
+impl FormatInto<Css> for Rule {
+    fn format_into(self, tokens: &mut Tokens) {
+        tokens.append(with_config(move |config: &Config| {
+            let mut tokens = Tokens::new();
+
+            if config.scss {
+                self.clone().format_nested(&mut tokens);
+            } else {
+                self.clone().format_flat(None, &mut tokens);
+            }
+
+            tokens
+        }));
+    }
+}
+
+/// Split a selector list on the commas which separate the selectors.
+fn split(selectors: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+
+    for (n, c) in selectors.char_indices() {
+        match c {
+            '(' | '[' => depth += 1,
+            ')' | ']' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                parts.push(selectors[start..n].trim());
+                start = n + 1;
+            }
+            _ => (),
+        }
+    }
+
+    parts.push(selectors[start..].trim());
+    parts
+}
+
+/// Combine the selectors of a parent and a nested rule like SCSS does.
+fn combine(parent: &str, child: &str) -> String {
+    let mut out = String::new();
+
+    for p in split(parent) {
+        for c in split(child) {
+            if !out.is_empty() {
+                out.push_str(", ");
+            }
+
+            if c.contains('&') {
+                out.push_str(&c.replace('&', p));
+            } else {
+                out.push_str(p);
+                out.push(' ');
+                out.push_str(c);
+            }
+        }
+    }
+
+    out
+}
+
+/// Import a stylesheet through an `@import` rule at the top of the file.
+///
+/// Interpolating the import doesn't write anything in place. Each
+/// stylesheet is only imported once, and since the order of imports is
+/// significant, they are written in the order they are first used.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let toks: css::Tokens = quote! {
+///     $(css::import("theme.css"))
+///     $(css::import("reset.css"))
+///     $(css::import("theme.css"))
+///     body {
+///         margin: 0;
+///     }
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "@import \"theme.css\";",
+///         "@import \"reset.css\";",
+///         "",
+///         "body {",
+///         "    margin: 0;",
+///         "}",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn import<P>(path: P) -> Import
+where
+    P: Into<ItemStr>,
+{
+    Import { path: path.into() }
+}
+
+/// A `url(...)` value, where the url is quoted.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let toks: css::Tokens = quote!(src: $(css::url("fonts/my \"font\".woff2")););
+/// assert_eq!("src: url(\"fonts/my \\\"font\\\".woff2\");", toks.to_string()?);
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn url<U>(url: U) -> Url
+where
+    U: Into<ItemStr>,
+{
+    Url { url: url.into() }
+}
+
+/// A rule, like `a { color: red; }`.
+///
+/// Declarations are added through [Rule::decl] and nested rules through
+/// [Rule::rule]. How nested rules are written depends on
+/// [Config::with_scss]. In plain CSS, a rule which only contains nested
+/// rules is left out, and only the nested rules are written.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let button = css::rule(".button")
+///     .decl("padding", quote!(4px 8px))
+///     .decl("font-family", quote!($(quoted("Open Sans")), sans-serif))
+///     .rule(css::rule("&.primary, &:focus").decl("color", "white"));
+///
+/// let toks: css::Tokens = quote! {
+///     $button
+///
+///     $(css::rule("div"))
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         ".button {",
+///         "    padding: 4px 8px;",
+///         "    font-family: \"Open Sans\", sans-serif;",
+///         "}",
+///         "",
+///         ".button.primary, .button:focus {",
+///         "    color: white;",
+///         "}",
+///         "",
+///         "div {}",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn rule<S>(selector: S) -> Rule
+where
+    S: Into<ItemStr>,
+{
+    Rule {
+        selector: selector.into(),
+        declarations: Vec::new(),
+        rules: Vec::new(),
+    }
+}
@@ -0,0 +1,94 @@
+use crate as genco;
+use crate::lang::Dart;
+use crate::quote_in;
+use crate::tokens::{FormatInto, ItemStr};
+use crate::Tokens;
+
+/// The header of a Dart class declaration, like
+/// `class Name extends Base with M1, M2 implements I1`.
+///
+/// The clauses are always emitted in the order required by the Dart grammar,
+/// regardless of the order in which they were configured.
+///
+/// This struct is created by the [class_header][super::class_header()]
+/// function.
+#[derive(Debug, Clone)]
+pub struct ClassHeader {
+    name: ItemStr,
+    extends: Option<Tokens<Dart>>,
+    mixins: Vec<Tokens<Dart>>,
+    implements: Vec<Tokens<Dart>>,
+}
+
+impl ClassHeader {
+    pub(super) fn new(name: ItemStr) -> Self {
+        Self {
+            name,
+            extends: None,
+            mixins: Vec::new(),
+            implements: Vec::new(),
+        }
+    }
+
+    /// Set the superclass of the class.
+    pub fn extends<T>(self, ty: T) -> Self
+    where
+        T: FormatInto<Dart>,
+    {
+        let mut extends = Tokens::new();
+        extends.append(ty);
+
+        Self {
+            extends: Some(extends),
+            ..self
+        }
+    }
+
+    /// Add mixins to the class.
+    pub fn with_mixins<I>(mut self, mixins: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: FormatInto<Dart>,
+    {
+        self.mixins.extend(mixins.into_iter().map(to_tokens));
+        self
+    }
+
+    /// Add interfaces which are implemented by the class.
+    pub fn implements<I>(mut self, interfaces: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: FormatInto<Dart>,
+    {
+        self.implements
+            .extend(interfaces.into_iter().map(to_tokens));
+        self
+    }
+}
+
+fn to_tokens<T>(ty: T) -> Tokens<Dart>
+where
+    T: FormatInto<Dart>,
+{
+    let mut tokens = Tokens::new();
+    tokens.append(ty);
+    tokens
+}
+
+impl FormatInto<Dart> for ClassHeader {
+    fn format_into(self, tokens: &mut Tokens<Dart>) {
+        quote_in!(*tokens => class $(self.name));
+
+        if let Some(extends) = self.extends {
+            quote_in!(*tokens => $[' ']extends $extends);
+        }
+
+        if !self.mixins.is_empty() {
+            quote_in!(*tokens => $[' ']with $(for m in self.mixins join (, ) => $m));
+        }
+
+        if !self.implements.is_empty() {
+            quote_in!(*tokens => $[' ']implements $(for i in self.implements join (, ) => $i));
+        }
+    }
+}
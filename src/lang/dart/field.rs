@@ -0,0 +1,54 @@
+use crate as genco;
+use crate::lang::Dart;
+use crate::quote_in;
+use crate::tokens::{FormatInto, ItemStr};
+use crate::Tokens;
+
+/// A field declaration, like `late final Foo? name;`.
+///
+/// This struct is created by the [field][super::field()] function.
+#[derive(Debug, Clone)]
+pub struct Field {
+    ty: Tokens<Dart>,
+    name: ItemStr,
+    late: bool,
+    is_final: bool,
+}
+
+impl Field {
+    pub(super) fn new(ty: Tokens<Dart>, name: ItemStr) -> Self {
+        Self {
+            ty,
+            name,
+            late: false,
+            is_final: false,
+        }
+    }
+
+    /// Mark the field as `late`, meaning it's initialized after construction.
+    pub fn late(self) -> Self {
+        Self { late: true, ..self }
+    }
+
+    /// Mark the field as `final`, meaning it can only be assigned once.
+    pub fn final_(self) -> Self {
+        Self {
+            is_final: true,
+            ..self
+        }
+    }
+}
+
+impl FormatInto<Dart> for Field {
+    fn format_into(self, tokens: &mut Tokens<Dart>) {
+        if self.late {
+            quote_in!(*tokens => late$[' ']);
+        }
+
+        if self.is_final {
+            quote_in!(*tokens => final$[' ']);
+        }
+
+        quote_in!(*tokens => $(self.ty) $(self.name););
+    }
+}
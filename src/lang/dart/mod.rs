@@ -33,14 +33,19 @@
 //! # }
 //! ```
 
+mod class_header;
 mod doc_comment;
+mod field;
 
+pub use self::class_header::ClassHeader;
 pub use self::doc_comment::DocComment;
+pub use self::field::Field;
+pub use crate::tokens::Nullable;
 
 use crate as genco;
 use crate::fmt;
 use crate::quote_in;
-use crate::tokens::{quoted, ItemStr};
+use crate::tokens::{self, quoted, FormatInto, ItemStr};
 use std::fmt::Write as _;
 
 const SEP: &str = ".";
@@ -59,6 +64,43 @@ impl_lang! {
         type Format = Format;
         type Item = Import;
 
+        fn capabilities() -> crate::lang::Capabilities {
+            crate::lang::Capabilities {
+                string_interpolation: true,
+                import_aliasing: true,
+                block_comments: true,
+                ..Default::default()
+            }
+        }
+
+        fn block_comment_delimiters() -> Option<(&'static str, &'static str)> {
+            Some(("/*", "*/"))
+        }
+
+        fn unit_type() -> Option<&'static str> {
+            Some("void")
+        }
+
+        fn open_quote(
+            out: &mut fmt::Formatter<'_>,
+            _config: &Self::Config,
+            _format: &Self::Format,
+            _has_eval: bool,
+        ) -> fmt::Result {
+            out.write_char(out.quote_style().as_char())?;
+            Ok(())
+        }
+
+        fn close_quote(
+            out: &mut fmt::Formatter<'_>,
+            _config: &Self::Config,
+            _format: &Self::Format,
+            _has_eval: bool,
+        ) -> fmt::Result {
+            out.write_char(out.quote_style().as_char())?;
+            Ok(())
+        }
+
         fn string_eval_literal(
             out: &mut fmt::Formatter<'_>,
             _config: &Self::Config,
@@ -107,9 +149,12 @@ impl_lang! {
                     '\t' => out.write_str("\\t")?,
                     // vertical tab
                     '\u{0011}' => out.write_str("\\v")?,
-                    // Note: only relevant if we were to use single-quoted strings.
-                    // '\'' => out.write_str("\\'")?,
-                    '"' => out.write_str("\\\"")?,
+                    '\'' if out.quote_style() == fmt::QuoteStyle::Single => {
+                        out.write_str("\\'")?
+                    }
+                    '"' if out.quote_style() == fmt::QuoteStyle::Double => {
+                        out.write_str("\\\"")?
+                    }
                     '\\' => out.write_str("\\\\")?,
                     '$' => out.write_str("\\$")?,
                     c if !c.is_control() => out.write_char(c)?,
@@ -139,6 +184,16 @@ impl_lang! {
             tokens.format(out, config, &format)?;
             Ok(())
         }
+
+        fn format_imports(
+            tokens: &Tokens,
+            out: &mut fmt::Formatter<'_>,
+            config: &Self::Config,
+        ) -> fmt::Result {
+            let mut imports = Tokens::new();
+            Self::imports(&mut imports, tokens, config);
+            imports.format(out, config, &Format::default())
+        }
     }
 
     Import {
@@ -183,6 +238,33 @@ impl Import {
             ..self
         }
     }
+
+    /// Use the imported type as nullable, like `Foo?`.
+    ///
+    /// The underlying type is still imported.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    ///
+    /// let client = dart::import("package:http/http.dart", "Client");
+    ///
+    /// let toks = quote!($(client.nullable()) client;);
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "import \"package:http/http.dart\";",
+    ///         "",
+    ///         "Client? client;",
+    ///     ],
+    ///     toks.to_file_vec()?
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn nullable(self) -> Nullable<Import> {
+        nullable(self)
+    }
 }
 
 impl Dart {
@@ -293,3 +375,125 @@ where
 {
     DocComment(comment)
 }
+
+/// Construct the header of a class declaration, like
+/// `class Name extends Base with M1, M2 implements I1`.
+///
+/// Imports are collected from every referenced type, and the clauses are
+/// always emitted in the order required by the Dart grammar.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let base = dart::import("package:app/base.dart", "Base");
+/// let mixin = dart::import("package:app/mixins.dart", "Logging");
+/// let comparable = dart::import("dart:core", "Comparable");
+///
+/// let header = dart::class_header("Foo")
+///     .implements([quote!($comparable<Foo>)])
+///     .with_mixins([mixin])
+///     .extends(base);
+///
+/// let toks = quote! {
+///     $header {
+///         int compareTo(Foo other) => 0;
+///     }
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "import \"package:app/base.dart\";",
+///         "import \"package:app/mixins.dart\";",
+///         "",
+///         "class Foo extends Base with Logging implements Comparable<Foo> {",
+///         "    int compareTo(Foo other) => 0;",
+///         "}",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn class_header<N>(name: N) -> ClassHeader
+where
+    N: Into<ItemStr>,
+{
+    ClassHeader::new(name.into())
+}
+
+/// A nullable type, like `Foo?`.
+///
+/// Any imports of the wrapped type are still emitted. For imported types,
+/// [Import::nullable] can be used instead.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let client = dart::import("package:http/http.dart", "Client");
+///
+/// let toks = quote! {
+///     $(dart::nullable("String")) name;
+///     $(dart::nullable(quote!(List<$client>))) clients;
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "import \"package:http/http.dart\";",
+///         "",
+///         "String? name;",
+///         "List<Client>? clients;",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn nullable<T>(inner: T) -> Nullable<T>
+where
+    T: FormatInto<Dart>,
+{
+    tokens::nullable(inner)
+}
+
+/// Construct a field declaration, like `Foo name;`.
+///
+/// The field can be marked with [Field::late] and [Field::final_].
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let client = dart::import("package:http/http.dart", "Client");
+///
+/// let toks = quote! {
+///     class Api {
+///         $(dart::field(&client, "client").late().final_())
+///         $(dart::field(client.nullable(), "fallback"))
+///     }
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "import \"package:http/http.dart\";",
+///         "",
+///         "class Api {",
+///         "    late final Client client;",
+///         "    Client? fallback;",
+///         "}",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn field<T, N>(ty: T, name: N) -> Field
+where
+    T: FormatInto<Dart>,
+    N: Into<ItemStr>,
+{
+    let mut tokens = Tokens::new();
+    tokens.append(ty);
+    Field::new(tokens, name.into())
+}
@@ -44,13 +44,15 @@
 
 use crate as genco;
 use crate::fmt;
-use crate::quote_in;
-use crate::tokens::{quoted, ItemStr};
+use crate::tokens::{quoted, raw_quoted, FormatInto, ItemStr};
+use crate::{quote, quote_in};
 use std::collections::BTreeSet;
 use std::fmt::Write as _;
 
 const MODULE_SEP: &str = "/";
 const SEP: &str = ".";
+/// The name of a blank import, like `import _ "foo"`.
+const BLANK: &str = "_";
 
 /// Tokens container specialization for Go.
 pub type Tokens = crate::Tokens<Go>;
@@ -62,11 +64,36 @@ impl_lang! {
         type Format = Format;
         type Item = Import;
 
+        fn capabilities() -> crate::lang::Capabilities {
+            crate::lang::Capabilities {
+                raw_strings: true,
+                import_aliasing: true,
+                block_comments: true,
+                ..Default::default()
+            }
+        }
+
+        fn block_comment_delimiters() -> Option<(&'static str, &'static str)> {
+            Some(("/*", "*/"))
+        }
+
+        fn statement_terminator() -> &'static str {
+            ""
+        }
+
         fn write_quoted(out: &mut fmt::Formatter<'_>, input: &str) -> fmt::Result {
             // From: https://golang.org/src/strconv/quote.go
             super::c_family_write_quoted(out, input)
         }
 
+        fn raw_string(out: &mut fmt::Formatter<'_>, input: &str) -> Option<fmt::Result> {
+            if input.contains(['`', '\r', '\n']) {
+                return None;
+            }
+
+            Some(write!(out, "`{}`", input).map_err(fmt::Error::from))
+        }
+
         fn format_file(
             tokens: &Tokens,
             out: &mut fmt::Formatter<'_>,
@@ -79,17 +106,30 @@ impl_lang! {
                 header.line();
             }
 
-            Self::imports(&mut header, tokens);
+            Self::imports(&mut header, config, tokens);
             let format = Format::default();
             header.format(out, config, &format)?;
             tokens.format(out, config, &format)?;
             Ok(())
         }
+
+        fn format_imports(
+            tokens: &Tokens,
+            out: &mut fmt::Formatter<'_>,
+            config: &Self::Config,
+        ) -> fmt::Result {
+            let mut header = Tokens::new();
+            Self::imports(&mut header, config, tokens);
+            header.format(out, config, &Format::default())
+        }
     }
 
     Import {
         fn format(&self, out: &mut fmt::Formatter<'_>, _: &Config, _: &Format) -> fmt::Result {
-            if let Some(module) = self.module.rsplit(MODULE_SEP).next() {
+            if let Some(alias) = &self.alias {
+                out.write_str(alias)?;
+                out.write_str(SEP)?;
+            } else if let Some(module) = self.module.rsplit(MODULE_SEP).next() {
                 out.write_str(module)?;
                 out.write_str(SEP)?;
             }
@@ -109,6 +149,45 @@ pub struct Import {
     module: ItemStr,
     /// Name imported.
     name: ItemStr,
+    /// Alias of the imported package.
+    alias: Option<ItemStr>,
+}
+
+impl Import {
+    /// Alias the package as it's imported, like `import apiv1 "api/v1"`.
+    ///
+    /// This is needed when two imported packages share the same base name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    ///
+    /// let a = go::import("example.com/api/v1", "Thing").with_alias("apiv1");
+    /// let b = go::import("example.com/other/v1", "Thing");
+    ///
+    /// let toks = quote!($a $b);
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "import apiv1 \"example.com/api/v1\"",
+    ///         "import \"example.com/other/v1\"",
+    ///         "",
+    ///         "apiv1.Thing v1.Thing",
+    ///     ],
+    ///     toks.to_file_vec()?
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn with_alias<A>(self, alias: A) -> Self
+    where
+        A: Into<ItemStr>,
+    {
+        Self {
+            alias: Some(alias.into()),
+            ..self
+        }
+    }
 }
 
 /// Format for Go.
@@ -119,6 +198,7 @@ pub struct Format {}
 #[derive(Debug, Default)]
 pub struct Config {
     package: Option<ItemStr>,
+    import_grouping: bool,
 }
 
 impl Config {
@@ -126,28 +206,426 @@ impl Config {
     pub fn with_package<P: Into<ItemStr>>(self, package: P) -> Self {
         Self {
             package: Some(package.into()),
+            ..self
+        }
+    }
+
+    /// Configure whether imports should be grouped like `goimports` does.
+    ///
+    /// When enabled, imports are emitted in a single `import ( ... )` block
+    /// where standard library packages are separated from other packages by
+    /// a blank line. A package belongs to the standard library if the first
+    /// segment of its path doesn't contain a `.`, so `net/http` is standard
+    /// while `example.com/fmt` is not. A single import is still written as
+    /// `import "x"`. Defaults to `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    /// use genco::fmt;
+    ///
+    /// let println = go::import("fmt", "Println");
+    /// let get = go::import("net/http", "Get");
+    /// let errors = go::import("github.com/pkg/errors", "New");
+    /// let other = go::import("example.com/fmt", "Sprintf");
+    ///
+    /// let toks = quote! {
+    ///     $(&println)($get, $errors, $other)
+    /// };
+    ///
+    /// let config = go::Config::default().with_package("main").with_import_grouping(true);
+    /// let fmt = fmt::Config::from_lang::<Go>();
+    ///
+    /// let mut w = fmt::VecWriter::new();
+    /// toks.format_file(&mut w.as_formatter(&fmt), &config)?;
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "package main",
+    ///         "",
+    ///         "import (",
+    ///         "    \"fmt\"",
+    ///         "    \"net/http\"",
+    ///         "",
+    ///         "    \"example.com/fmt\"",
+    ///         "    \"github.com/pkg/errors\"",
+    ///         ")",
+    ///         "",
+    ///         "fmt.Println(http.Get, errors.New, fmt.Sprintf)",
+    ///     ],
+    ///     w.into_vec()
+    /// );
+    ///
+    /// let toks = quote!($println());
+    ///
+    /// let mut w = fmt::VecWriter::new();
+    /// toks.format_file(&mut w.as_formatter(&fmt), &config)?;
+    ///
+    /// assert_eq!(
+    ///     vec!["package main", "", "import \"fmt\"", "", "fmt.Println()"],
+    ///     w.into_vec()
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn with_import_grouping(self, import_grouping: bool) -> Self {
+        Self {
+            import_grouping,
+            ..self
+        }
+    }
+}
+
+/// A raw string literal in Go.
+///
+/// Created using the [raw_string()] function.
+#[derive(Debug, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct RawString {
+    value: ItemStr,
+}
+
+impl FormatInto<Go> for RawString {
+    fn format_into(self, tokens: &mut Tokens) {
+        tokens.append(raw_quoted(self.value));
+    }
+}
+
+/// A `defer` statement in Go.
+///
+/// Created using the [defer()] function.
+#[derive(Debug, Clone, Copy)]
+pub struct Defer<T> {
+    call: T,
+}
+
+impl<T> FormatInto<Go> for Defer<T>
+where
+    T: FormatInto<Go>,
+{
+    fn format_into(self, tokens: &mut Tokens) {
+        quote_in!(*tokens => defer $(self.call));
+    }
+}
+
+/// A `go` statement in Go.
+///
+/// Created using the [go_stmt()] function.
+#[derive(Debug, Clone, Copy)]
+pub struct GoStmt<T> {
+    call: T,
+}
+
+impl<T> FormatInto<Go> for GoStmt<T>
+where
+    T: FormatInto<Go>,
+{
+    fn format_into(self, tokens: &mut Tokens) {
+        quote_in!(*tokens => go $(self.call));
+    }
+}
+
+/// A pointer type in Go, like `*T`.
+///
+/// Created using the [pointer()] function.
+#[derive(Debug, Clone, Copy)]
+pub struct Pointer<T> {
+    inner: T,
+}
+
+impl<T> FormatInto<Go> for Pointer<T>
+where
+    T: FormatInto<Go>,
+{
+    fn format_into(self, tokens: &mut Tokens) {
+        quote_in!(*tokens => *$(self.inner));
+    }
+}
+
+/// A fixed size array type in Go, like `[N]T`.
+///
+/// Created using the [fixed_array()] function.
+#[derive(Debug, Clone, Copy)]
+pub struct FixedArray<T> {
+    len: usize,
+    inner: T,
+}
+
+impl<T> FormatInto<Go> for FixedArray<T>
+where
+    T: FormatInto<Go>,
+{
+    fn format_into(self, tokens: &mut Tokens) {
+        quote_in!(*tokens => [$(self.len)]$(self.inner));
+    }
+}
+
+/// The direction of a channel type in Go.
+///
+/// Used with the [channel()] function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChanDir {
+    /// A bidirectional channel, like `chan T`.
+    Both,
+    /// A receive-only channel, like `<-chan T`.
+    Recv,
+    /// A send-only channel, like `chan<- T`.
+    Send,
+}
+
+/// A channel type in Go, like `chan T`.
+///
+/// Created using the [channel()] function.
+#[derive(Debug, Clone, Copy)]
+pub struct Channel<T> {
+    dir: ChanDir,
+    inner: T,
+}
+
+impl<T> FormatInto<Go> for Channel<T>
+where
+    T: FormatInto<Go>,
+{
+    fn format_into(self, tokens: &mut Tokens) {
+        match self.dir {
+            ChanDir::Both => quote_in!(*tokens => chan $(self.inner)),
+            ChanDir::Recv => quote_in!(*tokens => <-chan $(self.inner)),
+            ChanDir::Send => quote_in!(*tokens => chan<- $(self.inner)),
+        }
+    }
+}
+
+/// A function type in Go, like `func(int, string) (T, error)`.
+///
+/// Created using the [func_type()] function.
+#[derive(Debug, Clone)]
+pub struct FuncType {
+    params: Vec<Tokens>,
+    returns: Vec<Tokens>,
+}
+
+impl FormatInto<Go> for FuncType {
+    fn format_into(self, tokens: &mut Tokens) {
+        quote_in!(*tokens => func($(for p in self.params join (, ) => $p)));
+
+        if self.returns.is_empty() {
+            return;
+        }
+
+        tokens.space();
+
+        if self.returns.len() == 1 {
+            tokens.extend(self.returns.into_iter().flatten());
+        } else {
+            quote_in!(*tokens => ($(for r in self.returns join (, ) => $r)));
+        }
+    }
+}
+
+/// A struct tag in Go, like `` `json:"name,omitempty"` ``.
+///
+/// Created using the [struct_tag()] function.
+#[derive(Debug, Clone)]
+pub struct StructTag {
+    tags: Vec<(ItemStr, ItemStr)>,
+}
+
+impl FormatInto<Go> for StructTag {
+    fn format_into(self, tokens: &mut Tokens) {
+        if self.tags.is_empty() {
+            return;
+        }
+
+        let mut tag = String::new();
+
+        for (key, value) in &self.tags {
+            if !tag.is_empty() {
+                tag.push(' ');
+            }
+
+            tag.push_str(key);
+            tag.push_str(":\"");
+
+            // NB: values are unquoted with strconv.Unquote by reflect.
+            for c in value.chars() {
+                match c {
+                    '"' => tag.push_str("\\\""),
+                    '\\' => tag.push_str("\\\\"),
+                    '\n' => tag.push_str("\\n"),
+                    '\r' => tag.push_str("\\r"),
+                    '\t' => tag.push_str("\\t"),
+                    c if c.is_control() => {
+                        let _ = write!(tag, "\\u{:04x}", c as u32);
+                    }
+                    c => tag.push(c),
+                }
+            }
+
+            tag.push('"');
+        }
+
+        tokens.append(raw_quoted(tag));
+    }
+}
+
+/// A `var ( ... )` or `const ( ... )` block in Go.
+///
+/// Created using the [var_block()] or [const_block()] functions.
+#[derive(Debug, Clone)]
+pub struct DeclBlock {
+    keyword: &'static str,
+    decls: Vec<(ItemStr, Option<Tokens>, Option<Tokens>)>,
+}
+
+impl DeclBlock {
+    fn new<I, N, T, V>(keyword: &'static str, decls: I) -> Self
+    where
+        I: IntoIterator<Item = (N, Option<T>, Option<V>)>,
+        N: Into<ItemStr>,
+        T: FormatInto<Go>,
+        V: FormatInto<Go>,
+    {
+        let decls = decls
+            .into_iter()
+            .map(|(name, ty, value)| {
+                (
+                    name.into(),
+                    ty.map(|ty| quote!($ty)),
+                    value.map(|value| quote!($value)),
+                )
+            })
+            .collect();
+
+        Self { keyword, decls }
+    }
+}
+
+impl FormatInto<Go> for DeclBlock {
+    fn format_into(self, tokens: &mut Tokens) {
+        // Types are measured by how they render, which includes the package
+        // qualifier of imported names.
+        let types = self
+            .decls
+            .iter()
+            .map(|(_, ty, _)| match ty {
+                Some(ty) => ty.to_string().map(|s| s.chars().count()).unwrap_or(0),
+                None => 0,
+            })
+            .collect::<Vec<_>>();
+
+        let name_width = self
+            .decls
+            .iter()
+            .map(|(name, _, _)| name.chars().count())
+            .max()
+            .unwrap_or(0);
+
+        let has_types = self.decls.iter().any(|(_, ty, _)| ty.is_some());
+        let type_width = types.iter().copied().max().unwrap_or(0);
+
+        tokens.append(self.keyword);
+        tokens.space();
+        tokens.append("(");
+
+        if self.decls.is_empty() {
+            tokens.append(")");
+            return;
+        }
+
+        tokens.indent();
+
+        for ((name, ty, value), len) in self.decls.into_iter().zip(types) {
+            tokens.push();
+
+            let padding = name_width - name.chars().count();
+            tokens.append(name);
+
+            if ty.is_none() && value.is_none() {
+                continue;
+            }
+
+            tokens.append(pad(padding + 1));
+
+            if let Some(ty) = ty {
+                tokens.append(ty);
+            }
+
+            if let Some(value) = value {
+                if has_types {
+                    tokens.append(pad(type_width - len + 1));
+                }
+
+                quote_in!(*tokens => = $value);
+            }
+        }
+
+        tokens.unindent();
+        tokens.push();
+        tokens.append(")");
+
+        fn pad(n: usize) -> String {
+            " ".repeat(n)
         }
     }
 }
 
 impl Go {
-    fn imports(out: &mut Tokens, tokens: &Tokens) {
+    fn imports(out: &mut Tokens, config: &Config, tokens: &Tokens) {
         let mut modules = BTreeSet::new();
 
         for import in tokens.walk_imports() {
-            modules.insert(&import.module);
+            modules.insert((&import.module, import.alias.as_ref()));
         }
 
+        // A blank import is only needed for its side effects, which also
+        // happen if the package is imported under any other name.
+        let imported = modules
+            .iter()
+            .filter(|(_, alias)| alias.map(|a| a.as_ref()) != Some(BLANK))
+            .map(|(module, _)| *module)
+            .collect::<BTreeSet<_>>();
+
+        modules.retain(|(module, alias)| {
+            alias.map(|a| a.as_ref()) != Some(BLANK) || !imported.contains(module)
+        });
+
         if modules.is_empty() {
             return;
         }
 
-        for module in modules {
-            quote_in!(*out => import $(quoted(module)));
+        if config.import_grouping && modules.len() > 1 {
+            let (std, other): (Vec<_>, Vec<_>) = modules
+                .into_iter()
+                .partition(|(m, _)| !m.split('/').next().unwrap_or_default().contains('.'));
+
+            quote_in! { *out =>
+                import (
+                    $(for m in &std join ($['\r']) => $(ref o => spec(o, *m)))
+                    $(if !std.is_empty() && !other.is_empty() {
+                        $['\n']
+                    })
+                    $(for m in other join ($['\r']) => $(ref o => spec(o, m)))
+                )
+            }
+
+            out.line();
+            return;
+        }
+
+        for m in modules {
+            quote_in!(*out => import $(ref o => spec(o, m)));
             out.push();
         }
 
         out.line();
+
+        /// Write an import spec, like `alias "module"`.
+        fn spec(out: &mut Tokens, (module, alias): (&ItemStr, Option<&ItemStr>)) {
+            if let Some(alias) = alias {
+                quote_in!(*out => $alias $(quoted(module)));
+            } else {
+                quote_in!(*out => $(quoted(module)));
+            }
+        }
     }
 }
 
@@ -182,5 +660,457 @@ where
     Import {
         module: module.into(),
         name: name.into(),
+        alias: None,
+    }
+}
+
+/// A blank import of a Go package for its side effects, like
+/// `import _ "github.com/lib/pq"`.
+///
+/// The import doesn't render anything, so it's added to a token stream
+/// through [register][crate::tokens::register()]. Blank imports of the same
+/// package are deduplicated, and they're left out entirely if the package is
+/// also imported under another name since its side effects happen either way.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let open = go::import("database/sql", "Open");
+///
+/// let toks = quote! {
+///     $(register(go::blank_import("github.com/lib/pq")))
+///     $(register(go::blank_import("github.com/lib/pq")))
+///     $(register(go::blank_import("database/sql")))
+///
+///     db, err := $open("postgres", dsn)
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "import \"database/sql\"",
+///         "import _ \"github.com/lib/pq\"",
+///         "",
+///         "db, err := sql.Open(\"postgres\", dsn)",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn blank_import<M>(module: M) -> Import
+where
+    M: Into<ItemStr>,
+{
+    Import {
+        module: module.into(),
+        name: ItemStr::Static(""),
+        alias: Some(ItemStr::Static(BLANK)),
     }
 }
+
+/// A raw string literal in Go, like `` `C:\Windows` ``.
+///
+/// Escape sequences are not processed in raw strings, which makes them
+/// convenient for paths and regular expressions. If the content can't be
+/// represented as a single-line raw string because it contains backticks or
+/// line breaks, this falls back to a regular quoted string.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let toks: go::Tokens = quote! {
+///     path := $(go::raw_string("C:\\Windows\\System32"))
+///     re := $(go::raw_string("^\\d+$"))
+///     quote := $(go::raw_string("a `b`"))
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "path := `C:\\Windows\\System32`",
+///         "re := `^\\d+$`",
+///         "quote := \"a `b`\"",
+///     ],
+///     toks.to_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn raw_string<S>(value: S) -> RawString
+where
+    S: Into<ItemStr>,
+{
+    RawString {
+        value: value.into(),
+    }
+}
+
+/// A `defer` statement in Go, like `defer f.Close()`.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let mutex = go::import("sync", "Mutex");
+///
+/// let toks: go::Tokens = quote! {
+///     var mu $mutex
+///     mu.Lock()
+///     $(go::defer(quote!(mu.Unlock())))
+///     $(go::defer(quote!(f.Close())))
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "import \"sync\"",
+///         "",
+///         "var mu sync.Mutex",
+///         "mu.Lock()",
+///         "defer mu.Unlock()",
+///         "defer f.Close()",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn defer<T>(call: T) -> Defer<T>
+where
+    T: FormatInto<Go>,
+{
+    Defer { call }
+}
+
+/// A `go` statement in Go, like `go worker(jobs)`.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let serve = go::import("net/http", "ListenAndServe");
+///
+/// let toks: go::Tokens = quote! {
+///     $(go::go_stmt(quote!(worker(jobs))))
+///     $(go::go_stmt(quote!($serve(":8080", nil))))
+///     $(go::go_stmt(quote!(func() { done <- true }())))
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "import \"net/http\"",
+///         "",
+///         "go worker(jobs)",
+///         "go http.ListenAndServe(\":8080\", nil)",
+///         "go func() { done <- true }()",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn go_stmt<T>(call: T) -> GoStmt<T>
+where
+    T: FormatInto<Go>,
+{
+    GoStmt { call }
+}
+
+/// A pointer type in Go, like `*T`.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let client = go::import("net/http", "Client");
+///
+/// let toks: go::Tokens = quote! {
+///     var c $(go::pointer(client))
+///     var p $(go::pointer(go::pointer("int")))
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "import \"net/http\"",
+///         "",
+///         "var c *http.Client",
+///         "var p **int",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn pointer<T>(inner: T) -> Pointer<T>
+where
+    T: FormatInto<Go>,
+{
+    Pointer { inner }
+}
+
+/// A fixed size array type in Go, like `[N]T`.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let addr = go::import("net/netip", "Addr");
+///
+/// let toks: go::Tokens = quote! {
+///     var digest $(go::fixed_array(32, "byte"))
+///     var peers $(go::fixed_array(4, go::pointer(addr)))
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "import \"net/netip\"",
+///         "",
+///         "var digest [32]byte",
+///         "var peers [4]*netip.Addr",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn fixed_array<T>(len: usize, inner: T) -> FixedArray<T>
+where
+    T: FormatInto<Go>,
+{
+    FixedArray { len, inner }
+}
+
+/// A channel type in Go, like `chan T`, `<-chan T` or `chan<- T` depending on
+/// the given [ChanDir].
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+/// use genco::lang::go::ChanDir;
+///
+/// let duration = go::import("time", "Duration");
+///
+/// let toks: go::Tokens = quote! {
+///     var jobs $(go::channel(ChanDir::Both, "int"))
+///     var ticks $(go::channel(ChanDir::Recv, &duration))
+///     var results $(go::channel(ChanDir::Send, go::pointer(duration)))
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "import \"time\"",
+///         "",
+///         "var jobs chan int",
+///         "var ticks <-chan time.Duration",
+///         "var results chan<- *time.Duration",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn channel<T>(dir: ChanDir, inner: T) -> Channel<T>
+where
+    T: FormatInto<Go>,
+{
+    Channel { dir, inner }
+}
+
+/// A function type in Go, like `func(int, string) (T, error)`.
+///
+/// The parameter and return types are given without names. The return types
+/// are left out if there are none, written as they are if there's one, and
+/// wrapped in parenthesis if there's more than one. Imports are collected
+/// from all of the types.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let ctx = go::import("context", "Context");
+/// let request = go::import("net/http", "Request");
+///
+/// let toks: go::Tokens = quote! {
+///     type Handler struct {
+///         OnStart $(go::func_type(Vec::<go::Tokens>::new(), Vec::<go::Tokens>::new()))
+///         Handle $(go::func_type([quote!($ctx), quote!($(go::pointer(request)))], ["error"]))
+///         Lookup $(go::func_type(["string"], ["int", "bool"]))
+///     }
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "import \"context\"",
+///         "import \"net/http\"",
+///         "",
+///         "type Handler struct {",
+///         "    OnStart func()",
+///         "    Handle func(context.Context, *http.Request) error",
+///         "    Lookup func(string) (int, bool)",
+///         "}",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn func_type<P, R>(params: P, returns: R) -> FuncType
+where
+    P: IntoIterator,
+    P::Item: FormatInto<Go>,
+    R: IntoIterator,
+    R::Item: FormatInto<Go>,
+{
+    fn collect<I>(types: I) -> Vec<Tokens>
+    where
+        I: IntoIterator,
+        I::Item: FormatInto<Go>,
+    {
+        types
+            .into_iter()
+            .map(|ty| {
+                let mut tokens = Tokens::new();
+                tokens.append(ty);
+                tokens
+            })
+            .collect()
+    }
+
+    FuncType {
+        params: collect(params),
+        returns: collect(returns),
+    }
+}
+
+/// A struct tag in Go, like `` `json:"name,omitempty" db:"name"` ``.
+///
+/// Each tag is a key and a value, where the value is quoted and escaped the
+/// way [reflect.StructTag] expects. The tag is written as a raw string, or
+/// as an interpreted string if it contains a backtick. Nothing is rendered
+/// if there are no tags.
+///
+/// [reflect.StructTag]: https://pkg.go.dev/reflect#StructTag
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let toks: go::Tokens = quote! {
+///     type User struct {
+///         Name string $(go::struct_tag([("json", "name,omitempty"), ("db", "name")]))
+///         Note string $(go::struct_tag([("doc", "say \"hi\"")]))
+///         Raw string $(go::struct_tag([("doc", "`code`")]))
+///         Id int$(go::struct_tag(Vec::<(&str, &str)>::new()))
+///     }
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "type User struct {",
+///         "    Name string `json:\"name,omitempty\" db:\"name\"`",
+///         "    Note string `doc:\"say \\\"hi\\\"\"`",
+///         "    Raw string \"doc:\\\"`code`\\\"\"",
+///         "    Id int",
+///         "}",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn struct_tag<I, K, V>(tags: I) -> StructTag
+where
+    I: IntoIterator<Item = (K, V)>,
+    K: Into<ItemStr>,
+    V: Into<ItemStr>,
+{
+    StructTag {
+        tags: tags
+            .into_iter()
+            .map(|(key, value)| (key.into(), value.into()))
+            .collect(),
+    }
+}
+
+/// A `var ( ... )` block in Go, with names and types aligned like gofmt does.
+///
+/// Each declaration is a name, with an optional type and an optional value.
+/// Imports used in types and values are collected as usual.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let duration = go::import("time", "Duration");
+///
+/// let toks: go::Tokens = quote! {
+///     $(go::var_block([
+///         ("timeout", Some(quote!($(&duration))), Some(quote!(30 * $(&duration)(1)))),
+///         ("retries", Some(quote!(int)), None),
+///         ("name", None, Some(quote!($(quoted("server"))))),
+///         ("verbose", Some(quote!(bool)), Some(quote!(false))),
+///     ]))
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "import \"time\"",
+///         "",
+///         "var (",
+///         "    timeout time.Duration = 30 * time.Duration(1)",
+///         "    retries int",
+///         "    name                  = \"server\"",
+///         "    verbose bool          = false",
+///         ")",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn var_block<I, N, T, V>(decls: I) -> DeclBlock
+where
+    I: IntoIterator<Item = (N, Option<T>, Option<V>)>,
+    N: Into<ItemStr>,
+    T: FormatInto<Go>,
+    V: FormatInto<Go>,
+{
+    DeclBlock::new("var", decls)
+}
+
+/// A `const ( ... )` block in Go, aligned in the same way as [var_block()].
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let toks: go::Tokens = quote! {
+///     $(go::const_block([
+///         ("Red", Some("Color"), Some("iota")),
+///         ("Green", None, None),
+///         ("Blue", None, None),
+///     ]))
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "const (",
+///         "    Red   Color = iota",
+///         "    Green",
+///         "    Blue",
+///         ")",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn const_block<I, N, T, V>(decls: I) -> DeclBlock
+where
+    I: IntoIterator<Item = (N, Option<T>, Option<V>)>,
+    N: Into<ItemStr>,
+    T: FormatInto<Go>,
+    V: FormatInto<Go>,
+{
+    DeclBlock::new("const", decls)
+}
@@ -0,0 +1,349 @@
+//! Specialization for HTML code generation.
+//!
+//! Text content is written through [text()], which escapes `&`, `<` and `>`.
+//! Quoted strings are used for attribute values, so they are written with
+//! double quotes in which `"` and `'` are escaped as well. This means that
+//! string literals in [quote!][crate::quote], like `class="nav"`, are
+//! written as attribute values.
+//!
+//! Elements can also be built through [element()], which takes care of
+//! escaping and of [void elements] like `<br>` which don't have any content
+//! or closing tag. Names of elements and attributes can't be escaped, so
+//! formatting fails with [fmt::Error::UnsupportedChar] if they contain
+//! whitespace or one of `"`, `'`, `>`, `/` and `=`.
+//!
+//! HTML only has block comments, so line comments and editor region markers
+//! are written as `<!-- .. -->`.
+//!
+//! [void elements]: https://html.spec.whatwg.org/multipage/syntax.html#void-elements
+//!
+//! # Examples
+//!
+//! ```rust
+//! use genco::prelude::*;
+//!
+//! # fn main() -> genco::fmt::Result {
+//! let title = "Fish & Chips";
+//!
+//! let toks: html::Tokens = quote! {
+//!     <nav class="main">
+//!       <a href=$(quoted("/?a=1&b=2"))>$(html::text(title))</a>
+//!     </nav>
+//! };
+//!
+//! assert_eq!(
+//!     vec![
+//!         "<nav class=\"main\">",
+//!         "  <a href=\"/?a=1&amp;b=2\">Fish &amp; Chips</a>",
+//!         "</nav>",
+//!     ],
+//!     toks.to_file_vec()?
+//! );
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # String Quoting in HTML
+//!
+//! Attribute values are quoted with double quotes, in which `&`, `<`, `>`,
+//! `"` and `'` are written as character references.
+//!
+//! ```rust
+//! use genco::prelude::*;
+//!
+//! # fn main() -> genco::fmt::Result {
+//! let toks: html::Tokens = quote!(<input value=$(quoted("<\"it's\" & more>"))>);
+//! assert_eq!(
+//!     "<input value=\"&lt;&quot;it&#39;s&quot; &amp; more&gt;\">",
+//!     toks.to_string()?
+//! );
+//! # Ok(())
+//! # }
+//! ```
+
+use crate as genco;
+use crate::fmt;
+use crate::quote_in;
+use crate::tokens::{quoted, static_literal, FormatInto, Item, ItemStr};
+use std::fmt::Write as _;
+
+/// Tokens container specialization for HTML.
+pub type Tokens = crate::Tokens<Html>;
+
+/// Elements which never have any content, and are written without a closing
+/// tag.
+const VOID_ELEMENTS: &[&str] = &[
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "source", "track",
+    "wbr",
+];
+
+impl_lang! {
+    /// Language specialization for HTML.
+    pub Html {
+        type Config = Config;
+        type Format = Format;
+        type Item = Name;
+
+        fn capabilities() -> crate::lang::Capabilities {
+            crate::lang::Capabilities {
+                block_comments: true,
+                ..Default::default()
+            }
+        }
+
+        fn default_indentation() -> fmt::Indentation {
+            fmt::Indentation::Space(2)
+        }
+
+        fn line_comment_prefix() -> Option<&'static str> {
+            None
+        }
+
+        fn block_comment_delimiters() -> Option<(&'static str, &'static str)> {
+            Some(("<!--", "-->"))
+        }
+
+        fn statement_terminator() -> &'static str {
+            ""
+        }
+
+        fn write_quoted(out: &mut fmt::Formatter<'_>, input: &str) -> fmt::Result {
+            // From: https://html.spec.whatwg.org/multipage/syntax.html#attributes-2
+            escape(out, input, true)
+        }
+    }
+
+    Name {
+        fn format(&self, out: &mut fmt::Formatter<'_>, _: &Config, _: &Format) -> fmt::Result {
+            // From: https://html.spec.whatwg.org/multipage/syntax.html#syntax-attribute-name
+            for (index, c) in self.name.char_indices() {
+                if c.is_whitespace() || c.is_control() || matches!(c, '"' | '\'' | '>' | '/' | '=') {
+                    return Err(fmt::Error::UnsupportedChar {
+                        character: c,
+                        index,
+                    });
+                }
+            }
+
+            out.write_str(&self.name)?;
+            Ok(())
+        }
+    }
+}
+
+/// Write the input with characters that have a special meaning written as
+/// character references. Quotes are only escaped in attribute values.
+fn escape<W>(out: &mut W, input: &str, attribute: bool) -> fmt::Result
+where
+    W: std::fmt::Write,
+{
+    for c in input.chars() {
+        match c {
+            '&' => out.write_str("&amp;")?,
+            '<' => out.write_str("&lt;")?,
+            '>' => out.write_str("&gt;")?,
+            '"' if attribute => out.write_str("&quot;")?,
+            '\'' if attribute => out.write_str("&#39;")?,
+            c => out.write_char(c)?,
+        }
+    }
+
+    Ok(())
+}
+
+/// Format state for HTML.
+#[derive(Debug, Default)]
+pub struct Format {}
+
+/// Configuration for HTML.
+#[derive(Debug, Default)]
+pub struct Config {}
+
+/// The name of an element or an attribute, which is checked when it's
+/// formatted.
+///
+/// Created through the [element()] function and [Element::attr].
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Name {
+    name: ItemStr,
+}
+
+/// Escaped text content.
+///
+/// Created through the [text()] function.
+#[derive(Debug, Clone)]
+pub struct Text {
+    text: ItemStr,
+}
+
+impl FormatInto<Html> for Text {
+    fn format_into(self, tokens: &mut Tokens) {
+        let mut s = String::with_capacity(self.text.len());
+
+        if escape(&mut s, &self.text, false).is_ok() {
+            tokens.append(s);
+        }
+    }
+}
+
+/// An HTML element, like `<p class="note">..</p>`.
+///
+/// Created through the [element()] function.
+#[derive(Debug, Clone)]
+pub struct Element {
+    name: ItemStr,
+    attributes: Vec<(ItemStr, Option<ItemStr>)>,
+    children: Vec<Tokens>,
+}
+
+impl Element {
+    /// Add an attribute, like `class="note"`. The value is escaped.
+    pub fn attr<N, V>(mut self, name: N, value: V) -> Self
+    where
+        N: Into<ItemStr>,
+        V: Into<ItemStr>,
+    {
+        self.attributes.push((name.into(), Some(value.into())));
+        self
+    }
+
+    /// Add a boolean attribute without a value, like `disabled`.
+    pub fn flag<N>(mut self, name: N) -> Self
+    where
+        N: Into<ItemStr>,
+    {
+        self.attributes.push((name.into(), None));
+        self
+    }
+
+    /// Add a child, like another element or [text()].
+    ///
+    /// Void elements like `<br>` never have any content, so children added to
+    /// them are not rendered.
+    pub fn child<T>(mut self, child: T) -> Self
+    where
+        T: FormatInto<Html>,
+    {
+        let mut tokens = Tokens::new();
+        tokens.append(child);
+        self.children.push(tokens);
+        self
+    }
+
+    /// Test if the element is a void element, like `<br>`.
+    pub fn is_void(&self) -> bool {
+        VOID_ELEMENTS.contains(&self.name.to_ascii_lowercase().as_str())
+    }
+}
+
+impl FormatInto<Html> for Element {
+    fn format_into(self, tokens: &mut Tokens) {
+        let is_void = self.is_void();
+
+        quote_in!(*tokens => <$(Name { name: self.name.clone() }));
+
+        for (name, value) in self.attributes {
+            tokens.space();
+            tokens.append(Name { name });
+
+            if let Some(value) = value {
+                quote_in!(*tokens => =$(quoted(value)));
+            }
+        }
+
+        tokens.append(static_literal(">"));
+
+        if is_void {
+            return;
+        }
+
+        let inline = match &self.children[..] {
+            [] => true,
+            [child] => !child.iter().any(|item| {
+                matches!(
+                    item,
+                    Item::Push | Item::Line | Item::BlankLine | Item::Indentation(_)
+                )
+            }),
+            _ => false,
+        };
+
+        if inline {
+            tokens.extend(self.children.into_iter().flatten());
+        } else {
+            tokens.indent();
+
+            for child in self.children {
+                tokens.push();
+                tokens.append(child);
+            }
+
+            tokens.unindent();
+        }
+
+        quote_in!(*tokens => </$(Name { name: self.name })>);
+    }
+}
+
+/// Text content, where `&`, `<` and `>` are escaped.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let toks: html::Tokens = quote!(<p>$(html::text("1 < 2 & \"3\" > 0"))</p>);
+/// assert_eq!("<p>1 &lt; 2 &amp; \"3\" &gt; 0</p>", toks.to_string()?);
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn text<T>(text: T) -> Text
+where
+    T: Into<ItemStr>,
+{
+    Text { text: text.into() }
+}
+
+/// An HTML element with attributes and children.
+///
+/// An element with a single child which fits on one line is written on one
+/// line, while other children are written on lines of their own, indented
+/// one level deeper. Void elements like `<br>` and `<img>` are written
+/// without a closing tag.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let list = html::element("ul")
+///     .attr("class", "items")
+///     .child(html::element("li").child(html::text("Fish & Chips")))
+///     .child(html::element("li").child(html::element("img").attr("src", "a.png")))
+///     .child(html::element("li").child(html::element("input").flag("disabled")))
+///     .child(html::element("li"));
+///
+/// let toks: html::Tokens = quote!($list);
+///
+/// assert_eq!(
+///     vec![
+///         "<ul class=\"items\">",
+///         "  <li>Fish &amp; Chips</li>",
+///         "  <li><img src=\"a.png\"></li>",
+///         "  <li><input disabled></li>",
+///         "  <li></li>",
+///         "</ul>",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn element<N>(name: N) -> Element
+where
+    N: Into<ItemStr>,
+{
+    Element {
+        name: name.into(),
+        attributes: Vec::new(),
+        children: Vec::new(),
+    }
+}
@@ -0,0 +1,59 @@
+use crate as genco;
+use crate::lang::Java;
+use crate::quote_in;
+use crate::tokens::{FormatInto, ItemStr};
+use crate::Tokens;
+
+/// An annotation, like `@Override` or `@JsonProperty(value = "name")`.
+///
+/// This struct is created by the [annotation][super::annotation()] function.
+#[derive(Debug, Clone)]
+pub struct Annotation {
+    ty: Tokens<Java>,
+    arguments: Vec<(ItemStr, Tokens<Java>)>,
+}
+
+impl Annotation {
+    pub(super) fn new(ty: Tokens<Java>) -> Self {
+        Self {
+            ty,
+            arguments: Vec::new(),
+        }
+    }
+
+    /// Add a named argument to the annotation, like `value = "name"`.
+    pub fn with_argument<N, V>(mut self, name: N, value: V) -> Self
+    where
+        N: Into<ItemStr>,
+        V: FormatInto<Java>,
+    {
+        let mut tokens = Tokens::new();
+        tokens.append(value);
+        self.arguments.push((name.into(), tokens));
+        self
+    }
+
+    /// Add several named arguments to the annotation.
+    pub fn with_arguments<I, N, V>(self, arguments: I) -> Self
+    where
+        I: IntoIterator<Item = (N, V)>,
+        N: Into<ItemStr>,
+        V: FormatInto<Java>,
+    {
+        arguments
+            .into_iter()
+            .fold(self, |a, (name, value)| a.with_argument(name, value))
+    }
+}
+
+impl FormatInto<Java> for Annotation {
+    fn format_into(self, tokens: &mut Tokens<Java>) {
+        quote_in!(*tokens => @$(self.ty));
+
+        if !self.arguments.is_empty() {
+            quote_in! { *tokens =>
+                ($(for (name, value) in self.arguments join (, ) => $name = $value))
+            };
+        }
+    }
+}
@@ -0,0 +1,208 @@
+use crate::lang::{Java, Kotlin, Lang};
+use crate::tokens::{self, Deferred, FormatInto, Item, ItemStr};
+use crate::Tokens;
+use std::mem;
+
+/// A structured documentation comment, like Javadoc or KDoc.
+///
+/// This struct is created by the [doc][super::doc()] function.
+///
+/// If the formatter is configured with a [maximum width], the text of the
+/// comment is wrapped so that lines including their indentation are no
+/// longer than it, if possible. Words which don't fit are put on a line of
+/// their own, and the continuation lines of tags are indented by four spaces.
+///
+/// [maximum width]: crate::fmt::Config::with_max_width
+///
+/// # Examples
+///
+/// ```
+/// use genco::fmt;
+/// use genco::prelude::*;
+///
+/// let doc = java::doc("Compute the sum of all the values in the given list.")
+///     .param("values", "the values to sum, which may be empty");
+///
+/// let toks: java::Tokens = quote! {
+///     class Sum {
+///         $doc
+///         int sum(List<Integer> values);
+///     }
+/// };
+///
+/// let fmt = fmt::Config::from_lang::<Java>().with_max_width(37);
+/// let mut w = fmt::VecWriter::new();
+/// toks.format_file(&mut w.as_formatter(&fmt), &java::Config::default())?;
+///
+/// assert_eq!(
+///     vec![
+///         "class Sum {",
+///         "    /**",
+///         "     * Compute the sum of all the",
+///         "     * values in the given list.",
+///         "     *",
+///         "     * @param values the values to",
+///         "     *     sum, which may be empty",
+///         "     */",
+///         "    int sum(List<Integer> values);",
+///         "}",
+///     ],
+///     w.into_vec()
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct Doc {
+    description: ItemStr,
+    tags: Vec<(&'static str, ItemStr)>,
+}
+
+impl Doc {
+    pub(super) fn new(description: ItemStr) -> Self {
+        Self {
+            description,
+            tags: Vec::new(),
+        }
+    }
+
+    /// Document a parameter, like `@param name the description`.
+    pub fn param<N, T>(self, name: N, text: T) -> Self
+    where
+        N: Into<ItemStr>,
+        T: Into<ItemStr>,
+    {
+        self.tag("@param", format!("{} {}", name.into(), text.into()))
+    }
+
+    /// Document the return value, like `@return the description`.
+    pub fn returns<T>(self, text: T) -> Self
+    where
+        T: Into<ItemStr>,
+    {
+        self.tag("@return", text.into())
+    }
+
+    /// Document an exception, like `@throws IOException the description`.
+    pub fn throws<N, T>(self, name: N, text: T) -> Self
+    where
+        N: Into<ItemStr>,
+        T: Into<ItemStr>,
+    {
+        self.tag("@throws", format!("{} {}", name.into(), text.into()))
+    }
+
+    fn tag(mut self, tag: &'static str, text: impl Into<ItemStr>) -> Self {
+        self.tags.push((tag, text.into()));
+        self
+    }
+
+    fn format<L>(&self, tokens: &mut Tokens<L>, width: Option<usize>)
+    where
+        L: Lang,
+    {
+        let mut lines = Vec::new();
+
+        for line in self.description.lines() {
+            wrap(&mut lines, line.trim_end(), "", width);
+        }
+
+        while matches!(lines.last(), Some(line) if line.is_empty()) {
+            lines.pop();
+        }
+
+        if !lines.is_empty() && !self.tags.is_empty() {
+            lines.push(String::new());
+        }
+
+        for (tag, text) in &self.tags {
+            let text = format!(
+                "{tag} {}",
+                text.split_whitespace().collect::<Vec<_>>().join(" ")
+            );
+            wrap(&mut lines, &text, "    ", width);
+        }
+
+        if lines.is_empty() {
+            return;
+        }
+
+        tokens.push();
+        tokens.append(tokens::static_literal("/**"));
+        tokens.push();
+
+        for line in lines {
+            tokens.space();
+            tokens.append(tokens::static_literal("*"));
+
+            if !line.is_empty() {
+                tokens.space();
+                tokens.append(line);
+            }
+
+            tokens.push();
+        }
+
+        tokens.space();
+        tokens.append(tokens::static_literal("*/"));
+    }
+}
+
+/// Wrap a line of text into lines no longer than the given width, where each
+/// continuation line is prefixed with the given indentation.
+fn wrap(lines: &mut Vec<String>, text: &str, indent: &str, width: Option<usize>) {
+    let width = match width {
+        Some(width) if !text.is_empty() => width,
+        _ => {
+            lines.push(text.to_owned());
+            return;
+        }
+    };
+
+    let mut current = String::new();
+
+    for word in text.split_whitespace() {
+        if current.is_empty() {
+            current.push_str(word);
+            continue;
+        }
+
+        if current.chars().count() + 1 + word.chars().count() > width {
+            lines.push(mem::take(&mut current));
+            current.push_str(indent);
+            current.push_str(word);
+        } else {
+            current.push(' ');
+            current.push_str(word);
+        }
+    }
+
+    lines.push(current);
+}
+
+impl Doc {
+    /// Defer formatting until the indentation of the comment is known, so
+    /// that the text can be wrapped to the maximum width of the formatter.
+    fn format_deferred<L>(self, tokens: &mut Tokens<L>)
+    where
+        L: Lang,
+    {
+        tokens.item(Item::Deferred(Deferred::with_width(move |_, width| {
+            let mut tokens = Tokens::new();
+            // Account for the ` * ` prefix of each line.
+            self.format(&mut tokens, width.map(|width| width.saturating_sub(3)));
+            tokens
+        })));
+    }
+}
+
+impl FormatInto<Java> for Doc {
+    fn format_into(self, tokens: &mut Tokens<Java>) {
+        self.format_deferred(tokens);
+    }
+}
+
+impl FormatInto<Kotlin> for Doc {
+    fn format_into(self, tokens: &mut Tokens<Kotlin>) {
+        self.format_deferred(tokens);
+    }
+}
@@ -15,13 +15,19 @@
 //! # }
 //! ```
 
+mod annotation;
 mod block_comment;
+mod doc;
+mod pojo;
 
+pub use self::annotation::Annotation;
 pub use self::block_comment::BlockComment;
+pub use self::doc::Doc;
+pub use self::pojo::Pojo;
 
 use crate as genco;
 use crate::fmt;
-use crate::tokens::ItemStr;
+use crate::tokens::{FormatInto, ItemStr};
 use crate::{quote, quote_in};
 use std::collections::{BTreeSet, HashMap};
 use std::fmt::Write as _;
@@ -34,7 +40,23 @@ impl_lang! {
     pub Java {
         type Config = Config;
         type Format = Format;
-        type Item = Import;
+        type Item = Any;
+
+        fn capabilities() -> crate::lang::Capabilities {
+            crate::lang::Capabilities {
+                static_imports: true,
+                block_comments: true,
+                ..Default::default()
+            }
+        }
+
+        fn block_comment_delimiters() -> Option<(&'static str, &'static str)> {
+            Some(("/*", "*/"))
+        }
+
+        fn unit_type() -> Option<&'static str> {
+            Some("void")
+        }
 
         fn write_quoted(out: &mut fmt::Formatter<'_>, input: &str) -> fmt::Result {
             // From: https://docs.oracle.com/javase/tutorial/java/data/characters.html
@@ -76,11 +98,22 @@ impl_lang! {
             }
 
             let mut format = Format::default();
-            Self::imports(&mut header, tokens, config, &mut format.imported);
+            Self::imports(&mut header, tokens, config, &mut format);
             header.format(out, config, &format)?;
             tokens.format(out, config, &format)?;
             Ok(())
         }
+
+        fn format_imports(
+            tokens: &Tokens,
+            out: &mut fmt::Formatter<'_>,
+            config: &Self::Config,
+        ) -> fmt::Result {
+            let mut imports = Tokens::new();
+            let mut format = Format::default();
+            Self::imports(&mut imports, tokens, config, &mut format);
+            imports.format(out, config, &format)
+        }
     }
 
     Import {
@@ -98,6 +131,20 @@ impl_lang! {
             Ok(())
         }
     }
+
+    StaticImport {
+        fn format(&self, out: &mut fmt::Formatter<'_>, _: &Config, format: &Format) -> fmt::Result {
+            let imported = format.imported_static.get(self.name.as_ref()).map(String::as_str);
+
+            if imported != Some(self.class.as_ref()) {
+                out.write_str(self.class.as_ref())?;
+                out.write_str(SEP)?;
+            }
+
+            out.write_str(&self.name)?;
+            Ok(())
+        }
+    }
 }
 
 const JAVA_LANG: &str = "java.lang";
@@ -108,6 +155,9 @@ const SEP: &str = ".";
 pub struct Format {
     /// Types which has been imported into the local namespace.
     imported: HashMap<String, String>,
+    /// Static members which has been imported into the local namespace,
+    /// mapped to the class they are imported from.
+    imported_static: HashMap<String, String>,
 }
 
 /// Configuration for Java.
@@ -118,6 +168,11 @@ pub struct Config {
 }
 
 impl Config {
+    /// Get the package configured for the file generated, if any.
+    pub fn package(&self) -> Option<&str> {
+        self.package.as_deref()
+    }
+
     /// Configure package to use for the file generated.
     ///
     /// # Examples
@@ -168,27 +223,44 @@ pub struct Import {
     name: ItemStr,
 }
 
+/// The static import of a member of a Java class, like
+/// `import static org.junit.Assert.assertEquals;`.
+///
+/// Created through the [static_import()] function.
+#[derive(Debug, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct StaticImport {
+    /// Fully qualified name of the class the member belongs to.
+    class: ItemStr,
+    /// Name of the static member.
+    name: ItemStr,
+}
+
 impl Java {
-    fn imports(
-        out: &mut Tokens,
-        tokens: &Tokens,
-        config: &Config,
-        imported: &mut HashMap<String, String>,
-    ) {
+    fn imports(out: &mut Tokens, tokens: &Tokens, config: &Config, format: &mut Format) {
         let mut modules = BTreeSet::new();
+        let mut statics = BTreeSet::new();
 
         let file_package = config.package.as_ref().map(|p| p.as_ref());
 
         for import in tokens.walk_imports() {
-            modules.insert((import.package.clone(), import.name.clone()));
+            match import {
+                Any::Import(import) => {
+                    modules.insert((import.package.clone(), import.name.clone()));
+                }
+                Any::StaticImport(import) => {
+                    statics.insert((import.class.clone(), import.name.clone()));
+                }
+            }
         }
 
-        if modules.is_empty() {
+        if modules.is_empty() && statics.is_empty() {
             return;
         }
 
+        let mut regular = Tokens::new();
+
         for (package, name) in modules {
-            if imported.contains_key(&*name) {
+            if format.imported.contains_key(&*name) {
                 continue;
             }
 
@@ -200,13 +272,32 @@ impl Java {
                 continue;
             }
 
-            out.append(quote!(import $(package.clone())$(SEP)$(name.clone());));
+            regular.append(quote!(import $(package.clone())$(SEP)$(name.clone());));
+            regular.push();
+
+            format
+                .imported
+                .insert(name.to_string(), package.to_string());
+        }
+
+        // Static members which collide with an imported type or another
+        // static member are left fully qualified.
+        for (class, name) in statics {
+            if format.imported.contains_key(&*name) || format.imported_static.contains_key(&*name) {
+                continue;
+            }
+
+            out.append(quote!(import static $(class.clone())$(SEP)$(name.clone());));
             out.push();
 
-            imported.insert(name.to_string(), package.to_string());
+            format
+                .imported_static
+                .insert(name.to_string(), class.to_string());
         }
 
         out.line();
+        out.append(regular);
+        out.line();
     }
 }
 
@@ -247,6 +338,55 @@ where
     }
 }
 
+/// The static import of a member of a Java class, like
+/// `import static org.junit.Assert.assertEquals;`.
+///
+/// Static imports are rendered in their own group, before regular imports.
+/// If the name of the member collides with an imported type or another
+/// static import, it's left fully qualified instead.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let assert_that = java::static_import("org.assertj.core.api.Assertions", "assertThat");
+/// let equal_to = java::static_import("org.hamcrest.Matchers", "equalTo");
+/// let other = java::static_import("org.hamcrest.MatcherAssert", "assertThat");
+/// let list = java::import("java.util", "List");
+///
+/// let toks = quote! {
+///     $(&list)<String> names = $list.of("a");
+///     $assert_that(names).isNotEmpty();
+///     $other(names.size(), $equal_to(1));
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "import static org.assertj.core.api.Assertions.assertThat;",
+///         "import static org.hamcrest.Matchers.equalTo;",
+///         "",
+///         "import java.util.List;",
+///         "",
+///         "List<String> names = List.of(\"a\");",
+///         "assertThat(names).isNotEmpty();",
+///         "org.hamcrest.MatcherAssert.assertThat(names.size(), equalTo(1));",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn static_import<C, N>(class: C, name: N) -> StaticImport
+where
+    C: Into<ItemStr>,
+    N: Into<ItemStr>,
+{
+    StaticImport {
+        class: class.into(),
+        name: name.into(),
+    }
+}
+
 /// Format a block comment, starting with `/**`, and ending in `*/`.
 ///
 /// # Examples
@@ -282,3 +422,210 @@ where
 {
     BlockComment(comment)
 }
+
+/// Construct a documentation comment with tags, like Javadoc.
+///
+/// The comment starts with the description, followed by an empty line and
+/// the tags in the order they were added. Line breaks in the description
+/// are kept, and the empty line is left out if either the description or
+/// the tags are empty. Nothing is rendered if both are empty.
+///
+/// The same comment can be used for KDoc in Kotlin.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let doc = java::doc("Read the whole file.")
+///     .param("path", "the file to read")
+///     .returns("the contents of the file")
+///     .throws("IOException", "if the file can't be read");
+///
+/// let toks: java::Tokens = quote! {
+///     class Files {
+///         $doc
+///         String read(String path) throws IOException;
+///
+///         $(java::doc("").returns("the separator"))
+///         String separator();
+///     }
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "class Files {",
+///         "    /**",
+///         "     * Read the whole file.",
+///         "     *",
+///         "     * @param path the file to read",
+///         "     * @return the contents of the file",
+///         "     * @throws IOException if the file can't be read",
+///         "     */",
+///         "    String read(String path) throws IOException;",
+///         "",
+///         "    /**",
+///         "     * @return the separator",
+///         "     */",
+///         "    String separator();",
+///         "}",
+///     ],
+///     toks.to_file_vec()?
+/// );
+///
+/// let toks: kotlin::Tokens = quote! {
+///     $(java::doc("Says hello.").param("name", "who to greet"))
+///     fun hello(name: String)
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "/**",
+///         " * Says hello.",
+///         " *",
+///         " * @param name who to greet",
+///         " */",
+///         "fun hello(name: String)",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn doc<D>(description: D) -> Doc
+where
+    D: Into<ItemStr>,
+{
+    Doc::new(description.into())
+}
+
+/// Construct a plain class with private fields, a constructor which takes
+/// every field, and a getter and setter for each field.
+///
+/// Field names are expected to be in camelCase, and the accessors follow
+/// the JavaBeans convention, so the field `firstName` is accessed through
+/// `getFirstName` and `setFirstName`. Field types are imported like any
+/// other interpolated item.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let list = java::import("java.util", "List");
+///
+/// let pojo = java::pojo("Person", vec![
+///     ("name", quote!(String)),
+///     ("emailAddresses", quote!($list<String>)),
+/// ]);
+///
+/// let toks = quote!($pojo);
+///
+/// assert_eq!(
+///     vec![
+///         "import java.util.List;",
+///         "",
+///         "public class Person {",
+///         "    private String name;",
+///         "    private List<String> emailAddresses;",
+///         "",
+///         "    public Person(String name, List<String> emailAddresses) {",
+///         "        this.name = name;",
+///         "        this.emailAddresses = emailAddresses;",
+///         "    }",
+///         "",
+///         "    public String getName() {",
+///         "        return this.name;",
+///         "    }",
+///         "",
+///         "    public void setName(String name) {",
+///         "        this.name = name;",
+///         "    }",
+///         "",
+///         "    public List<String> getEmailAddresses() {",
+///         "        return this.emailAddresses;",
+///         "    }",
+///         "",
+///         "    public void setEmailAddresses(List<String> emailAddresses) {",
+///         "        this.emailAddresses = emailAddresses;",
+///         "    }",
+///         "}",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn pojo<N, I, F, T>(name: N, fields: I) -> Pojo
+where
+    N: Into<ItemStr>,
+    I: IntoIterator<Item = (F, T)>,
+    F: Into<ItemStr>,
+    T: FormatInto<Java>,
+{
+    let fields = fields
+        .into_iter()
+        .map(|(field, ty)| {
+            let mut tokens = Tokens::new();
+            tokens.append(ty);
+            (field.into(), tokens)
+        })
+        .collect();
+
+    Pojo {
+        name: name.into(),
+        fields,
+    }
+}
+
+/// Construct an annotation, like `@Override` or
+/// `@JsonProperty(value = "name")`.
+///
+/// The annotation type is imported like any other interpolated item.
+/// Arguments are added through [Annotation::with_argument] and
+/// [Annotation::with_arguments], and the parentheses are left out if there
+/// are none.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let json_property = java::import("com.fasterxml.jackson.annotation", "JsonProperty");
+/// let size = java::import("javax.validation.constraints", "Size");
+///
+/// let toks = quote! {
+///     $(java::annotation(json_property).with_argument("value", quoted("name")))
+///     $(java::annotation(size).with_arguments([("min", 1), ("max", 10)]))
+///     private String name;
+///
+///     $(java::annotation("Override"))
+///     public String toString() {
+///         return name;
+///     }
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "import com.fasterxml.jackson.annotation.JsonProperty;",
+///         "import javax.validation.constraints.Size;",
+///         "",
+///         "@JsonProperty(value = \"name\")",
+///         "@Size(min = 1, max = 10)",
+///         "private String name;",
+///         "",
+///         "@Override",
+///         "public String toString() {",
+///         "    return name;",
+///         "}",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn annotation<T>(ty: T) -> Annotation
+where
+    T: FormatInto<Java>,
+{
+    let mut tokens = Tokens::new();
+    tokens.append(ty);
+    Annotation::new(tokens)
+}
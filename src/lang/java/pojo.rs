@@ -0,0 +1,53 @@
+use crate as genco;
+use crate::lang::Java;
+use crate::quote_in;
+use crate::tokens::{FormatInto, ItemStr};
+use crate::Tokens;
+
+/// A plain Java class with private fields, a constructor, and a getter and
+/// setter for each field.
+///
+/// This struct is created by the [pojo][super::pojo()] function.
+#[derive(Debug, Clone)]
+pub struct Pojo {
+    pub(super) name: ItemStr,
+    pub(super) fields: Vec<(ItemStr, Tokens<Java>)>,
+}
+
+impl FormatInto<Java> for Pojo {
+    fn format_into(self, tokens: &mut Tokens<Java>) {
+        let name = &self.name;
+        let fields = &self.fields;
+
+        quote_in! { *tokens =>
+            public class $name {
+                $(for (field, ty) in fields join ($['\r']) => private $ty $field;)
+                $['\n']
+                public $name($(for (field, ty) in fields join (, ) => $ty $field)) {
+                    $(for (field, _) in fields join ($['\r']) => this.$field = $field;)
+                }
+                $(for (field, ty) in fields {
+                    $['\n']
+                    public $ty get$(capitalize(field))() {
+                        return this.$field;
+                    }
+                    $['\n']
+                    public void set$(capitalize(field))($ty $field) {
+                        this.$field = $field;
+                    }
+                })
+            }
+        }
+    }
+}
+
+/// Convert a camelCase field name into the suffix of its accessors, like
+/// `firstName` into `FirstName`.
+fn capitalize(name: &str) -> String {
+    let mut chars = name.chars();
+
+    match chars.next() {
+        Some(c) => c.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
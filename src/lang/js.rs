@@ -67,6 +67,19 @@ impl_lang! {
         type Format = Format;
         type Item = Import;
 
+        fn capabilities() -> crate::lang::Capabilities {
+            crate::lang::Capabilities {
+                string_interpolation: true,
+                import_aliasing: true,
+                block_comments: true,
+                ..Default::default()
+            }
+        }
+
+        fn block_comment_delimiters() -> Option<(&'static str, &'static str)> {
+            Some(("/*", "*/"))
+        }
+
         /// Start a string quote.
         fn open_quote(
             out: &mut fmt::Formatter<'_>,
@@ -79,7 +92,7 @@ impl_lang! {
             if has_eval {
                 out.write_char('`')?;
             } else {
-                out.write_char('"')?;
+                out.write_char(out.quote_style().as_char())?;
             }
 
             Ok(())
@@ -97,7 +110,7 @@ impl_lang! {
             if has_eval {
                 out.write_char('`')?;
             } else {
-                out.write_char('"')?;
+                out.write_char(out.quote_style().as_char())?;
             }
 
             Ok(())
@@ -140,9 +153,12 @@ impl_lang! {
                     '\u{0011}' => out.write_str("\\v")?,
                     // null character.
                     '\0' => out.write_str("\\0")?,
-                    // Note: only relevant if we were to use single-quoted strings.
-                    // '\'' => out.write_str("\\'")?,
-                    '"' => out.write_str("\\\"")?,
+                    '\'' if out.quote_style() == fmt::QuoteStyle::Single => {
+                        out.write_str("\\'")?
+                    }
+                    '"' if out.quote_style() == fmt::QuoteStyle::Double => {
+                        out.write_str("\\\"")?
+                    }
                     '\\' => out.write_str("\\\\")?,
                     c if !c.is_control() => out.write_char(c)?,
                     c if (c as u32) < 0x100 => {
@@ -169,6 +185,16 @@ impl_lang! {
             tokens.format(out, config, &format)?;
             Ok(())
         }
+
+        fn format_imports(
+            tokens: &Tokens,
+            out: &mut fmt::Formatter<'_>,
+            config: &Self::Config,
+        ) -> fmt::Result {
+            let mut imports = Tokens::new();
+            Self::imports(&mut imports, tokens, config);
+            imports.format(out, config, &Format::default())
+        }
     }
 
     Import {
@@ -178,7 +204,8 @@ impl_lang! {
                 _ => &self.name,
             };
 
-            out.write_str(name)
+            out.write_str(name)?;
+            Ok(())
         }
     }
 }
@@ -253,6 +280,7 @@ enum ImportKind {
     Named,
     Default,
     Wildcard,
+    SideEffect,
 }
 
 /// The import of a JavaScript type `import {foo} from "module.js"`.
@@ -419,6 +447,7 @@ impl JavaScript {
 
         let mut modules = BTreeMap::<&Module, ResolvedModule<'_>>::new();
         let mut wildcards = BTreeSet::new();
+        let mut side_effects = BTreeSet::new();
 
         for import in tokens.walk_imports() {
             match import.kind {
@@ -437,13 +466,29 @@ impl JavaScript {
                 ImportKind::Wildcard => {
                     wildcards.insert((&import.module, &import.name));
                 }
+                ImportKind::SideEffect => {
+                    side_effects.insert(&import.module);
+                }
             }
         }
 
-        if modules.is_empty() && wildcards.is_empty() {
+        // Side effects of a module also happen if anything else is imported
+        // from it.
+        side_effects.retain(|module| {
+            !modules.contains_key(module) && !wildcards.iter().any(|(m, _)| m == module)
+        });
+
+        if modules.is_empty() && wildcards.is_empty() && side_effects.is_empty() {
             return;
         }
 
+        for module in side_effects {
+            out.push();
+            quote_in! { *out =>
+                import $(ref t => render_from(t, config.module_path.as_deref(), module));
+            }
+        }
+
         for (module, name) in wildcards {
             out.push();
             quote_in! { *out =>
@@ -562,3 +607,146 @@ where
         alias: None,
     }
 }
+
+/// A named import, like `import {useState} from "react"`.
+///
+/// This is the same as [import()]. Named imports from the same module are
+/// merged into a single import.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let use_state = js::import_named("react", "useState");
+/// let use_effect = js::import_named("react", "useEffect");
+///
+/// let toks = quote!($use_state $use_effect);
+///
+/// assert_eq!(
+///     vec![
+///         "import {useEffect, useState} from \"react\";",
+///         "",
+///         "useState useEffect",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn import_named<M, N>(module: M, name: N) -> Import
+where
+    M: Into<Module>,
+    N: Into<ItemStr>,
+{
+    import(module, name)
+}
+
+/// A default import, like `import React from "react"`.
+///
+/// This is the same as [import()] followed by [Import::into_default]. The
+/// default import of a module is merged with its named imports.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let react = js::import_default("react", "React");
+/// let use_state = js::import_named("react", "useState");
+///
+/// let toks = quote!($react $use_state);
+///
+/// assert_eq!(
+///     vec![
+///         "import React, {useState} from \"react\";",
+///         "",
+///         "React useState",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn import_default<M, N>(module: M, name: N) -> Import
+where
+    M: Into<Module>,
+    N: Into<ItemStr>,
+{
+    import(module, name).into_default()
+}
+
+/// A namespace import, like `import * as path from "node:path"`.
+///
+/// This is the same as [import()] followed by [Import::into_wildcard].
+/// Namespace imports are always kept as separate imports.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let path = js::import_namespace("node:path", "path");
+/// let join = js::import_named("node:path", "join");
+///
+/// let toks = quote!($path $join);
+///
+/// assert_eq!(
+///     vec![
+///         "import * as path from \"node:path\";",
+///         "import {join} from \"node:path\";",
+///         "",
+///         "path join",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn import_namespace<M, N>(module: M, name: N) -> Import
+where
+    M: Into<Module>,
+    N: Into<ItemStr>,
+{
+    import(module, name).into_wildcard()
+}
+
+/// A side-effect import, like `import "./polyfills.js"`.
+///
+/// The import doesn't render anything, so it's added through
+/// [register][crate::tokens::register()]. Side-effect imports come before
+/// the other imports, and are left out if anything else is imported from the
+/// same module since that runs its side effects too.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let react = js::import_default("react", "React");
+///
+/// let toks = quote! {
+///     $(register(js::import_side_effect("./polyfills.js")))
+///     $(register(js::import_side_effect("react")))
+///     $react
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "import \"./polyfills.js\";",
+///         "import React from \"react\";",
+///         "",
+///         "React",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn import_side_effect<M>(module: M) -> Import
+where
+    M: Into<Module>,
+{
+    Import {
+        kind: ImportKind::SideEffect,
+        module: module.into(),
+        name: ItemStr::Static(""),
+        alias: None,
+    }
+}
@@ -0,0 +1,526 @@
+//! Specialization for Kotlin code generation.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use genco::prelude::*;
+//!
+//! # fn main() -> genco::fmt::Result {
+//! let list = kotlin::import("kotlin.collections", "List");
+//! let instant = kotlin::import("java.time", "Instant");
+//!
+//! let toks: kotlin::Tokens = quote! {
+//!     data class Event(
+//!         val names: $list<String>,
+//!         val at: $(kotlin::nullable(&instant)),
+//!     )
+//! };
+//!
+//! assert_eq!(
+//!     vec![
+//!         "import java.time.Instant",
+//!         "",
+//!         "data class Event(",
+//!         "    val names: List<String>,",
+//!         "    val at: Instant?,",
+//!         ")",
+//!     ],
+//!     toks.to_file_vec()?
+//! );
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # String Quoting in Kotlin
+//!
+//! Kotlin supports [string templates], so `$` is escaped in quoted strings.
+//! [Quoted string interpolation] is rendered as a string template.
+//!
+//! [string templates]: https://kotlinlang.org/docs/strings.html#string-templates
+//! [Quoted string interpolation]: https://docs.rs/genco/0/genco/macro.quote.html#quoted-string-interpolation
+//!
+//! ```rust
+//! use genco::prelude::*;
+//!
+//! # fn main() -> genco::fmt::Result {
+//! let toks: kotlin::Tokens = quote!("start π $ \n \x7f end");
+//! assert_eq!("\"start π \\$ \\n \\u007f end\"", toks.to_string()?);
+//!
+//! let toks: kotlin::Tokens = quote!($[str](Hello $(name) and $(a + b)));
+//! assert_eq!("\"Hello $name and ${a + b}\"", toks.to_string()?);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate as genco;
+use crate::fmt;
+use crate::quote_in;
+use crate::tokens::{self, FormatInto, ItemStr};
+
+pub use crate::tokens::Nullable;
+use std::collections::{BTreeSet, HashMap};
+use std::fmt::Write as _;
+
+/// Tokens container specialized for Kotlin.
+pub type Tokens = crate::Tokens<Kotlin>;
+
+impl genco::lang::LangSupportsEval for Kotlin {}
+
+impl_lang! {
+    /// Language specialization for Kotlin.
+    pub Kotlin {
+        type Config = Config;
+        type Format = Format;
+        type Item = Import;
+
+        fn capabilities() -> crate::lang::Capabilities {
+            crate::lang::Capabilities {
+                string_interpolation: true,
+                import_aliasing: true,
+                block_comments: true,
+                ..Default::default()
+            }
+        }
+
+        fn block_comment_delimiters() -> Option<(&'static str, &'static str)> {
+            Some(("/*", "*/"))
+        }
+
+        fn unit_type() -> Option<&'static str> {
+            Some("Unit")
+        }
+
+        fn statement_terminator() -> &'static str {
+            ""
+        }
+
+        fn string_eval_literal(
+            out: &mut fmt::Formatter<'_>,
+            _config: &Self::Config,
+            _format: &Self::Format,
+            literal: &str,
+        ) -> fmt::Result {
+            write!(out, "${}", literal)?;
+            Ok(())
+        }
+
+        fn start_string_eval(
+            out: &mut fmt::Formatter<'_>,
+            _config: &Self::Config,
+            _format: &Self::Format,
+        ) -> fmt::Result {
+            out.write_str("${")?;
+            Ok(())
+        }
+
+        fn end_string_eval(
+            out: &mut fmt::Formatter<'_>,
+            _config: &Self::Config,
+            _format: &Self::Format,
+        ) -> fmt::Result {
+            out.write_char('}')?;
+            Ok(())
+        }
+
+        fn write_quoted(out: &mut fmt::Formatter<'_>, input: &str) -> fmt::Result {
+            // From: https://kotlinlang.org/docs/characters.html
+            for c in input.chars() {
+                match c {
+                    '\t' => out.write_str("\\t")?,
+                    '\u{0008}' => out.write_str("\\b")?,
+                    '\n' => out.write_str("\\n")?,
+                    '\r' => out.write_str("\\r")?,
+                    '"' => out.write_str("\\\"")?,
+                    '\\' => out.write_str("\\\\")?,
+                    '$' => out.write_str("\\$")?,
+                    c if !c.is_control() => out.write_char(c)?,
+                    c => {
+                        for c in c.encode_utf16(&mut [0u16; 2]) {
+                            write!(out, "\\u{:04x}", c)?;
+                        }
+                    }
+                }
+            }
+
+            Ok(())
+        }
+
+        fn format_file(
+            tokens: &Tokens,
+            out: &mut fmt::Formatter<'_>,
+            config: &Self::Config,
+        ) -> fmt::Result {
+            let mut header = Tokens::new();
+
+            if let Some(ref package) = config.package {
+                quote_in!(header => package $package);
+                header.line();
+            }
+
+            let mut format = Format::default();
+            Self::imports(&mut header, tokens, config, &mut format.imported);
+            header.format(out, config, &format)?;
+            tokens.format(out, config, &format)?;
+            Ok(())
+        }
+
+        fn format_imports(
+            tokens: &Tokens,
+            out: &mut fmt::Formatter<'_>,
+            config: &Self::Config,
+        ) -> fmt::Result {
+            let mut imports = Tokens::new();
+            let mut format = Format::default();
+            Self::imports(&mut imports, tokens, config, &mut format.imported);
+            imports.format(out, config, &format)
+        }
+    }
+
+    Import {
+        fn format(&self, out: &mut fmt::Formatter<'_>, config: &Config, format: &Format) -> fmt::Result {
+            let local = self.alias.as_ref().unwrap_or(&self.name);
+            let imported = format.imported.get(local.as_ref());
+
+            if imported == Some(&(self.package.clone(), self.name.clone())) {
+                out.write_str(local)?;
+                return Ok(());
+            }
+
+            let file_package = config.package.as_deref();
+
+            if self.alias.is_some() || !is_implicit(&self.package, file_package) {
+                out.write_str(&self.package)?;
+                out.write_str(SEP)?;
+            }
+
+            out.write_str(&self.name)?;
+            Ok(())
+        }
+    }
+}
+
+const SEP: &str = ".";
+
+/// Packages which are imported by default in Kotlin.
+///
+/// See: <https://kotlinlang.org/docs/packages.html#default-imports>
+const DEFAULT_PACKAGES: &[&str] = &[
+    "kotlin",
+    "kotlin.annotation",
+    "kotlin.collections",
+    "kotlin.comparisons",
+    "kotlin.io",
+    "kotlin.ranges",
+    "kotlin.sequences",
+    "kotlin.text",
+    "java.lang",
+    "kotlin.jvm",
+];
+
+/// Test if the given package doesn't have to be imported.
+fn is_implicit(package: &str, file_package: Option<&str>) -> bool {
+    DEFAULT_PACKAGES.contains(&package) || Some(package) == file_package
+}
+
+/// Format state for Kotlin.
+#[derive(Debug, Default)]
+pub struct Format {
+    /// Names which have been imported into the local namespace, mapped to
+    /// the package and name they were imported from.
+    imported: HashMap<String, (ItemStr, ItemStr)>,
+}
+
+/// Configuration for Kotlin.
+#[derive(Debug, Default)]
+pub struct Config {
+    /// Package to use.
+    package: Option<ItemStr>,
+}
+
+impl Config {
+    /// Configure package to use for the file generated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    /// use genco::fmt;
+    ///
+    /// let foo = kotlin::import("com.example", "Foo");
+    /// let bar = kotlin::import("com.example.other", "Bar");
+    ///
+    /// let toks = quote!($foo $bar);
+    ///
+    /// let config = kotlin::Config::default().with_package("com.example");
+    /// let fmt = fmt::Config::from_lang::<Kotlin>();
+    ///
+    /// let mut w = fmt::VecWriter::new();
+    ///
+    /// toks.format_file(&mut w.as_formatter(&fmt), &config)?;
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "package com.example",
+    ///         "",
+    ///         "import com.example.other.Bar",
+    ///         "",
+    ///         "Foo Bar",
+    ///     ],
+    ///     w.into_vec(),
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn with_package<P>(self, package: P) -> Self
+    where
+        P: Into<ItemStr>,
+    {
+        Self {
+            package: Some(package.into()),
+        }
+    }
+}
+
+/// The import of a Kotlin type `import kotlin.collections.List`.
+///
+/// Created through the [import()] function.
+#[derive(Debug, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct Import {
+    /// Package of the type.
+    package: ItemStr,
+    /// Name of the type.
+    name: ItemStr,
+    /// Alias to import the type as.
+    alias: Option<ItemStr>,
+}
+
+impl Import {
+    /// Import the type under an alias, like `import a.b.C as D`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    ///
+    /// let a = kotlin::import("com.example.a", "Node");
+    /// let b = kotlin::import("com.example.b", "Node").with_alias("BNode");
+    ///
+    /// let toks = quote!($a $b);
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "import com.example.a.Node",
+    ///         "import com.example.b.Node as BNode",
+    ///         "",
+    ///         "Node BNode",
+    ///     ],
+    ///     toks.to_file_vec()?
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn with_alias<A>(self, alias: A) -> Self
+    where
+        A: Into<ItemStr>,
+    {
+        Self {
+            alias: Some(alias.into()),
+            ..self
+        }
+    }
+}
+
+/// A data class, with a primary constructor declaring one property for each
+/// field.
+///
+/// Created through the [data_class()] function.
+#[derive(Debug, Clone)]
+pub struct DataClass {
+    name: ItemStr,
+    fields: Vec<(ItemStr, Tokens)>,
+}
+
+impl FormatInto<Kotlin> for DataClass {
+    fn format_into(self, tokens: &mut Tokens) {
+        let name = &self.name;
+        let fields = &self.fields;
+
+        quote_in! { *tokens =>
+            data class $name(
+                $(for (field, ty) in fields join ($['\r']) => val $field: $ty,)
+            )
+        }
+    }
+}
+
+impl Kotlin {
+    fn imports(
+        out: &mut Tokens,
+        tokens: &Tokens,
+        config: &Config,
+        imported: &mut HashMap<String, (ItemStr, ItemStr)>,
+    ) {
+        let mut modules = BTreeSet::new();
+
+        let file_package = config.package.as_deref();
+
+        for import in tokens.walk_imports() {
+            modules.insert((&import.package, &import.name, import.alias.as_ref()));
+        }
+
+        if modules.is_empty() {
+            return;
+        }
+
+        for (package, name, alias) in modules {
+            if alias.is_none() && is_implicit(package, file_package) {
+                continue;
+            }
+
+            let local = alias.unwrap_or(name);
+
+            // Names which are already in use are left fully qualified.
+            if imported.contains_key(local.as_ref()) {
+                continue;
+            }
+
+            match alias {
+                Some(alias) => quote_in!(*out => import $package$(SEP)$name as $alias),
+                None => quote_in!(*out => import $package$(SEP)$name),
+            }
+
+            out.push();
+            imported.insert(local.to_string(), (package.clone(), name.clone()));
+        }
+
+        out.line();
+    }
+}
+
+/// The import of a Kotlin type `import kotlin.collections.List`.
+///
+/// Types from packages which are imported by default, like `kotlin` or
+/// `kotlin.collections`, are never imported. If two imported types have the
+/// same name, the first one in sorted order is imported and the other one
+/// is left fully qualified.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let a = kotlin::import("com.example.a", "Node");
+/// let b = kotlin::import("com.example.b", "Node");
+/// let map = kotlin::import("kotlin.collections", "Map");
+///
+/// let toks = quote! {
+///     val nodes: $map<$(&a), $b> = mapOf()
+///     val first: $a? = null
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "import com.example.a.Node",
+///         "",
+///         "val nodes: Map<Node, com.example.b.Node> = mapOf()",
+///         "val first: Node? = null",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn import<P, N>(package: P, name: N) -> Import
+where
+    P: Into<ItemStr>,
+    N: Into<ItemStr>,
+{
+    Import {
+        package: package.into(),
+        name: name.into(),
+        alias: None,
+    }
+}
+
+/// A nullable type, like `Foo?`.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let list = kotlin::import("kotlin.collections", "List");
+///
+/// let toks: kotlin::Tokens = quote! {
+///     val a: $(kotlin::nullable("String")) = null
+///     val b: $(kotlin::nullable(quote!($list<Int>))) = null
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "val a: String? = null",
+///         "val b: List<Int>? = null",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn nullable<T>(inner: T) -> Nullable<T>
+where
+    T: FormatInto<Kotlin>,
+{
+    tokens::nullable(inner)
+}
+
+/// Construct a data class declaration, like `data class Person(val name: String)`.
+///
+/// Field names are expected to be in camelCase, and are used as the names of
+/// the properties. Field types are imported like any other interpolated item.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let instant = kotlin::import("java.time", "Instant");
+///
+/// let class = kotlin::data_class("Person", vec![
+///     ("name", quote!(String)),
+///     ("emailAddresses", quote!(List<String>)),
+///     ("lastSeen", quote!($(kotlin::nullable(instant)))),
+/// ]);
+///
+/// let toks = quote!($class);
+///
+/// assert_eq!(
+///     vec![
+///         "import java.time.Instant",
+///         "",
+///         "data class Person(",
+///         "    val name: String,",
+///         "    val emailAddresses: List<String>,",
+///         "    val lastSeen: Instant?,",
+///         ")",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn data_class<N, I, F, T>(name: N, fields: I) -> DataClass
+where
+    N: Into<ItemStr>,
+    I: IntoIterator<Item = (F, T)>,
+    F: Into<ItemStr>,
+    T: FormatInto<Kotlin>,
+{
+    let fields = fields
+        .into_iter()
+        .map(|(field, ty)| {
+            let mut tokens = Tokens::new();
+            tokens.append(ty);
+            (field.into(), tokens)
+        })
+        .collect();
+
+    DataClass {
+        name: name.into(),
+        fields,
+    }
+}
@@ -0,0 +1,430 @@
+//! Specialization for Lua code generation.
+//!
+//! Modules are loaded with [require()], which binds the module table to a
+//! local at the top of the file, like `local json = require("json")`. Each
+//! module is only required once, and they are sorted by name.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use genco::prelude::*;
+//!
+//! # fn main() -> genco::fmt::Result {
+//! let http = lua::require("socket.http");
+//! let json = lua::require("dkjson").with_alias("json");
+//!
+//! let toks: lua::Tokens = quote! {
+//!     local function fetch(url)
+//!         local body = $(&http).request(url)
+//!         return $json.decode(body)
+//!     end
+//!
+//!     print($http.TIMEOUT)
+//! };
+//!
+//! assert_eq!(
+//!     vec![
+//!         "local json = require(\"dkjson\")",
+//!         "local http = require(\"socket.http\")",
+//!         "",
+//!         "local function fetch(url)",
+//!         "    local body = http.request(url)",
+//!         "    return json.decode(body)",
+//!         "end",
+//!         "",
+//!         "print(http.TIMEOUT)",
+//!     ],
+//!     toks.to_file_vec()?
+//! );
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # String Quoting in Lua
+//!
+//! Strings are quoted with double quotes, where control characters are
+//! written as escape sequences. Since escapes are written as decimal byte
+//! values like `\0`, any character can be represented.
+//!
+//! Multi-line strings can be written as long bracket strings like
+//! `[[...]]` through [raw_quoted][crate::tokens::raw_quoted()], in which
+//! escape sequences are not processed. Enough `=` are added to the brackets
+//! so that they don't occur in the string itself.
+//!
+//! ```rust
+//! use genco::prelude::*;
+//! use genco::tokens::raw_quoted;
+//!
+//! # fn main() -> genco::fmt::Result {
+//! let toks: lua::Tokens = quote!($(quoted("hello \"world\"\n\u{7f}")));
+//! assert_eq!("\"hello \\\"world\\\"\\n\\127\"", toks.to_string()?);
+//!
+//! let toks: lua::Tokens = quote!($(raw_quoted("first\nsecond")));
+//! assert_eq!("[[first\nsecond]]", toks.to_string()?);
+//!
+//! let toks: lua::Tokens = quote!($(raw_quoted("a[b]]")));
+//! assert_eq!("[=[a[b]]]=]", toks.to_string()?);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate as genco;
+use crate::fmt;
+use crate::quote_in;
+use crate::tokens::{quoted, ItemStr};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write as _;
+
+/// Tokens container specialization for Lua.
+pub type Tokens = crate::Tokens<Lua>;
+
+impl_lang! {
+    /// Language specialization for Lua.
+    pub Lua {
+        type Config = Config;
+        type Format = Format;
+        type Item = Require;
+
+        fn capabilities() -> crate::lang::Capabilities {
+            crate::lang::Capabilities {
+                raw_strings: true,
+                import_aliasing: true,
+                block_comments: true,
+                ..Default::default()
+            }
+        }
+
+        fn line_comment_prefix() -> Option<&'static str> {
+            Some("--")
+        }
+
+        fn block_comment_delimiters() -> Option<(&'static str, &'static str)> {
+            Some(("--[[", "]]"))
+        }
+
+        fn unit_type() -> Option<&'static str> {
+            Some("nil")
+        }
+
+        fn statement_terminator() -> &'static str {
+            ""
+        }
+
+        fn write_quoted(out: &mut fmt::Formatter<'_>, input: &str) -> fmt::Result {
+            // From: https://www.lua.org/manual/5.4/manual.html#3.1
+
+            for c in input.chars() {
+                match c {
+                    '\u{0007}' => out.write_str("\\a")?,
+                    '\u{0008}' => out.write_str("\\b")?,
+                    '\u{000c}' => out.write_str("\\f")?,
+                    '\n' => out.write_str("\\n")?,
+                    '\r' => out.write_str("\\r")?,
+                    '\t' => out.write_str("\\t")?,
+                    '\u{000b}' => out.write_str("\\v")?,
+                    '\\' => out.write_str("\\\\")?,
+                    '"' => out.write_str("\\\"")?,
+                    // NB: decimal escapes are always written with three digits,
+                    // so that they can't run into a following digit.
+                    c if c.is_ascii_control() => write!(out, "\\{:03}", c as u32)?,
+                    c => out.write_char(c)?,
+                }
+            }
+
+            Ok(())
+        }
+
+        fn raw_string(out: &mut fmt::Formatter<'_>, input: &str) -> Option<fmt::Result> {
+            // A line break directly after the opening bracket is skipped, and
+            // carriage returns are normalized.
+            if input.starts_with(['\r', '\n']) || input.contains('\r') {
+                return None;
+            }
+
+            let mut eq = String::new();
+
+            while input.contains(&format!("]{eq}]")) || input.ends_with(&format!("]{eq}")) {
+                eq.push('=');
+            }
+
+            Some(write!(out, "[{eq}[{input}]{eq}]").map_err(fmt::Error::from))
+        }
+
+        fn format_file(
+            tokens: &Tokens,
+            out: &mut fmt::Formatter<'_>,
+            config: &Self::Config,
+        ) -> fmt::Result {
+            let mut header = Tokens::new();
+
+            if let Some(shebang) = &config.shebang {
+                quote_in!(header => $(shebang.clone()));
+                header.line();
+            }
+
+            let format = Format {
+                aliases: Self::aliases(tokens),
+            };
+
+            Self::requires(&mut header, tokens, &format);
+            header.format(out, config, &format)?;
+            tokens.format(out, config, &format)?;
+            Ok(())
+        }
+
+        fn format_imports(
+            tokens: &Tokens,
+            out: &mut fmt::Formatter<'_>,
+            config: &Self::Config,
+        ) -> fmt::Result {
+            let format = Format {
+                aliases: Self::aliases(tokens),
+            };
+
+            let mut requires = Tokens::new();
+            Self::requires(&mut requires, tokens, &format);
+            requires.format(out, config, &format)
+        }
+    }
+
+    Require {
+        fn format(&self, out: &mut fmt::Formatter<'_>, _: &Config, format: &Format) -> fmt::Result {
+            out.write_str(self.local(format))?;
+            Ok(())
+        }
+    }
+}
+
+/// Format state for Lua.
+#[derive(Debug, Default)]
+pub struct Format {
+    /// Locals assigned to modules which would otherwise be bound to the same
+    /// local as another module, indexed by module.
+    aliases: BTreeMap<ItemStr, String>,
+}
+
+/// Configuration for Lua.
+#[derive(Debug, Default)]
+pub struct Config {
+    /// The shebang line to start the file with.
+    shebang: Option<ItemStr>,
+}
+
+impl Config {
+    /// Set the shebang line which the file starts with, like
+    /// `#!/usr/bin/env lua`. By default no shebang line is emitted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    /// use genco::fmt;
+    ///
+    /// let inspect = lua::require("inspect");
+    /// let toks: lua::Tokens = quote!(print($inspect(arg)));
+    ///
+    /// let config = lua::Config::default().with_shebang("#!/usr/bin/env lua");
+    /// let fmt = fmt::Config::from_lang::<Lua>();
+    ///
+    /// let mut w = fmt::VecWriter::new();
+    /// toks.format_file(&mut w.as_formatter(&fmt), &config)?;
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "#!/usr/bin/env lua",
+    ///         "",
+    ///         "local inspect = require(\"inspect\")",
+    ///         "",
+    ///         "print(inspect(arg))",
+    ///     ],
+    ///     w.into_vec(),
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn with_shebang<S>(self, shebang: S) -> Self
+    where
+        S: Into<ItemStr>,
+    {
+        Self {
+            shebang: Some(shebang.into()),
+        }
+    }
+}
+
+/// A required module, like `local json = require("json")`.
+///
+/// Created through the [require()] function.
+#[derive(Debug, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct Require {
+    /// The name of the module.
+    module: ItemStr,
+    /// The local to bind the module to, if it's not derived from the name of
+    /// the module.
+    alias: Option<ItemStr>,
+}
+
+impl Require {
+    /// Bind the module to a local with a different name, like
+    /// `local json = require("dkjson")`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    ///
+    /// let lfs = lua::require("lfs").with_alias("fs");
+    ///
+    /// let toks: lua::Tokens = quote!($lfs.currentdir());
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "local fs = require(\"lfs\")",
+    ///         "",
+    ///         "fs.currentdir()",
+    ///     ],
+    ///     toks.to_file_vec()?
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn with_alias<A>(self, alias: A) -> Self
+    where
+        A: Into<ItemStr>,
+    {
+        Self {
+            alias: Some(alias.into()),
+            ..self
+        }
+    }
+
+    /// The name of the local which the module is bound to.
+    fn local<'a>(&'a self, format: &'a Format) -> &'a str {
+        match &self.alias {
+            Some(alias) => alias,
+            None => match format.aliases.get(&self.module) {
+                Some(alias) => alias,
+                None => self.default_local(),
+            },
+        }
+    }
+
+    /// The local derived from the name of the module.
+    fn default_local(&self) -> &str {
+        self.module.rsplit('.').next().unwrap_or_default()
+    }
+}
+
+impl Lua {
+    /// Assign locals to modules which would otherwise be bound to a local
+    /// that's already used by another module.
+    fn aliases(tokens: &Tokens) -> BTreeMap<ItemStr, String> {
+        // Locals which are in use, and the module they are bound to.
+        let mut locals = BTreeMap::<String, &ItemStr>::new();
+        let mut modules = BTreeSet::new();
+
+        for require in tokens.walk_imports() {
+            match &require.alias {
+                Some(alias) => {
+                    locals.entry(alias.to_string()).or_insert(&require.module);
+                }
+                None => {
+                    modules.insert(require);
+                }
+            }
+        }
+
+        let mut aliases = BTreeMap::new();
+
+        for require in modules {
+            let local = require.default_local();
+
+            match locals.get(local) {
+                Some(module) if **module != require.module => {}
+                _ => {
+                    locals.insert(local.to_owned(), &require.module);
+                    continue;
+                }
+            }
+
+            // Name the local after the full name of the module instead, like
+            // `socket_url` for `socket.url`.
+            let base = require.module.replace('.', "_");
+            let mut alias = base.clone();
+            let mut n = 1;
+
+            while locals.contains_key(&alias) {
+                n += 1;
+                alias = format!("{base}_{n}");
+            }
+
+            locals.insert(alias.clone(), &require.module);
+            aliases.insert(require.module.clone(), alias);
+        }
+
+        aliases
+    }
+
+    fn requires(out: &mut Tokens, tokens: &Tokens, format: &Format) {
+        let mut requires = BTreeSet::new();
+
+        for require in tokens.walk_imports() {
+            requires.insert((&*require.module, require.local(format)));
+        }
+
+        if requires.is_empty() {
+            return;
+        }
+
+        for (module, local) in requires {
+            quote_in!(*out => local $local = require($(quoted(module))));
+            out.push();
+        }
+
+        out.line();
+    }
+}
+
+/// Require a module, which is bound to a local at the top of the file.
+///
+/// The local is named after the last component of the module name, so
+/// `socket.http` is bound to `http`. Use [Require::with_alias] to pick
+/// another name. The same module is only required once for every local it's
+/// bound to. If the local is already used by another module, it's named
+/// after the full name of the module instead, so `socket.url` is bound to
+/// `socket_url`.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let lpeg = lua::require("lpeg");
+/// let url = lua::require("socket.url");
+///
+/// let toks: lua::Tokens = quote! {
+///     local p = $(&lpeg).P("a")
+///     local q = $lpeg.P("b")
+///     local u = $url.parse(input)
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "local lpeg = require(\"lpeg\")",
+///         "local url = require(\"socket.url\")",
+///         "",
+///         "local p = lpeg.P(\"a\")",
+///         "local q = lpeg.P(\"b\")",
+///         "local u = url.parse(input)",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn require<M>(module: M) -> Require
+where
+    M: Into<ItemStr>,
+{
+    Require {
+        module: module.into(),
+        alias: None,
+    }
+}
@@ -0,0 +1,284 @@
+//! Specialization for Makefile code generation.
+//!
+//! The recipe of a Makefile target must be indented with a literal tab, so
+//! recipes are written through [target()], which prefixes every line of the
+//! recipe with a tab regardless of the configured [fmt::Indentation]. Other
+//! lines don't have any indentation requirements, so this language defaults
+//! to tab indentation for consistency.
+//!
+//! Make uses `$` for variable references, which has to be escaped as `$$` in
+//! [quote!][crate::quote] like any other literal `$`.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use genco::prelude::*;
+//!
+//! # fn main() -> genco::fmt::Result {
+//! let build = make::target("build", ["src/main.c"], quote! {
+//!     $$(CC) $$(CFLAGS) -o build src/main.c
+//! });
+//!
+//! let clean = make::target("clean", None::<&str>, quote!(rm -f build)).phony();
+//!
+//! let toks: make::Tokens = quote! {
+//!     ## Generated file, do not edit.
+//!     CC ?= cc
+//!
+//!     $build
+//!
+//!     $clean
+//! };
+//!
+//! assert_eq!(
+//!     vec![
+//!         "# Generated file, do not edit.",
+//!         "CC ?= cc",
+//!         "",
+//!         "build: src/main.c",
+//!         "\t$(CC) $(CFLAGS) -o build src/main.c",
+//!         "",
+//!         ".PHONY: clean",
+//!         "clean:",
+//!         "\trm -f build",
+//!     ],
+//!     toks.to_file_vec()?
+//! );
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # String Quoting in Makefiles
+//!
+//! Make doesn't have string literals, but recipes are passed to the shell.
+//! Quoted strings are written with single quotes for the shell like in
+//! [bash][super::bash], where `'` is written as `'\''`, and `$` is escaped as
+//! `$$` so that make doesn't expand it. Line breaks would end the line of a
+//! recipe, so quoting one fails with [fmt::Error::UnsupportedChar].
+//!
+//! ```rust
+//! use genco::prelude::*;
+//!
+//! # fn main() -> genco::fmt::Result {
+//! let toks: make::Tokens = quote!(echo $(quoted("it's $5 \"now\"")));
+//! assert_eq!("echo 'it'\\''s $$5 \"now\"'", toks.to_string()?);
+//!
+//! let toks: make::Tokens = quote!(echo $(quoted("one\ntwo")));
+//! assert_eq!(
+//!     Err(genco::fmt::Error::UnsupportedChar { character: '\n', index: 3 }),
+//!     toks.to_string()
+//! );
+//! # Ok(())
+//! # }
+//! ```
+
+use crate as genco;
+use crate::fmt;
+use crate::quote_in;
+use crate::tokens::{static_literal, FormatInto, Item, ItemStr};
+use std::fmt::Write as _;
+
+/// Tokens container specialization for Makefiles.
+pub type Tokens = crate::Tokens<Make>;
+
+impl_lang! {
+    /// Language specialization for Makefiles.
+    pub Make {
+        type Config = Config;
+        type Format = Format;
+        type Item = Any;
+
+        fn default_indentation() -> fmt::Indentation {
+            fmt::Indentation::Tab
+        }
+
+        fn capabilities() -> crate::lang::Capabilities {
+            // Makefiles only have line comments, and no imports.
+            crate::lang::Capabilities::default()
+        }
+
+        fn line_comment_prefix() -> Option<&'static str> {
+            Some("#")
+        }
+
+        fn statement_terminator() -> &'static str {
+            ""
+        }
+
+        fn open_quote(
+            out: &mut fmt::Formatter<'_>,
+            _config: &Self::Config,
+            _format: &Self::Format,
+            _has_eval: bool,
+        ) -> fmt::Result {
+            out.write_char('\'')?;
+            Ok(())
+        }
+
+        fn close_quote(
+            out: &mut fmt::Formatter<'_>,
+            _config: &Self::Config,
+            _format: &Self::Format,
+            _has_eval: bool,
+        ) -> fmt::Result {
+            out.write_char('\'')?;
+            Ok(())
+        }
+
+        fn write_quoted(out: &mut fmt::Formatter<'_>, input: &str) -> fmt::Result {
+            for (index, c) in input.char_indices() {
+                match c {
+                    // A line break would end the recipe line.
+                    '\n' | '\r' | '\0' => {
+                        return Err(fmt::Error::UnsupportedChar {
+                            character: c,
+                            index,
+                        })
+                    }
+                    '\'' => out.write_str("'\\''")?,
+                    '$' => out.write_str("$$")?,
+                    c => out.write_char(c)?,
+                }
+            }
+
+            Ok(())
+        }
+    }
+}
+
+/// Format state for Makefiles.
+#[derive(Debug, Default)]
+pub struct Format {}
+
+/// Configuration for Makefiles.
+#[derive(Debug, Default)]
+pub struct Config {}
+
+/// A Makefile target, with its prerequisites and recipe.
+///
+/// Created through the [target()] function.
+#[derive(Debug, Clone)]
+pub struct Target {
+    /// The name of the target.
+    name: ItemStr,
+    /// Prerequisites of the target.
+    prerequisites: Vec<ItemStr>,
+    /// The recipe to build the target.
+    recipe: Tokens,
+    /// If the target is declared as `.PHONY`.
+    phony: bool,
+}
+
+impl Target {
+    /// Declare the target as phony, like `.PHONY: clean`, so that it's
+    /// built even if a file with the same name exists.
+    pub fn phony(self) -> Self {
+        Self {
+            phony: true,
+            ..self
+        }
+    }
+}
+
+impl FormatInto<Make> for Target {
+    fn format_into(self, tokens: &mut Tokens) {
+        if self.phony {
+            quote_in!(*tokens => .PHONY: $(&self.name));
+            tokens.push();
+        }
+
+        quote_in! { *tokens =>
+            $(self.name):$(for p in self.prerequisites => $[' ']$p)
+        }
+
+        let mut line_start = true;
+        let mut depth = 0i16;
+
+        for item in self.recipe {
+            match item {
+                // NB: nested indentation is written as additional tabs, since
+                // the configured indentation might not start with a tab.
+                Item::Indentation(n) => {
+                    tokens.push();
+                    depth = depth.saturating_add(n).max(0);
+                    line_start = true;
+                }
+                Item::Push | Item::Line | Item::BlankLine => {
+                    tokens.item(item);
+                    line_start = true;
+                }
+                item => {
+                    if line_start {
+                        tokens.push();
+
+                        for _ in 0..=depth {
+                            tokens.append(static_literal("\t"));
+                        }
+
+                        line_start = false;
+                    }
+
+                    tokens.item(item);
+                }
+            }
+        }
+    }
+}
+
+/// A Makefile target, like `build: src/main.c`, followed by its recipe.
+///
+/// Every line of the recipe is prefixed with a literal tab as make requires,
+/// regardless of the configured [fmt::Indentation]. Each nesting level in
+/// the recipe adds another tab. Since the tabs are written at the start of
+/// each line, targets shouldn't be indented themselves.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+/// use genco::fmt;
+///
+/// let all = make::target("all", ["build", "test"], make::Tokens::new());
+///
+/// let test = make::target("test", ["build"], quote! {
+///     ./run-tests --verbose
+///     @echo "done"
+/// });
+///
+/// let toks: make::Tokens = quote! {
+///     $all
+///     $test
+/// };
+///
+/// let fmt = fmt::Config::from_lang::<Make>().with_indentation(fmt::Indentation::Space(4));
+/// let mut w = fmt::VecWriter::new();
+/// toks.format_file(&mut w.as_formatter(&fmt), &make::Config::default())?;
+///
+/// assert_eq!(
+///     vec![
+///         "all: build test",
+///         "test: build",
+///         "\t./run-tests --verbose",
+///         "\t@echo 'done'",
+///     ],
+///     w.into_vec()
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn target<N, I, T>(name: N, prerequisites: I, recipe: T) -> Target
+where
+    N: Into<ItemStr>,
+    I: IntoIterator,
+    I::Item: Into<ItemStr>,
+    T: FormatInto<Make>,
+{
+    let mut tokens = Tokens::new();
+    tokens.append(recipe);
+
+    Target {
+        name: name.into(),
+        prerequisites: prerequisites.into_iter().map(Into::into).collect(),
+        recipe: tokens,
+        phony: false,
+    }
+}
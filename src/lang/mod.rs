@@ -17,27 +17,56 @@
 //! # }
 //! ```
 
+mod capabilities;
+
+pub mod bash;
 pub mod c;
 pub mod csharp;
+pub mod css;
 pub mod dart;
 pub mod go;
+pub mod html;
 pub mod java;
 pub mod js;
+pub mod kotlin;
+pub mod lua;
+pub mod make;
 pub mod nix;
+pub mod php;
+pub mod proto;
 pub mod python;
+pub mod ruby;
 pub mod rust;
+pub mod scala;
+pub mod sql;
 pub mod swift;
+pub mod typescript;
+pub mod yaml;
 
+pub use self::bash::Bash;
 pub use self::c::C;
+pub use self::capabilities::Capabilities;
 pub use self::csharp::Csharp;
+pub use self::css::Css;
 pub use self::dart::Dart;
 pub use self::go::Go;
+pub use self::html::Html;
 pub use self::java::Java;
 pub use self::js::JavaScript;
+pub use self::kotlin::Kotlin;
+pub use self::lua::Lua;
+pub use self::make::Make;
 pub use self::nix::Nix;
+pub use self::php::Php;
+pub use self::proto::Proto;
 pub use self::python::Python;
+pub use self::ruby::Ruby;
 pub use self::rust::Rust;
+pub use self::scala::Scala;
+pub use self::sql::Sql;
 pub use self::swift::Swift;
+pub use self::typescript::TypeScript;
+pub use self::yaml::Yaml;
 
 use crate::fmt;
 use crate::Tokens;
@@ -57,11 +86,97 @@ where
     /// The type used when resolving imports.
     type Item: LangItem<Self>;
 
+    /// Report which features the language specialization supports.
+    fn capabilities() -> Capabilities {
+        Capabilities::default()
+    }
+
     /// Provide the default indentation.
     fn default_indentation() -> fmt::Indentation {
         fmt::Indentation::Space(4)
     }
 
+    /// Provide the default newline.
+    fn default_newline() -> &'static str {
+        "\n"
+    }
+
+    /// Provide the default delimiter to use for quoted strings.
+    ///
+    /// This is only relevant for languages which support more than one kind
+    /// of string delimiter, see [fmt::QuoteStyle].
+    fn default_quote_style() -> fmt::QuoteStyle {
+        fmt::QuoteStyle::Double
+    }
+
+    /// The prefix used for line comments in the language, like `//`.
+    ///
+    /// Languages without line comments, like HTML, return `None`. Comments
+    /// are then written as block comments using
+    /// [block_comment_delimiters][Lang::block_comment_delimiters] instead.
+    fn line_comment_prefix() -> Option<&'static str> {
+        Some("//")
+    }
+
+    /// The delimiters used for block comments in the language, like `/*` and
+    /// `*/`.
+    ///
+    /// These are only used to write comments in languages which don't have
+    /// [line comments][Lang::line_comment_prefix]. If a language has neither,
+    /// comments are left out.
+    fn block_comment_delimiters() -> Option<(&'static str, &'static str)> {
+        None
+    }
+
+    /// The name which represents the absence of a value, like `()` in Rust or
+    /// `void` in Java.
+    ///
+    /// In statically typed languages this is a type. Dynamically typed
+    /// languages and data formats use the value instead, like `None` in
+    /// Python, `nil` in Ruby and Lua, and `null` in YAML. This is `None` for
+    /// languages which have neither, or where it's left out, like the return
+    /// type of a function in Go.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    /// use genco::lang::Lang;
+    ///
+    /// fn callback<L>() -> Tokens<L>
+    /// where
+    ///     L: Lang,
+    /// {
+    ///     quote!(callback: $(L::unit_type()))
+    /// }
+    ///
+    /// assert_eq!("callback: ()", callback::<Rust>().to_string()?);
+    /// assert_eq!("callback: void", callback::<Java>().to_string()?);
+    /// assert_eq!("callback: None", callback::<Python>().to_string()?);
+    /// assert_eq!("callback: Unit", callback::<Kotlin>().to_string()?);
+    /// assert_eq!("callback: nil", callback::<Ruby>().to_string()?);
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    fn unit_type() -> Option<&'static str> {
+        None
+    }
+
+    /// The terminator of a statement, like `;`.
+    ///
+    /// This is empty for languages where statements are terminated by the
+    /// end of the line. It's used by [statement][crate::tokens::statement()].
+    fn statement_terminator() -> &'static str {
+        ";"
+    }
+
+    /// Indicates if adjacent string literals are concatenated by the
+    /// language, like `"foo" "bar"` in C.
+    ///
+    /// This is used by [concat_strings][crate::tokens::concat_strings()].
+    fn concatenates_adjacent_strings() -> bool {
+        false
+    }
+
     /// Start a string quote.
     fn open_quote(
         out: &mut fmt::Formatter<'_>,
@@ -120,10 +235,72 @@ where
     }
 
     /// Performing string quoting according to language convention.
+    ///
+    /// If the input contains a character which can't be represented in a
+    /// string literal of the language, this should fail with
+    /// [fmt::Error::UnsupportedChar], which is passed on to the caller of
+    /// the formatting function.
     fn write_quoted(out: &mut fmt::Formatter<'_>, input: &str) -> fmt::Result {
         use std::fmt::Write as _;
 
-        out.write_str(input)
+        out.write_str(input)?;
+        Ok(())
+    }
+
+    /// Performing string quoting according to language convention, with
+    /// access to the configuration of the language.
+    ///
+    /// This is used for quoted strings by the formatter, and is useful for
+    /// languages where quoting depends on configuration, like the dialect of
+    /// [SQL][sql::Config::with_dialect].
+    ///
+    /// By default this is the same as [write_quoted][Lang::write_quoted].
+    fn write_quoted_with_config(
+        out: &mut fmt::Formatter<'_>,
+        _config: &Self::Config,
+        _format: &Self::Format,
+        input: &str,
+    ) -> fmt::Result {
+        Self::write_quoted(out, input)
+    }
+
+    /// Performing string quoting of the literal parts of a string which
+    /// contains [quoted string interpolation].
+    ///
+    /// By default this is the same as [write_quoted][Lang::write_quoted].
+    ///
+    /// [quoted string interpolation]: https://docs.rs/genco/0/genco/macro.quote.html#quoted-string-interpolation
+    fn write_eval_quoted(out: &mut fmt::Formatter<'_>, input: &str) -> fmt::Result {
+        Self::write_quoted(out, input)
+    }
+
+    /// Performing string quoting of the literal parts of a string which is
+    /// nested inside of an interpolated expression of another string, like
+    /// `"a"` in the Python f-string `f"{d["a"]}"`.
+    ///
+    /// By default this is the same as
+    /// [write_quoted_with_config][Lang::write_quoted_with_config].
+    /// [fmt::Formatter::in_string_eval] can be used to determine if the
+    /// delimiters of a string are written in this position.
+    fn write_nested_quoted(
+        out: &mut fmt::Formatter<'_>,
+        config: &Self::Config,
+        format: &Self::Format,
+        input: &str,
+    ) -> fmt::Result {
+        Self::write_quoted_with_config(out, config, format, input)
+    }
+
+    /// Write the input as a raw string literal, in which escape sequences are
+    /// not processed.
+    ///
+    /// Returns `None` without writing anything if the language doesn't have
+    /// raw strings, or if the input can't be represented as one. In that case
+    /// the input is written as a regular quoted string instead.
+    ///
+    /// This is used by [raw_quoted][crate::tokens::raw_quoted()].
+    fn raw_string(_out: &mut fmt::Formatter<'_>, _input: &str) -> Option<fmt::Result> {
+        None
     }
 
     /// Write a file according to the specified language convention.
@@ -135,6 +312,72 @@ where
         let format = Self::Format::default();
         tokens.format(out, config, &format)
     }
+
+    /// Transform the fully assembled output of a file.
+    ///
+    /// This is an escape hatch for transformations which can't be expressed
+    /// token by token, like trimming trailing whitespace or aligning
+    /// columns. By default it does nothing.
+    ///
+    /// It's called by [Tokens::to_file_string], [Tokens::to_file_string_with]
+    /// and [Tokens::to_file_vec] once formatting has completed, so the output
+    /// includes the imports and everything else written by
+    /// [format_file][Lang::format_file], as well as the trailing newline if
+    /// [fmt::Config::with_trailing_newline] is enabled. The hook is only
+    /// skipped when formatting directly through [Tokens::format_file], which
+    /// streams to the underlying writer and can only be post-processed by the
+    /// caller.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    /// use genco::lang::Lang;
+    ///
+    /// #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    /// struct Trimmed;
+    ///
+    /// impl Lang for Trimmed {
+    ///     type Config = ();
+    ///     type Format = ();
+    ///     type Item = ();
+    ///
+    ///     fn post_process(output: &mut String) {
+    ///         let mut trimmed = String::with_capacity(output.len());
+    ///
+    ///         for line in output.split_inclusive('\n') {
+    ///             let end = line.strip_suffix('\n');
+    ///             trimmed.push_str(end.unwrap_or(line).trim_end());
+    ///
+    ///             if end.is_some() {
+    ///                 trimmed.push('\n');
+    ///             }
+    ///         }
+    ///
+    ///         *output = trimmed;
+    ///     }
+    /// }
+    ///
+    /// let tokens: Tokens<Trimmed> = quote!($("a  \nb "));
+    /// assert_eq!("a\nb\n", tokens.to_file_string()?);
+    /// assert_eq!(vec!["a", "b"], tokens.to_file_vec()?);
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    fn post_process(_output: &mut String) {}
+
+    /// Write only the imports of a file according to the specified language
+    /// convention.
+    ///
+    /// This renders the same sorted and deduplicated imports as
+    /// [format_file][Lang::format_file], but leaves out everything else like
+    /// package declarations and the body of the file.
+    fn format_imports(
+        _tokens: &Tokens<Self>,
+        _out: &mut fmt::Formatter<'_>,
+        _config: &Self::Config,
+    ) -> fmt::Result {
+        Ok(())
+    }
 }
 
 /// Marker trait indicating that a language supports
@@ -16,6 +16,21 @@ impl_lang! {
         type Format = Format;
         type Item = Import;
 
+        fn capabilities() -> crate::lang::Capabilities {
+            crate::lang::Capabilities {
+                block_comments: true,
+                ..Default::default()
+            }
+        }
+
+        fn line_comment_prefix() -> Option<&'static str> {
+            Some("#")
+        }
+
+        fn block_comment_delimiters() -> Option<(&'static str, &'static str)> {
+            Some(("/*", "*/"))
+        }
+
         fn write_quoted(out: &mut fmt::Formatter<'_>, input: &str) -> fmt::Result {
             super::c_family_write_quoted(out, input)
         }
@@ -37,6 +52,21 @@ impl_lang! {
             tokens.format(out, config, &format)?;
             Ok(())
         }
+
+        fn format_imports(
+            tokens: &Tokens,
+            out: &mut fmt::Formatter<'_>,
+            config: &Self::Config,
+        ) -> fmt::Result {
+            let mut header = Tokens::new();
+
+            if !config.scoped {
+                Self::arguments(&mut header, tokens);
+            }
+            Self::withs(&mut header, tokens);
+            Self::imports(&mut header, tokens);
+            header.format(out, config, &Format::default())
+        }
     }
 
     Import {
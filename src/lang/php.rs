@@ -0,0 +1,404 @@
+//! Specialization for PHP code generation.
+//!
+//! Files start with the `<?php` opening tag, followed by the namespace of the
+//! file and the `use` statements for imported classes.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use genco::prelude::*;
+//! use genco::fmt;
+//!
+//! # fn main() -> genco::fmt::Result {
+//! let user = php::import("App\\Models", "User");
+//! let controller = php::import("App\\Http\\Controllers", "Controller");
+//!
+//! let toks: php::Tokens = quote! {
+//!     class UserController extends $controller
+//!     {
+//!         public function show(int $$id): $(&user)
+//!         {
+//!             return $user::findOrFail($$id);
+//!         }
+//!     }
+//! };
+//!
+//! let config = php::Config::default().with_namespace("App\\Http\\Controllers\\Api");
+//! let fmt = fmt::Config::from_lang::<Php>();
+//!
+//! let mut w = fmt::VecWriter::new();
+//! toks.format_file(&mut w.as_formatter(&fmt), &config)?;
+//!
+//! assert_eq!(
+//!     vec![
+//!         "<?php",
+//!         "",
+//!         "namespace App\\Http\\Controllers\\Api;",
+//!         "",
+//!         "use App\\Http\\Controllers\\Controller;",
+//!         "use App\\Models\\User;",
+//!         "",
+//!         "class UserController extends Controller",
+//!         "{",
+//!         "    public function show(int $id): User",
+//!         "    {",
+//!         "        return User::findOrFail($id);",
+//!         "    }",
+//!         "}",
+//!     ],
+//!     w.into_vec(),
+//! );
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # String Quoting in PHP
+//!
+//! Strings are quoted with single quotes by default, in which only `\` and
+//! `'` are escaped. Double quotes can be selected through
+//! [fmt::Config::with_quote_style], in which case `$` is escaped to prevent
+//! interpolation, and control characters are written as escape sequences.
+//!
+//! [fmt::Config::with_quote_style]: crate::fmt::Config::with_quote_style
+//!
+//! ```rust
+//! use genco::prelude::*;
+//! use genco::fmt;
+//!
+//! # fn main() -> genco::fmt::Result {
+//! let toks: php::Tokens = quote!($(quoted("it's $5 \\ more")));
+//! assert_eq!("'it\\'s $5 \\\\ more'", toks.to_string()?);
+//!
+//! let fmt = fmt::Config::from_lang::<Php>().with_quote_style(fmt::QuoteStyle::Double);
+//! let toks: php::Tokens = quote!($(quoted("it's $5 \n \"more\"")));
+//!
+//! let mut w = fmt::VecWriter::new();
+//! toks.format(&mut w.as_formatter(&fmt), &php::Config::default(), &php::Format::default())?;
+//! assert_eq!(vec!["\"it's \\$5 \\n \\\"more\\\"\""], w.into_vec());
+//! # Ok(())
+//! # }
+//! ```
+
+use crate as genco;
+use crate::fmt;
+use crate::quote_in;
+use crate::tokens::ItemStr;
+use std::collections::{BTreeSet, HashMap};
+use std::fmt::Write as _;
+
+/// Tokens container specialization for PHP.
+pub type Tokens = crate::Tokens<Php>;
+
+impl_lang! {
+    /// Language specialization for PHP.
+    pub Php {
+        type Config = Config;
+        type Format = Format;
+        type Item = Import;
+
+        fn capabilities() -> crate::lang::Capabilities {
+            crate::lang::Capabilities {
+                block_comments: true,
+                ..Default::default()
+            }
+        }
+
+        fn block_comment_delimiters() -> Option<(&'static str, &'static str)> {
+            Some(("/*", "*/"))
+        }
+
+        fn default_quote_style() -> fmt::QuoteStyle {
+            fmt::QuoteStyle::Single
+        }
+
+        fn unit_type() -> Option<&'static str> {
+            Some("void")
+        }
+
+        fn open_quote(
+            out: &mut fmt::Formatter<'_>,
+            _config: &Self::Config,
+            _format: &Self::Format,
+            _has_eval: bool,
+        ) -> fmt::Result {
+            out.write_char(out.quote_style().as_char())?;
+            Ok(())
+        }
+
+        fn close_quote(
+            out: &mut fmt::Formatter<'_>,
+            _config: &Self::Config,
+            _format: &Self::Format,
+            _has_eval: bool,
+        ) -> fmt::Result {
+            out.write_char(out.quote_style().as_char())?;
+            Ok(())
+        }
+
+        fn write_quoted(out: &mut fmt::Formatter<'_>, input: &str) -> fmt::Result {
+            // From: https://www.php.net/manual/en/language.types.string.php
+
+            if out.quote_style() == fmt::QuoteStyle::Single {
+                for c in input.chars() {
+                    match c {
+                        '\'' => out.write_str("\\'")?,
+                        '\\' => out.write_str("\\\\")?,
+                        c => out.write_char(c)?,
+                    }
+                }
+
+                return Ok(());
+            }
+
+            for c in input.chars() {
+                match c {
+                    '\n' => out.write_str("\\n")?,
+                    '\r' => out.write_str("\\r")?,
+                    '\t' => out.write_str("\\t")?,
+                    '\u{000b}' => out.write_str("\\v")?,
+                    '\u{001b}' => out.write_str("\\e")?,
+                    '\u{000c}' => out.write_str("\\f")?,
+                    '"' => out.write_str("\\\"")?,
+                    '\\' => out.write_str("\\\\")?,
+                    '$' => out.write_str("\\$")?,
+                    c if !c.is_control() => out.write_char(c)?,
+                    c => {
+                        write!(out, "\\u{{{:x}}}", c as u32)?;
+                    }
+                }
+            }
+
+            Ok(())
+        }
+
+        fn format_file(
+            tokens: &Tokens,
+            out: &mut fmt::Formatter<'_>,
+            config: &Self::Config,
+        ) -> fmt::Result {
+            let mut header = Tokens::new();
+
+            if config.open_tag {
+                quote_in!(header => <?php);
+                header.line();
+            }
+
+            if let Some(namespace) = &config.namespace {
+                quote_in!(header => namespace $namespace;);
+                header.line();
+            }
+
+            let mut format = Format::default();
+            Self::imports(&mut header, tokens, config, &mut format);
+            header.format(out, config, &format)?;
+            tokens.format(out, config, &format)?;
+            Ok(())
+        }
+
+        fn format_imports(
+            tokens: &Tokens,
+            out: &mut fmt::Formatter<'_>,
+            config: &Self::Config,
+        ) -> fmt::Result {
+            let mut imports = Tokens::new();
+            let mut format = Format::default();
+            Self::imports(&mut imports, tokens, config, &mut format);
+            imports.format(out, config, &format)
+        }
+    }
+
+    Import {
+        fn format(&self, out: &mut fmt::Formatter<'_>, config: &Config, format: &Format) -> fmt::Result {
+            let imported = format.imported.get(&self.name);
+
+            if imported == Some(&self.namespace) || is_local(&self.namespace, config) {
+                out.write_str(&self.name)?;
+                return Ok(());
+            }
+
+            out.write_str(SEP)?;
+
+            if !self.namespace.is_empty() {
+                out.write_str(&self.namespace)?;
+                out.write_str(SEP)?;
+            }
+
+            out.write_str(&self.name)?;
+            Ok(())
+        }
+    }
+}
+
+const SEP: &str = "\\";
+
+/// Test if the given namespace is the namespace of the file.
+fn is_local(namespace: &str, config: &Config) -> bool {
+    config.namespace.as_deref().unwrap_or("") == namespace
+}
+
+/// Format state for PHP.
+#[derive(Debug, Default)]
+pub struct Format {
+    /// Names which have been imported, mapped to the namespace they were
+    /// imported from.
+    imported: HashMap<ItemStr, ItemStr>,
+}
+
+/// Configuration for PHP.
+#[derive(Debug)]
+pub struct Config {
+    /// Namespace of the file.
+    namespace: Option<ItemStr>,
+    /// Emit the `<?php` opening tag.
+    open_tag: bool,
+}
+
+impl Config {
+    /// Set the namespace of the file.
+    ///
+    /// Classes from the same namespace are referenced without being
+    /// imported.
+    pub fn with_namespace<N>(self, namespace: N) -> Self
+    where
+        N: Into<ItemStr>,
+    {
+        Self {
+            namespace: Some(namespace.into()),
+            ..self
+        }
+    }
+
+    /// Set if the `<?php` opening tag is emitted. This is enabled by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    /// use genco::fmt;
+    ///
+    /// let user = php::import("App\\Models", "User");
+    /// let toks: php::Tokens = quote!($user::all(););
+    ///
+    /// let config = php::Config::default().with_open_tag(false);
+    /// let fmt = fmt::Config::from_lang::<Php>();
+    ///
+    /// let mut w = fmt::VecWriter::new();
+    /// toks.format_file(&mut w.as_formatter(&fmt), &config)?;
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "use App\\Models\\User;",
+    ///         "",
+    ///         "User::all();",
+    ///     ],
+    ///     w.into_vec(),
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn with_open_tag(self, open_tag: bool) -> Self {
+        Self { open_tag, ..self }
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            namespace: None,
+            open_tag: true,
+        }
+    }
+}
+
+/// The import of a PHP class, like `use App\Models\User;`.
+///
+/// Created through the [import()] function.
+#[derive(Debug, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct Import {
+    /// Namespace of the class.
+    namespace: ItemStr,
+    /// Name of the class.
+    name: ItemStr,
+}
+
+impl Php {
+    fn imports(out: &mut Tokens, tokens: &Tokens, config: &Config, format: &mut Format) {
+        let mut modules = BTreeSet::new();
+
+        for import in tokens.walk_imports() {
+            if is_local(&import.namespace, config) {
+                continue;
+            }
+
+            modules.insert((&import.namespace, &import.name));
+        }
+
+        if modules.is_empty() {
+            return;
+        }
+
+        for (namespace, name) in modules {
+            // Names which are already in use are left fully qualified.
+            if format.imported.contains_key(name) {
+                continue;
+            }
+
+            if namespace.is_empty() {
+                quote_in!(*out => use $name;);
+            } else {
+                quote_in!(*out => use $namespace$(SEP)$name;);
+            }
+
+            out.push();
+            format.imported.insert(name.clone(), namespace.clone());
+        }
+
+        out.line();
+    }
+}
+
+/// Import a class from the given namespace, like `use App\Models\User;`.
+///
+/// Imported classes are referenced by their short name. If two imported
+/// classes have the same name, the first one in sorted order is imported and
+/// the other one is fully qualified. Classes from the global namespace are
+/// imported with an empty namespace.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let a = php::import("App\\Models", "User");
+/// let b = php::import("Legacy\\Models", "User");
+/// let date = php::import("", "DateTime");
+///
+/// let toks: php::Tokens = quote! {
+///     $(&a)::find(1);
+///     $b::find(2);
+///     new $date();
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "<?php",
+///         "",
+///         "use App\\Models\\User;",
+///         "",
+///         "User::find(1);",
+///         "\\Legacy\\Models\\User::find(2);",
+///         "new DateTime();",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn import<N, M>(namespace: N, name: M) -> Import
+where
+    N: Into<ItemStr>,
+    M: Into<ItemStr>,
+{
+    Import {
+        namespace: namespace.into(),
+        name: name.into(),
+    }
+}
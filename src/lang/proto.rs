@@ -0,0 +1,326 @@
+//! Specialization for Protocol Buffers (`.proto`) code generation.
+//!
+//! Files start with a `syntax` declaration, followed by an optional package
+//! and the imported `.proto` files.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use genco::prelude::*;
+//!
+//! # fn main() -> genco::fmt::Result {
+//! let timestamp = proto::import("google/protobuf/timestamp.proto", "google.protobuf.Timestamp");
+//! let user = proto::import("example/user.proto", "example.User");
+//!
+//! let toks: proto::Tokens = quote! {
+//!     message Event {
+//!         $timestamp at = 1;
+//!         $user user = 2;
+//!     }
+//! };
+//!
+//! assert_eq!(
+//!     vec![
+//!         "syntax = \"proto3\";",
+//!         "",
+//!         "import \"example/user.proto\";",
+//!         "import \"google/protobuf/timestamp.proto\";",
+//!         "",
+//!         "message Event {",
+//!         "  google.protobuf.Timestamp at = 1;",
+//!         "  example.User user = 2;",
+//!         "}",
+//!     ],
+//!     toks.to_file_vec()?
+//! );
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # String Quoting in Protocol Buffers
+//!
+//! Strings are quoted with C-style escape sequences.
+//!
+//! ```rust
+//! use genco::prelude::*;
+//!
+//! # fn main() -> genco::fmt::Result {
+//! let toks: proto::Tokens = quote!("start \n \" end");
+//! assert_eq!("\"start \\n \\\" end\"", toks.to_string()?);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate as genco;
+use crate::fmt;
+use crate::quote_in;
+use crate::tokens::{quoted, ItemStr};
+use std::collections::BTreeSet;
+use std::fmt::Write as _;
+
+/// Tokens container specialization for Protocol Buffers.
+pub type Tokens = crate::Tokens<Proto>;
+
+impl_lang! {
+    /// Language specialization for Protocol Buffers.
+    pub Proto {
+        type Config = Config;
+        type Format = Format;
+        type Item = Import;
+
+        fn capabilities() -> crate::lang::Capabilities {
+            crate::lang::Capabilities {
+                block_comments: true,
+                ..Default::default()
+            }
+        }
+
+        fn block_comment_delimiters() -> Option<(&'static str, &'static str)> {
+            Some(("/*", "*/"))
+        }
+
+        fn default_indentation() -> fmt::Indentation {
+            fmt::Indentation::Space(2)
+        }
+
+        fn write_quoted(out: &mut fmt::Formatter<'_>, input: &str) -> fmt::Result {
+            super::c_family_write_quoted(out, input)
+        }
+
+        fn format_file(
+            tokens: &Tokens,
+            out: &mut fmt::Formatter<'_>,
+            config: &Self::Config,
+        ) -> fmt::Result {
+            let mut header = Tokens::new();
+
+            quote_in!(header => syntax = $(quoted(&config.syntax)););
+            header.line();
+
+            if let Some(package) = &config.package {
+                quote_in!(header => package $package;);
+                header.line();
+            }
+
+            Self::imports(&mut header, tokens, config);
+            let format = Format::default();
+            header.format(out, config, &format)?;
+            tokens.format(out, config, &format)?;
+            Ok(())
+        }
+
+        fn format_imports(
+            tokens: &Tokens,
+            out: &mut fmt::Formatter<'_>,
+            config: &Self::Config,
+        ) -> fmt::Result {
+            let mut imports = Tokens::new();
+            Self::imports(&mut imports, tokens, config);
+            imports.format(out, config, &Format::default())
+        }
+    }
+
+    Import {
+        fn format(&self, out: &mut fmt::Formatter<'_>, config: &Config, _: &Format) -> fmt::Result {
+            let name = match (config.package.as_deref(), self.name.rsplit_once(SEP)) {
+                (Some(package), Some((prefix, name))) if prefix == package => name,
+                _ => &self.name,
+            };
+
+            out.write_str(name)?;
+            Ok(())
+        }
+    }
+}
+
+const SEP: char = '.';
+
+/// Format state for Protocol Buffers.
+#[derive(Debug, Default)]
+pub struct Format {}
+
+/// Configuration for Protocol Buffers.
+#[derive(Debug)]
+pub struct Config {
+    /// The syntax version of the file.
+    syntax: ItemStr,
+    /// Package of the file.
+    package: Option<ItemStr>,
+}
+
+impl Config {
+    /// Set the syntax version declared by the file. Defaults to `proto3`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    /// use genco::fmt;
+    ///
+    /// let toks: proto::Tokens = quote!(message Empty {});
+    ///
+    /// let config = proto::Config::default().with_syntax("proto2");
+    /// let fmt = fmt::Config::from_lang::<Proto>();
+    ///
+    /// let mut w = fmt::VecWriter::new();
+    /// toks.format_file(&mut w.as_formatter(&fmt), &config)?;
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "syntax = \"proto2\";",
+    ///         "",
+    ///         "message Empty {}",
+    ///     ],
+    ///     w.into_vec(),
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn with_syntax<S>(self, syntax: S) -> Self
+    where
+        S: Into<ItemStr>,
+    {
+        Self {
+            syntax: syntax.into(),
+            ..self
+        }
+    }
+
+    /// Set the package of the file.
+    ///
+    /// Messages which are imported from the same package are referenced by
+    /// their name without the package.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    /// use genco::fmt;
+    ///
+    /// let user = proto::import("example/user.proto", "example.User");
+    /// let timestamp = proto::import("google/protobuf/timestamp.proto", "google.protobuf.Timestamp");
+    ///
+    /// let toks: proto::Tokens = quote!($user $timestamp);
+    ///
+    /// let config = proto::Config::default().with_package("example");
+    /// let fmt = fmt::Config::from_lang::<Proto>();
+    ///
+    /// let mut w = fmt::VecWriter::new();
+    /// toks.format_file(&mut w.as_formatter(&fmt), &config)?;
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "syntax = \"proto3\";",
+    ///         "",
+    ///         "package example;",
+    ///         "",
+    ///         "import \"example/user.proto\";",
+    ///         "import \"google/protobuf/timestamp.proto\";",
+    ///         "",
+    ///         "User google.protobuf.Timestamp",
+    ///     ],
+    ///     w.into_vec(),
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn with_package<P>(self, package: P) -> Self
+    where
+        P: Into<ItemStr>,
+    {
+        Self {
+            package: Some(package.into()),
+            ..self
+        }
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            syntax: ItemStr::Static("proto3"),
+            package: None,
+        }
+    }
+}
+
+/// The import of a message from a `.proto` file, like
+/// `import "google/protobuf/timestamp.proto";`.
+///
+/// Created through the [import()] function.
+#[derive(Debug, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct Import {
+    /// The file to import.
+    file: ItemStr,
+    /// Fully qualified name of the message.
+    name: ItemStr,
+}
+
+impl Proto {
+    fn imports(out: &mut Tokens, tokens: &Tokens, _: &Config) {
+        let mut files = BTreeSet::new();
+
+        for import in tokens.walk_imports() {
+            files.insert(&import.file);
+        }
+
+        if files.is_empty() {
+            return;
+        }
+
+        for file in files {
+            quote_in!(*out => import $(quoted(file)););
+            out.push();
+        }
+
+        out.line();
+    }
+}
+
+/// Import a message from a `.proto` file.
+///
+/// The name of the message is fully qualified with its package, like
+/// `google.protobuf.Timestamp`. It's rendered without the package if it's
+/// the same as the one configured through [Config::with_package].
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let timestamp = proto::import("google/protobuf/timestamp.proto", "google.protobuf.Timestamp");
+/// let duration = proto::import("google/protobuf/duration.proto", "google.protobuf.Duration");
+///
+/// let toks = quote! {
+///     message Span {
+///         $(&timestamp) start = 1;
+///         $timestamp end = 2;
+///         $duration length = 3;
+///     }
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "syntax = \"proto3\";",
+///         "",
+///         "import \"google/protobuf/duration.proto\";",
+///         "import \"google/protobuf/timestamp.proto\";",
+///         "",
+///         "message Span {",
+///         "  google.protobuf.Timestamp start = 1;",
+///         "  google.protobuf.Timestamp end = 2;",
+///         "  google.protobuf.Duration length = 3;",
+///         "}",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn import<F, N>(file: F, name: N) -> Import
+where
+    F: Into<ItemStr>,
+    N: Into<ItemStr>,
+{
+    Import {
+        file: file.into(),
+        name: name.into(),
+    }
+}
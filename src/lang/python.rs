@@ -16,17 +16,76 @@
 //! # Ok(())
 //! # }
 //! ```
+//!
+//! # F-strings
+//!
+//! Python supports [quoted string interpolation], which is rendered as an
+//! [f-string]. Interpolated expressions are wrapped in `{}`, while literal
+//! braces are escaped as `{{` and `}}`.
+//!
+//! Before Python 3.12, quoted strings inside of an interpolated expression
+//! can't reuse the delimiter of the f-string, nor contain backslashes. By
+//! default they are therefore written with the alternate quote character, and
+//! characters which would need escaping cause an
+//! [UnsupportedChar][fmt::Error::UnsupportedChar] error. Reusing the delimiter
+//! is supported since Python 3.12 ([PEP 701]), which can be enabled with
+//! [Config::with_version].
+//!
+//! [quoted string interpolation]: https://docs.rs/genco/0/genco/macro.quote.html#quoted-string-interpolation
+//! [f-string]: https://docs.python.org/3/reference/lexical_analysis.html#f-strings
+//! [PEP 701]: https://peps.python.org/pep-0701/
+//!
+//! ```rust
+//! use genco::prelude::*;
+//! use genco::fmt;
+//!
+//! # fn main() -> genco::fmt::Result {
+//! let dumps = python::import("json", "dumps");
+//!
+//! let toks: python::Tokens = quote! {
+//!     print($[str](value is $($dumps(x))))
+//!     print($[str]({ literal } $(x)))
+//!     print($[str](key: $(d[$(quoted("a"))])))
+//! };
+//!
+//! assert_eq!(
+//!     vec![
+//!         "from json import dumps",
+//!         "",
+//!         "print(f\"value is {dumps(x)}\")",
+//!         "print(f\"{{ literal }} {x}\")",
+//!         "print(f\"key: {d['a']}\")",
+//!     ],
+//!     toks.to_file_vec()?
+//! );
+//!
+//! let toks: python::Tokens = quote!($[str]($(d[$(quoted("it's"))])));
+//! assert_eq!(
+//!     Err(fmt::Error::UnsupportedChar { character: '\'', index: 2 }),
+//!     toks.to_string(),
+//! );
+//!
+//! let mut w = fmt::VecWriter::new();
+//! let fmt = fmt::Config::from_lang::<Python>();
+//! let config = python::Config::default().with_version(3, 12);
+//! toks.format_file(&mut w.as_formatter(&fmt), &config)?;
+//! assert_eq!(vec!["f\"{d[\"it\\'s\"]}\""], w.into_vec());
+//! # Ok(())
+//! # }
+//! ```
 
 use crate as genco;
 use crate::fmt;
 use crate::tokens::ItemStr;
 use crate::{quote, quote_in};
-use std::collections::{BTreeMap, BTreeSet};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::fmt::Write as _;
 
 /// Tokens container specialization for Python.
 pub type Tokens = crate::Tokens<Python>;
 
+impl crate::lang::LangSupportsEval for Python {}
+
 impl_lang! {
     /// Language specialization for Python.
     pub Python {
@@ -34,37 +93,177 @@ impl_lang! {
         type Format = Format;
         type Item = Any;
 
+        fn capabilities() -> crate::lang::Capabilities {
+            crate::lang::Capabilities {
+                string_interpolation: true,
+                raw_strings: true,
+                adjacent_string_concatenation: true,
+                import_aliasing: true,
+                ..Default::default()
+            }
+        }
+
+        fn unit_type() -> Option<&'static str> {
+            Some("None")
+        }
+
+        fn statement_terminator() -> &'static str {
+            ""
+        }
+
+        fn open_quote(
+            out: &mut fmt::Formatter<'_>,
+            config: &Self::Config,
+            _format: &Self::Format,
+            has_eval: bool,
+        ) -> fmt::Result {
+            if has_eval {
+                out.write_char('f')?;
+            }
+
+            out.write_char(quote_char(out, config))?;
+            Ok(())
+        }
+
+        fn close_quote(
+            out: &mut fmt::Formatter<'_>,
+            config: &Self::Config,
+            _format: &Self::Format,
+            _has_eval: bool,
+        ) -> fmt::Result {
+            out.write_char(quote_char(out, config))?;
+            Ok(())
+        }
+
+        fn start_string_eval(
+            out: &mut fmt::Formatter<'_>,
+            _config: &Self::Config,
+            _format: &Self::Format,
+        ) -> fmt::Result {
+            out.write_char('{')?;
+            Ok(())
+        }
+
+        fn end_string_eval(
+            out: &mut fmt::Formatter<'_>,
+            _config: &Self::Config,
+            _format: &Self::Format,
+        ) -> fmt::Result {
+            out.write_char('}')?;
+            Ok(())
+        }
+
+        fn concatenates_adjacent_strings() -> bool {
+            true
+        }
+
+        fn line_comment_prefix() -> Option<&'static str> {
+            Some("#")
+        }
+
         fn write_quoted(out: &mut fmt::Formatter<'_>, input: &str) -> fmt::Result {
             // From: https://docs.python.org/3/reference/lexical_analysis.html#string-and-bytes-literals
             super::c_family_write_quoted(out, input)
         }
 
+        fn write_eval_quoted(out: &mut fmt::Formatter<'_>, input: &str) -> fmt::Result {
+            // Literal braces in f-strings are escaped by doubling them.
+            let mut last = 0;
+
+            for (n, brace) in input.match_indices(['{', '}']) {
+                super::c_family_write_quoted(out, &input[last..n])?;
+                out.write_str(brace)?;
+                out.write_str(brace)?;
+                last = n + brace.len();
+            }
+
+            super::c_family_write_quoted(out, &input[last..])
+        }
+
+        fn write_nested_quoted(
+            out: &mut fmt::Formatter<'_>,
+            config: &Self::Config,
+            _format: &Self::Format,
+            input: &str,
+        ) -> fmt::Result {
+            if config.supports_nested_quotes() {
+                return Self::write_quoted(out, input);
+            }
+
+            // Expressions in f-strings can't contain backslashes, nor comments
+            // before Python 3.12, so nothing can be escaped.
+            for (index, c) in input.char_indices() {
+                if matches!(c, '\\' | '\'' | '"' | '#') || c.is_control() {
+                    return Err(fmt::Error::UnsupportedChar {
+                        character: c,
+                        index,
+                    });
+                }
+            }
+
+            out.write_str(input)?;
+            Ok(())
+        }
+
+        fn raw_string(out: &mut fmt::Formatter<'_>, input: &str) -> Option<fmt::Result> {
+            let quote = out.quote_style().as_char();
+
+            // Raw strings can't contain their own delimiter, nor end with an
+            // odd number of backslashes.
+            if input.contains([quote, '\r', '\n']) || input.ends_with('\\') {
+                return None;
+            }
+
+            Some(write!(out, "r{quote}{input}{quote}").map_err(fmt::Error::from))
+        }
+
         fn format_file(
             tokens: &Tokens,
             out: &mut fmt::Formatter<'_>,
             config: &Self::Config,
         ) -> fmt::Result {
             let mut imports = Tokens::new();
-            Self::imports(&mut imports, tokens);
-            let format = Format::default();
+            let mut format = Format::default();
+            Self::imports(&mut imports, tokens, &mut format);
             imports.format(out, config, &format)?;
             tokens.format(out, config, &format)?;
             Ok(())
         }
+
+        fn format_imports(
+            tokens: &Tokens,
+            out: &mut fmt::Formatter<'_>,
+            config: &Self::Config,
+        ) -> fmt::Result {
+            let mut imports = Tokens::new();
+            let mut format = Format::default();
+            Self::imports(&mut imports, tokens, &mut format);
+            imports.format(out, config, &format)
+        }
     }
 
     Import {
-        fn format(&self, out: &mut fmt::Formatter<'_>, _: &Config, _: &Format) -> fmt::Result {
-            if let TypeModule::Qualified { module, alias }  = &self.module {
-                out.write_str(alias.as_ref().unwrap_or(module))?;
-                out.write_str(SEP)?;
-            }
-
+        fn format(&self, out: &mut fmt::Formatter<'_>, _: &Config, format: &Format) -> fmt::Result {
             let name = match &self.alias {
                 Some(alias) => alias,
                 None => &self.name,
             };
 
+            match &self.module {
+                TypeModule::Qualified { module, alias } => {
+                    out.write_str(alias.as_ref().unwrap_or(module))?;
+                    out.write_str(SEP)?;
+                }
+                TypeModule::Unqualified { module } => {
+                    if format.conflicts.contains(&(module.clone(), name.clone())) {
+                        out.write_str(module)?;
+                        out.write_str(SEP)?;
+                        out.write_str(&self.name)?;
+                        return Ok(());
+                    }
+                }
+            }
+
             out.write_str(name)?;
             Ok(())
         }
@@ -85,10 +284,45 @@ impl_lang! {
 
 /// Formatting state for python.
 #[derive(Debug, Default)]
-pub struct Format {}
+pub struct Format {
+    /// Modules and local names of imports that conflict with a name that was
+    /// imported from another module, and which are used qualified instead.
+    conflicts: HashSet<(ItemStr, ItemStr)>,
+}
 /// Configuration for python.
 #[derive(Debug, Default)]
-pub struct Config {}
+pub struct Config {
+    /// The targeted version of Python, if any.
+    version: Option<(u32, u32)>,
+}
+
+impl Config {
+    /// Configure the version of Python to generate code for.
+    ///
+    /// By default, generated code is compatible with all versions of Python 3.
+    /// Targeting Python 3.12 or later allows quoted strings inside of
+    /// f-string interpolations to reuse the delimiter of the f-string.
+    pub fn with_version(self, major: u32, minor: u32) -> Self {
+        Self {
+            version: Some((major, minor)),
+        }
+    }
+
+    /// Test if quoted strings nested in f-strings can reuse its delimiter.
+    fn supports_nested_quotes(&self) -> bool {
+        matches!(self.version, Some(version) if version >= (3, 12))
+    }
+}
+
+/// Get the delimiter to use for a string, which is the alternate quote
+/// character for strings nested in f-strings before Python 3.12.
+fn quote_char(out: &fmt::Formatter<'_>, config: &Config) -> char {
+    match out.quote_style() {
+        fmt::QuoteStyle::Double if out.in_string_eval() && !config.supports_nested_quotes() => '\'',
+        fmt::QuoteStyle::Single if out.in_string_eval() && !config.supports_nested_quotes() => '"',
+        style => style.as_char(),
+    }
+}
 
 static SEP: &str = ".";
 
@@ -285,7 +519,7 @@ impl ImportModule {
 }
 
 impl Python {
-    fn imports(out: &mut Tokens, tokens: &Tokens) {
+    fn imports(out: &mut Tokens, tokens: &Tokens, format: &mut Format) {
         let mut imported_from = BTreeMap::new();
         let mut imports = BTreeSet::new();
 
@@ -316,7 +550,34 @@ impl Python {
             return;
         }
 
+        // A name can only be imported from one module. The first module in
+        // sorted order wins, and the others are imported as modules and used
+        // qualified.
+        let mut names = HashMap::new();
+
+        for (module, names_from) in &mut imported_from {
+            names_from.retain(|(name, alias)| {
+                let local = alias.as_ref().unwrap_or(name);
+
+                match names.get(local) {
+                    Some(m) if m != module => {
+                        format.conflicts.insert(((*module).clone(), local.clone()));
+                        imports.insert((*module, &None));
+                        false
+                    }
+                    _ => {
+                        names.insert(local, *module);
+                        true
+                    }
+                }
+            });
+        }
+
         for (module, imports) in imported_from {
+            if imports.is_empty() {
+                continue;
+            }
+
             out.push();
 
             let imports = imports
@@ -349,6 +610,11 @@ impl Python {
 
 /// The import of a Python name `from module import foo`.
 ///
+/// Names imported from the same module are grouped into a single `from`
+/// import. If the same name is imported from more than one module, it's
+/// imported from the first module in sorted order, and the other modules are
+/// imported as a whole and used qualified.
+///
 /// # Examples
 ///
 /// ```
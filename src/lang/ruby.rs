@@ -0,0 +1,447 @@
+//! Specialization for Ruby code generation.
+//!
+//! Files start with the `require` and `require_relative` statements of the
+//! features which are used in them, sorted and deduplicated.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use genco::prelude::*;
+//!
+//! # fn main() -> genco::fmt::Result {
+//! let json = ruby::import("json", "JSON");
+//! let user = ruby::import_relative("./models/user", "Models::User");
+//!
+//! let toks: ruby::Tokens = quote! {
+//!     $(register(ruby::require("set")))
+//!
+//!     def load(path)
+//!         $user.new($json.parse(File.read(path)))
+//!     end
+//! };
+//!
+//! assert_eq!(
+//!     vec![
+//!         "require 'json'",
+//!         "require 'set'",
+//!         "require_relative './models/user'",
+//!         "",
+//!         "def load(path)",
+//!         "  Models::User.new(JSON.parse(File.read(path)))",
+//!         "end",
+//!     ],
+//!     toks.to_file_vec()?
+//! );
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # String Quoting in Ruby
+//!
+//! Strings are quoted with single quotes by default, in which only `\` and
+//! `'` are escaped. Strings which use [quoted string interpolation] are
+//! always quoted with double quotes and rendered as `#{..}`, since single
+//! quoted strings don't support interpolation.
+//!
+//! [quoted string interpolation]: https://docs.rs/genco/0/genco/macro.quote.html#quoted-string-interpolation
+//!
+//! ```rust
+//! use genco::prelude::*;
+//!
+//! # fn main() -> genco::fmt::Result {
+//! let toks: ruby::Tokens = quote!("it's \n");
+//! assert_eq!("'it\\'s \n'", toks.to_string()?);
+//!
+//! let toks: ruby::Tokens = quote!($[str](Hello #$(name.upcase)));
+//! assert_eq!("\"Hello \\##{name.upcase}\"", toks.to_string()?);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate as genco;
+use crate::fmt;
+use crate::quote_in;
+use crate::tokens::{quoted, ItemStr};
+use std::collections::BTreeSet;
+use std::fmt::Write as _;
+
+/// Tokens container specialization for Ruby.
+pub type Tokens = crate::Tokens<Ruby>;
+
+impl crate::lang::LangSupportsEval for Ruby {}
+
+impl_lang! {
+    /// Language specialization for Ruby.
+    pub Ruby {
+        type Config = Config;
+        type Format = Format;
+        type Item = Any;
+
+        fn capabilities() -> crate::lang::Capabilities {
+            crate::lang::Capabilities {
+                string_interpolation: true,
+                ..Default::default()
+            }
+        }
+
+        fn default_indentation() -> fmt::Indentation {
+            fmt::Indentation::Space(2)
+        }
+
+        fn default_quote_style() -> fmt::QuoteStyle {
+            fmt::QuoteStyle::Single
+        }
+
+        fn line_comment_prefix() -> Option<&'static str> {
+            Some("#")
+        }
+
+        fn unit_type() -> Option<&'static str> {
+            Some("nil")
+        }
+
+        fn statement_terminator() -> &'static str {
+            ""
+        }
+
+        fn open_quote(
+            out: &mut fmt::Formatter<'_>,
+            _config: &Self::Config,
+            _format: &Self::Format,
+            has_eval: bool,
+        ) -> fmt::Result {
+            if has_eval {
+                out.write_char('"')?;
+            } else {
+                out.write_char(out.quote_style().as_char())?;
+            }
+
+            Ok(())
+        }
+
+        fn close_quote(
+            out: &mut fmt::Formatter<'_>,
+            _config: &Self::Config,
+            _format: &Self::Format,
+            has_eval: bool,
+        ) -> fmt::Result {
+            if has_eval {
+                out.write_char('"')?;
+            } else {
+                out.write_char(out.quote_style().as_char())?;
+            }
+
+            Ok(())
+        }
+
+        fn start_string_eval(
+            out: &mut fmt::Formatter<'_>,
+            _config: &Self::Config,
+            _format: &Self::Format,
+        ) -> fmt::Result {
+            out.write_str("#{")?;
+            Ok(())
+        }
+
+        fn end_string_eval(
+            out: &mut fmt::Formatter<'_>,
+            _config: &Self::Config,
+            _format: &Self::Format,
+        ) -> fmt::Result {
+            out.write_char('}')?;
+            Ok(())
+        }
+
+        fn write_quoted(out: &mut fmt::Formatter<'_>, input: &str) -> fmt::Result {
+            // From: https://docs.ruby-lang.org/en/master/syntax/literals_rdoc.html#label-Strings
+
+            if out.quote_style() == fmt::QuoteStyle::Single {
+                for c in input.chars() {
+                    match c {
+                        '\'' => out.write_str("\\'")?,
+                        '\\' => out.write_str("\\\\")?,
+                        c => out.write_char(c)?,
+                    }
+                }
+
+                return Ok(());
+            }
+
+            write_double_quoted(out, input)
+        }
+
+        fn write_eval_quoted(out: &mut fmt::Formatter<'_>, input: &str) -> fmt::Result {
+            write_double_quoted(out, input)
+        }
+
+        fn format_file(
+            tokens: &Tokens,
+            out: &mut fmt::Formatter<'_>,
+            config: &Self::Config,
+        ) -> fmt::Result {
+            let mut header = Tokens::new();
+            Self::requires(&mut header, tokens);
+            let format = Format::default();
+            header.format(out, config, &format)?;
+            tokens.format(out, config, &format)?;
+            Ok(())
+        }
+
+        fn format_imports(
+            tokens: &Tokens,
+            out: &mut fmt::Formatter<'_>,
+            config: &Self::Config,
+        ) -> fmt::Result {
+            let mut requires = Tokens::new();
+            Self::requires(&mut requires, tokens);
+            requires.format(out, config, &Format::default())
+        }
+    }
+
+    Require {
+        fn format(&self, _: &mut fmt::Formatter<'_>, _: &Config, _: &Format) -> fmt::Result {
+            Ok(())
+        }
+    }
+
+    Import {
+        fn format(&self, out: &mut fmt::Formatter<'_>, _: &Config, _: &Format) -> fmt::Result {
+            out.write_str(&self.name)?;
+            Ok(())
+        }
+    }
+}
+
+/// Write a double quoted string, in which `#` is escaped to prevent
+/// interpolation.
+fn write_double_quoted(out: &mut fmt::Formatter<'_>, input: &str) -> fmt::Result {
+    for c in input.chars() {
+        match c {
+            '\n' => out.write_str("\\n")?,
+            '\r' => out.write_str("\\r")?,
+            '\t' => out.write_str("\\t")?,
+            '\u{0007}' => out.write_str("\\a")?,
+            '\u{0008}' => out.write_str("\\b")?,
+            '\u{000b}' => out.write_str("\\v")?,
+            '\u{000c}' => out.write_str("\\f")?,
+            '\u{001b}' => out.write_str("\\e")?,
+            '"' => out.write_str("\\\"")?,
+            '\\' => out.write_str("\\\\")?,
+            '#' => out.write_str("\\#")?,
+            c if !c.is_control() => out.write_char(c)?,
+            c => {
+                write!(out, "\\u{{{:x}}}", c as u32)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Format state for Ruby.
+#[derive(Debug, Default)]
+pub struct Format {}
+
+/// Configuration for Ruby.
+#[derive(Debug, Default)]
+pub struct Config {}
+
+/// A required feature, like `require 'json'`.
+///
+/// Created through the [require()] and [require_relative()] functions.
+#[derive(Debug, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct Require {
+    /// If the feature is required relative to the current file.
+    relative: bool,
+    /// The path of the required feature.
+    path: ItemStr,
+}
+
+/// A constant which is defined by a required feature, like `JSON`.
+///
+/// Created through the [import()] and [import_relative()] functions.
+#[derive(Debug, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct Import {
+    /// The feature which defines the constant.
+    require: Require,
+    /// The path of the constant, like `Foo::Bar`.
+    name: ItemStr,
+}
+
+impl Ruby {
+    fn requires(out: &mut Tokens, tokens: &Tokens) {
+        let mut requires = BTreeSet::new();
+
+        for import in tokens.walk_imports() {
+            match import {
+                Any::Require(require) => {
+                    requires.insert(require);
+                }
+                Any::Import(import) => {
+                    requires.insert(&import.require);
+                }
+            }
+        }
+
+        if requires.is_empty() {
+            return;
+        }
+
+        for Require { relative, path } in requires {
+            if *relative {
+                quote_in!(*out => require_relative $(quoted(path)));
+            } else {
+                quote_in!(*out => require $(quoted(path)));
+            }
+
+            out.push();
+        }
+
+        out.line();
+    }
+}
+
+/// Require a feature for its side effects, like `require 'json'`.
+///
+/// The require doesn't render anything, so it's typically added through
+/// [register][crate::tokens::register()]. Requires are deduplicated and
+/// sorted, with `require_relative` statements after the other ones.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let toks: ruby::Tokens = quote! {
+///     $(register(ruby::require("set")))
+///     $(register(ruby::require("json")))
+///     $(register(ruby::require("set")))
+///     puts Set.new([1, 2]).to_a.to_json
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "require 'json'",
+///         "require 'set'",
+///         "",
+///         "puts Set.new([1, 2]).to_a.to_json",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn require<P>(path: P) -> Require
+where
+    P: Into<ItemStr>,
+{
+    Require {
+        relative: false,
+        path: path.into(),
+    }
+}
+
+/// Require a feature relative to the current file, like
+/// `require_relative './foo'`.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let toks: ruby::Tokens = quote! {
+///     $(register(ruby::require_relative("./helpers")))
+///     $(register(ruby::require("json")))
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "require 'json'",
+///         "require_relative './helpers'",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn require_relative<P>(path: P) -> Require
+where
+    P: Into<ItemStr>,
+{
+    Require {
+        relative: true,
+        path: path.into(),
+    }
+}
+
+/// Reference a constant defined by a required feature.
+///
+/// The constant is rendered as given, including any module nesting like
+/// `Foo::Bar`, and the feature is required by the file.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let digest = ruby::import("digest", "Digest::SHA256");
+/// let json = ruby::import("json", "JSON");
+///
+/// let toks: ruby::Tokens = quote! {
+///     $json.generate($(&digest).hexdigest(data))
+///     $digest.hexdigest(other)
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "require 'digest'",
+///         "require 'json'",
+///         "",
+///         "JSON.generate(Digest::SHA256.hexdigest(data))",
+///         "Digest::SHA256.hexdigest(other)",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn import<P, N>(path: P, name: N) -> Import
+where
+    P: Into<ItemStr>,
+    N: Into<ItemStr>,
+{
+    Import {
+        require: require(path),
+        name: name.into(),
+    }
+}
+
+/// Reference a constant defined by a feature which is required relative to
+/// the current file.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let user = ruby::import_relative("./models/user", "Models::User");
+///
+/// let toks: ruby::Tokens = quote!($user.find(1));
+///
+/// assert_eq!(
+///     vec![
+///         "require_relative './models/user'",
+///         "",
+///         "Models::User.find(1)",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn import_relative<P, N>(path: P, name: N) -> Import
+where
+    P: Into<ItemStr>,
+    N: Into<ItemStr>,
+{
+    Import {
+        require: require_relative(path),
+        name: name.into(),
+    }
+}
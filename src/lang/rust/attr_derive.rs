@@ -0,0 +1,35 @@
+use crate as genco;
+use crate::lang::Rust;
+use crate::quote_in;
+use crate::tokens::FormatInto;
+use crate::Tokens;
+
+/// A derive attribute, like `#[derive(Debug, Clone)]`.
+///
+/// This struct is created by the [attr_derive][super::attr_derive()]
+/// function.
+#[derive(Debug, Clone)]
+pub struct AttrDerive {
+    derives: Vec<Tokens<Rust>>,
+}
+
+impl AttrDerive {
+    pub(super) fn new(derives: Vec<Tokens<Rust>>) -> Self {
+        Self { derives }
+    }
+
+    /// Test if there are no derives.
+    pub fn is_empty(&self) -> bool {
+        self.derives.is_empty()
+    }
+}
+
+impl FormatInto<Rust> for AttrDerive {
+    fn format_into(self, tokens: &mut Tokens<Rust>) {
+        if self.derives.is_empty() {
+            return;
+        }
+
+        quote_in!(*tokens => #[derive($(for d in self.derives join (, ) => $d))]);
+    }
+}
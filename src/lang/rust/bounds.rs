@@ -0,0 +1,30 @@
+use crate as genco;
+use crate::lang::Rust;
+use crate::quote_in;
+use crate::tokens::FormatInto;
+use crate::Tokens;
+
+/// Trait and lifetime bounds, like `Clone + Send + 'static`.
+///
+/// This struct is created by the [bounds][super::bounds()] function.
+#[derive(Debug, Clone)]
+pub struct Bounds {
+    bounds: Vec<Tokens<Rust>>,
+}
+
+impl Bounds {
+    pub(super) fn new(bounds: Vec<Tokens<Rust>>) -> Self {
+        Self { bounds }
+    }
+
+    /// Test if there are no bounds.
+    pub fn is_empty(&self) -> bool {
+        self.bounds.is_empty()
+    }
+}
+
+impl FormatInto<Rust> for Bounds {
+    fn format_into(self, tokens: &mut Tokens<Rust>) {
+        quote_in!(*tokens => $(for b in self.bounds join ( + ) => $b));
+    }
+}
@@ -0,0 +1,94 @@
+use crate as genco;
+use crate::lang::Rust;
+use crate::quote_in;
+use crate::tokens::FormatInto;
+use crate::Tokens;
+
+/// A conditional compilation attribute, like `#[cfg(test)]`.
+///
+/// This struct is created by the [cfg][super::cfg()] function.
+#[derive(Debug, Clone)]
+pub struct Cfg {
+    predicate: Tokens<Rust>,
+    item: Option<Tokens<Rust>>,
+}
+
+impl Cfg {
+    pub(super) fn new(predicate: Tokens<Rust>) -> Self {
+        Self {
+            predicate,
+            item: None,
+        }
+    }
+
+    /// Put the attribute above the given item, on a line of its own.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    ///
+    /// let item = quote! {
+    ///     mod tests {
+    ///         use super::*;
+    ///     }
+    /// };
+    ///
+    /// let toks: rust::Tokens = quote!($(rust::cfg("test").wrap(item)));
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "#[cfg(test)]",
+    ///         "mod tests {",
+    ///         "    use super::*;",
+    ///         "}",
+    ///     ],
+    ///     toks.to_file_vec()?
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn wrap<T>(self, item: T) -> Self
+    where
+        T: FormatInto<Rust>,
+    {
+        let mut tokens = Tokens::new();
+        tokens.append(item);
+
+        Self {
+            item: Some(tokens),
+            ..self
+        }
+    }
+}
+
+impl FormatInto<Rust> for Cfg {
+    fn format_into(self, tokens: &mut Tokens<Rust>) {
+        quote_in!(*tokens => #[cfg($(self.predicate))]);
+
+        if let Some(item) = self.item {
+            tokens.push();
+            tokens.append(item);
+        }
+    }
+}
+
+/// A conditional attribute, like `#[cfg_attr(test, derive(Debug))]`.
+///
+/// This struct is created by the [cfg_attr][super::cfg_attr()] function.
+#[derive(Debug, Clone)]
+pub struct CfgAttr {
+    predicate: Tokens<Rust>,
+    attr: Tokens<Rust>,
+}
+
+impl CfgAttr {
+    pub(super) fn new(predicate: Tokens<Rust>, attr: Tokens<Rust>) -> Self {
+        Self { predicate, attr }
+    }
+}
+
+impl FormatInto<Rust> for CfgAttr {
+    fn format_into(self, tokens: &mut Tokens<Rust>) {
+        quote_in!(*tokens => #[cfg_attr($(self.predicate), $(self.attr))]);
+    }
+}
@@ -0,0 +1,68 @@
+use crate as genco;
+use crate::lang::Rust;
+use crate::quote_in;
+use crate::tokens::FormatInto;
+use crate::Tokens;
+
+/// A closure expression, like `move |x: u32| -> u32 { x + 1 }`.
+///
+/// This struct is created by the [closure][super::closure()] function.
+#[derive(Debug, Clone)]
+pub struct Closure {
+    is_move: bool,
+    params: Vec<Tokens<Rust>>,
+    returns: Option<Tokens<Rust>>,
+    body: Tokens<Rust>,
+}
+
+impl Closure {
+    pub(super) fn new(params: Vec<Tokens<Rust>>, body: Tokens<Rust>) -> Self {
+        Self {
+            is_move: false,
+            params,
+            returns: None,
+            body,
+        }
+    }
+
+    /// Configure whether the closure captures by value, using `move`.
+    pub fn move_(self, is_move: bool) -> Self {
+        Self { is_move, ..self }
+    }
+
+    /// Set the return type of the closure.
+    pub fn returns<T>(self, ty: T) -> Self
+    where
+        T: FormatInto<Rust>,
+    {
+        let mut returns = Tokens::new();
+        returns.append(ty);
+
+        Self {
+            returns: Some(returns),
+            ..self
+        }
+    }
+}
+
+impl FormatInto<Rust> for Closure {
+    fn format_into(self, tokens: &mut Tokens<Rust>) {
+        if self.is_move {
+            quote_in!(*tokens => move$[' ']);
+        }
+
+        quote_in!(*tokens => |$(for p in self.params join (, ) => $p)|);
+
+        if let Some(returns) = self.returns {
+            quote_in!(*tokens => $[' ']->$[' ']$returns);
+        }
+
+        tokens.space();
+
+        quote_in! { *tokens =>
+            {
+                $(self.body)
+            }
+        }
+    }
+}
@@ -0,0 +1,92 @@
+use crate as genco;
+use crate::lang::Rust;
+use crate::quote_in;
+use crate::tokens::FormatInto;
+use crate::Tokens;
+
+/// A destructuring `let` binding of a struct, like
+/// `let Point { x, y, .. } = point;`.
+///
+/// This struct is created by the [destructure][super::destructure()]
+/// function.
+#[derive(Debug, Clone)]
+pub struct Destructure {
+    path: Tokens<Rust>,
+    expr: Tokens<Rust>,
+    fields: Vec<Tokens<Rust>>,
+    rest: bool,
+}
+
+impl Destructure {
+    pub(super) fn new(path: Tokens<Rust>, expr: Tokens<Rust>) -> Self {
+        Self {
+            path,
+            expr,
+            fields: Vec::new(),
+            rest: false,
+        }
+    }
+
+    /// Add a field to bind, like `a` or `a: renamed`.
+    pub fn field<T>(mut self, field: T) -> Self
+    where
+        T: FormatInto<Rust>,
+    {
+        let mut tokens = Tokens::new();
+        tokens.append(field);
+        self.fields.push(tokens);
+        self
+    }
+
+    /// Configure whether the remaining fields are ignored using `..`.
+    pub fn rest(self, rest: bool) -> Self {
+        Self { rest, ..self }
+    }
+}
+
+impl FormatInto<Rust> for Destructure {
+    fn format_into(self, tokens: &mut Tokens<Rust>) {
+        let mut fields = self.fields;
+
+        if self.rest {
+            let mut rest = Tokens::new();
+            rest.append("..");
+            fields.push(rest);
+        }
+
+        quote_in! { *tokens =>
+            let $(self.path) $(if fields.is_empty() {
+                {}
+            } else {
+                { $(for f in fields join (, ) => $f) }
+            }) = $(self.expr);
+        }
+    }
+}
+
+/// A destructuring `let` binding of a tuple, like `let (a, b) = pair;`.
+///
+/// This struct is created by the [destructure_tuple][super::destructure_tuple()]
+/// function.
+#[derive(Debug, Clone)]
+pub struct DestructureTuple {
+    bindings: Vec<Tokens<Rust>>,
+    expr: Tokens<Rust>,
+}
+
+impl DestructureTuple {
+    pub(super) fn new(bindings: Vec<Tokens<Rust>>, expr: Tokens<Rust>) -> Self {
+        Self { bindings, expr }
+    }
+}
+
+impl FormatInto<Rust> for DestructureTuple {
+    fn format_into(self, tokens: &mut Tokens<Rust>) {
+        // A tuple with a single element needs a trailing comma.
+        let trailing = (self.bindings.len() == 1).then_some(",");
+
+        quote_in! { *tokens =>
+            let ($(for b in self.bindings join (, ) => $b)$trailing) = $(self.expr);
+        }
+    }
+}
@@ -0,0 +1,99 @@
+use crate as genco;
+use crate::lang::Rust;
+use crate::quote_in;
+use crate::tokens::{FormatInto, ItemStr};
+use crate::Tokens;
+
+/// An enum declaration, like `#[repr(u8)] enum Name { A = 0, B = 1 }`.
+///
+/// This struct is created by the [enum_decl][super::enum_decl()] function.
+#[derive(Debug, Clone)]
+pub struct EnumDecl {
+    name: ItemStr,
+    repr: Option<ItemStr>,
+    variants: Vec<Variant>,
+}
+
+impl EnumDecl {
+    pub(super) fn new(name: ItemStr) -> Self {
+        Self {
+            name,
+            repr: None,
+            variants: Vec::new(),
+        }
+    }
+
+    /// Set the representation of the enum, emitted as `#[repr(...)]`.
+    pub fn repr<R>(self, repr: R) -> Self
+    where
+        R: Into<ItemStr>,
+    {
+        Self {
+            repr: Some(repr.into()),
+            ..self
+        }
+    }
+
+    /// Add a variant to the enum.
+    pub fn variant(mut self, variant: Variant) -> Self {
+        self.variants.push(variant);
+        self
+    }
+}
+
+impl FormatInto<Rust> for EnumDecl {
+    fn format_into(self, tokens: &mut Tokens<Rust>) {
+        if let Some(repr) = self.repr {
+            quote_in!(*tokens => #[repr($repr)]);
+            tokens.push();
+        }
+
+        quote_in! { *tokens =>
+            enum $(self.name) {
+                $(for v in self.variants join ($['\r']) => $v,)
+            }
+        }
+    }
+}
+
+/// A variant of an enum, with an optional explicit discriminant.
+///
+/// This struct is created by the [variant][super::variant()] function.
+#[derive(Debug, Clone)]
+pub struct Variant {
+    name: ItemStr,
+    discriminant: Option<Tokens<Rust>>,
+}
+
+impl Variant {
+    pub(super) fn new(name: ItemStr) -> Self {
+        Self {
+            name,
+            discriminant: None,
+        }
+    }
+
+    /// Set the explicit discriminant of the variant, like `A = 3`.
+    pub fn discriminant<T>(self, expr: T) -> Self
+    where
+        T: FormatInto<Rust>,
+    {
+        let mut discriminant = Tokens::new();
+        discriminant.append(expr);
+
+        Self {
+            discriminant: Some(discriminant),
+            ..self
+        }
+    }
+}
+
+impl FormatInto<Rust> for Variant {
+    fn format_into(self, tokens: &mut Tokens<Rust>) {
+        quote_in!(*tokens => $(self.name));
+
+        if let Some(discriminant) = self.discriminant {
+            quote_in!(*tokens => $[' ']= $discriminant);
+        }
+    }
+}
@@ -0,0 +1,41 @@
+use crate as genco;
+use crate::lang::Rust;
+use crate::quote_in;
+use crate::tokens::FormatInto;
+use crate::Tokens;
+
+/// An `if let` expression.
+///
+/// This is created by the [if_let][super::if_let()] function.
+pub struct IfLet<P, X, B, E> {
+    pub(super) pattern: P,
+    pub(super) expr: X,
+    pub(super) body: B,
+    pub(super) else_body: Option<E>,
+}
+
+impl<P, X, B, E> FormatInto<Rust> for IfLet<P, X, B, E>
+where
+    P: FormatInto<Rust>,
+    X: FormatInto<Rust>,
+    B: FormatInto<Rust>,
+    E: FormatInto<Rust>,
+{
+    fn format_into(self, tokens: &mut Tokens<Rust>) {
+        quote_in! { *tokens =>
+            if let $(self.pattern) = $(self.expr) {
+                $(self.body)
+            }
+        }
+
+        if let Some(else_body) = self.else_body {
+            tokens.space();
+
+            quote_in! { *tokens =>
+                else {
+                    $else_body
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use crate as genco;
+use crate::lang::Rust;
+use crate::quote_in;
+use crate::tokens::FormatInto;
+use crate::Tokens;
+
+/// A `let ... else` statement.
+///
+/// This is created by the [let_else][super::let_else()] function.
+pub struct LetElse<P, X, D> {
+    pub(super) pattern: P,
+    pub(super) expr: X,
+    pub(super) diverging_body: D,
+}
+
+impl<P, X, D> FormatInto<Rust> for LetElse<P, X, D>
+where
+    P: FormatInto<Rust>,
+    X: FormatInto<Rust>,
+    D: FormatInto<Rust>,
+{
+    fn format_into(self, tokens: &mut Tokens<Rust>) {
+        quote_in! { *tokens =>
+            let $(self.pattern) = $(self.expr) else {
+                $(self.diverging_body)
+            };
+        }
+    }
+}
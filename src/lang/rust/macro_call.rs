@@ -0,0 +1,46 @@
+use crate as genco;
+use crate::lang::Rust;
+use crate::quote_in;
+use crate::tokens::FormatInto;
+use crate::Tokens;
+
+/// The delimiter of a macro invocation.
+///
+/// Used with the [macro_call][super::macro_call()] function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Delimiter {
+    /// Parenthesis, like `println!(..)`.
+    Paren,
+    /// Brackets, like `vec![..]`.
+    Bracket,
+    /// Braces, like `thread_local! { .. }`. The body is written on lines of
+    /// its own, indented one level deeper.
+    Brace,
+}
+
+/// A macro invocation, like `vec![1, 2, 3]`.
+///
+/// This is created by the [macro_call][super::macro_call()] function.
+pub struct MacroCall<P, B> {
+    pub(super) path: P,
+    pub(super) delimiter: Delimiter,
+    pub(super) body: B,
+}
+
+impl<P, B> FormatInto<Rust> for MacroCall<P, B>
+where
+    P: FormatInto<Rust>,
+    B: FormatInto<Rust>,
+{
+    fn format_into(self, tokens: &mut Tokens<Rust>) {
+        match self.delimiter {
+            Delimiter::Paren => quote_in!(*tokens => $(self.path)!($(self.body))),
+            Delimiter::Bracket => quote_in!(*tokens => $(self.path)![$(self.body)]),
+            Delimiter::Brace => quote_in! { *tokens =>
+                $(self.path)! {
+                    $(self.body)
+                }
+            },
+        }
+    }
+}
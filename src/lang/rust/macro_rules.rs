@@ -0,0 +1,32 @@
+use crate as genco;
+use crate::lang::Rust;
+use crate::quote_in;
+use crate::tokens::{FormatInto, ItemStr};
+use crate::Tokens;
+
+/// A `macro_rules!` definition.
+///
+/// This is created by the [macro_rules][super::macro_rules()] function.
+pub struct MacroRules<I> {
+    pub(super) name: ItemStr,
+    pub(super) rules: I,
+}
+
+impl<I, M, E> FormatInto<Rust> for MacroRules<I>
+where
+    I: IntoIterator<Item = (M, E)>,
+    M: FormatInto<Rust>,
+    E: FormatInto<Rust>,
+{
+    fn format_into(self, tokens: &mut Tokens<Rust>) {
+        quote_in! { *tokens =>
+            macro_rules! $(self.name) {
+                $(for (matcher, expansion) in self.rules join ($['\r']) {
+                    ($matcher) => {
+                        $expansion
+                    };
+                })
+            }
+        }
+    }
+}
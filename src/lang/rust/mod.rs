@@ -0,0 +1,2051 @@
+//! Specialization for Rust code generation.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use genco::prelude::*;
+//!
+//! # fn main() -> genco::fmt::Result {
+//! let toks: rust::Tokens = quote! {
+//!     fn foo() -> u32 {
+//!         42
+//!     }
+//! };
+//!
+//! assert_eq!(
+//!     vec![
+//!         "fn foo() -> u32 {",
+//!         "    42",
+//!         "}",
+//!     ],
+//!     toks.to_file_vec()?
+//! );
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # String Quoting in Rust
+//!
+//! Rust uses UTF-8 internally, string quoting is with the exception of escape
+//! sequences a one-to-one translation.
+//!
+//! ```rust
+//! use genco::prelude::*;
+//!
+//! # fn main() -> genco::fmt::Result {
+//! let toks: rust::Tokens = quote!("start π 😊 \n \x7f ÿ $ end");
+//! assert_eq!("\"start π 😊 \\n \\x7f ÿ $ end\"", toks.to_string()?);
+//! # Ok(())
+//! # }
+
+mod attr_derive;
+mod bounds;
+mod cfg;
+mod closure;
+mod destructure;
+mod enum_decl;
+mod if_let;
+mod let_else;
+mod macro_call;
+mod macro_rules;
+mod raw_quoted;
+mod serde_attr;
+mod type_alias;
+mod while_let;
+
+pub use self::attr_derive::AttrDerive;
+pub use self::bounds::Bounds;
+pub use self::cfg::{Cfg, CfgAttr};
+pub use self::closure::Closure;
+pub use self::destructure::{Destructure, DestructureTuple};
+pub use self::enum_decl::{EnumDecl, Variant};
+pub use self::if_let::IfLet;
+pub use self::let_else::LetElse;
+pub use self::macro_call::{Delimiter, MacroCall};
+pub use self::macro_rules::MacroRules;
+pub use self::raw_quoted::RawQuoted;
+pub use self::serde_attr::SerdeAttr;
+pub use self::type_alias::TypeAlias;
+pub use self::while_let::WhileLet;
+
+use crate::fmt;
+use crate::tokens::{FormatInto, ItemStr};
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+use std::fmt::Write as _;
+
+const SEP: &str = "::";
+
+/// Tokens container specialization for Rust.
+pub type Tokens = crate::Tokens<Rust>;
+
+impl_lang! {
+    /// Language specialization for Rust.
+    pub Rust {
+        type Config = Config;
+        type Format = Format;
+        type Item = Import;
+
+        fn capabilities() -> crate::lang::Capabilities {
+            crate::lang::Capabilities {
+                raw_strings: true,
+                import_aliasing: true,
+                block_comments: true,
+                ..Default::default()
+            }
+        }
+
+        fn block_comment_delimiters() -> Option<(&'static str, &'static str)> {
+            Some(("/*", "*/"))
+        }
+
+        fn unit_type() -> Option<&'static str> {
+            Some("()")
+        }
+
+        fn write_quoted(out: &mut fmt::Formatter<'_>, input: &str) -> fmt::Result {
+            // From: https://doc.rust-lang.org/reference/tokens.html#literals
+
+            for c in input.chars() {
+                match c {
+                    // new line
+                    '\n' => out.write_str("\\n")?,
+                    // carriage return
+                    '\r' => out.write_str("\\r")?,
+                    // horizontal tab
+                    '\t' => out.write_str("\\t")?,
+                    // backslash
+                    '\\' => out.write_str("\\\\")?,
+                    // null
+                    '\0' => out.write_str("\\0")?,
+                    // Note: only relevant if we were to use single-quoted strings.
+                    // '\'' => out.write_str("\\'")?,
+                    '"' => out.write_str("\\\"")?,
+                    c if !c.is_control() => out.write_char(c)?,
+                    c if (c as u32) < 0x80 => {
+                        write!(out, "\\x{:02x}", c as u32)?;
+                    }
+                    c => {
+                        write!(out, "\\u{{{:04x}}}", c as u32)?;
+                    }
+                };
+            }
+
+            Ok(())
+        }
+
+        fn raw_string(out: &mut fmt::Formatter<'_>, input: &str) -> Option<fmt::Result> {
+            // Raw strings can't contain null bytes or bare carriage returns.
+            if input.contains(['\0', '\r']) {
+                return None;
+            }
+
+            // The closing delimiter needs one more `#` than the longest run
+            // of `#` following a quote in the input.
+            let hashes = if input.contains('"') {
+                input
+                    .match_indices('"')
+                    .map(|(n, _)| input[n + 1..].bytes().take_while(|b| *b == b'#').count())
+                    .max()
+                    .unwrap_or_default()
+                    + 1
+            } else {
+                0
+            };
+
+            let hashes = "#".repeat(hashes);
+            Some(write!(out, "r{hashes}\"{input}\"{hashes}").map_err(fmt::Error::from))
+        }
+
+        fn format_file(
+            tokens: &Tokens,
+            out: &mut fmt::Formatter<'_>,
+            config: &Self::Config,
+        ) -> fmt::Result {
+            let format = Format {
+                aliases: Self::aliases(config, tokens),
+            };
+
+            let mut imports: Tokens = Tokens::new();
+            Self::imports(&mut imports, config, &format, tokens, out.max_width());
+            imports.format(out, config, &format)?;
+
+            if config.space_in_double_angle {
+                Self::space_double_angles(tokens).format(out, config, &format)?;
+            } else {
+                tokens.format(out, config, &format)?;
+            }
+
+            Ok(())
+        }
+
+        fn format_imports(
+            tokens: &Tokens,
+            out: &mut fmt::Formatter<'_>,
+            config: &Self::Config,
+        ) -> fmt::Result {
+            let format = Format {
+                aliases: Self::aliases(config, tokens),
+            };
+
+            let mut imports = Tokens::new();
+            Self::imports(&mut imports, config, &format, tokens, out.max_width());
+            imports.format(out, config, &format)
+        }
+    }
+
+    Import {
+        fn format(&self, out: &mut fmt::Formatter<'_>, config: &Config, format: &Format) -> fmt::Result {
+            match &self.module {
+                Module::Module {
+                    import: Some(ImportMode::Direct),
+                    module,
+                } => {
+                    self.write_direct(out, format, module)?;
+                }
+                Module::Module {
+                    import: Some(ImportMode::Qualified),
+                    module,
+                } => {
+                    self.write_prefixed(out, config, module)?;
+                }
+                Module::Module {
+                    import: None,
+                    module,
+                } => match &config.default_import {
+                    ImportMode::Direct => self.write_direct(out, format, module)?,
+                    ImportMode::Qualified => self.write_prefixed(out, config, module)?,
+                },
+                Module::Aliased {
+                    alias: ref module, ..
+                } => {
+                    out.write_str(module)?;
+                    out.write_str(SEP)?;
+                    out.write_str(&self.name)?;
+                }
+            }
+
+            Ok(())
+        }
+    }
+}
+
+/// Format state for Rust.
+#[derive(Debug, Default)]
+pub struct Format {
+    /// Aliases assigned to directly imported names which would otherwise
+    /// collide, indexed by module and name.
+    aliases: BTreeMap<ItemStr, BTreeMap<ItemStr, ItemStr>>,
+}
+
+impl Format {
+    /// Get the alias assigned to the given import, if any.
+    fn alias(&self, module: &ItemStr, name: &ItemStr) -> Option<&ItemStr> {
+        self.aliases.get(module)?.get(name)
+    }
+}
+
+/// Language configuration for Rust.
+#[derive(Debug)]
+pub struct Config {
+    default_import: ImportMode,
+    edition: Edition,
+    space_in_double_angle: bool,
+}
+
+impl Config {
+    /// Configure the default import mode to use.
+    ///
+    /// See [Import] for more details.
+    pub fn with_default_import(self, default_import: ImportMode) -> Self {
+        Self {
+            default_import,
+            ..self
+        }
+    }
+
+    /// Configure the edition of Rust to generate code for.
+    ///
+    /// This defaults to [Edition::Rust2018].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    /// use genco::fmt;
+    ///
+    /// let map = rust::import("std::collections", "HashMap");
+    /// let ser = rust::import("serde", "Serialize");
+    ///
+    /// let toks: rust::Tokens = quote! {
+    ///     #[derive($ser)]
+    ///     struct Foo($map<u32, u32>);
+    /// };
+    ///
+    /// let mut w = fmt::VecWriter::new();
+    /// let fmt = fmt::Config::from_lang::<Rust>();
+    /// let config = rust::Config::default().with_edition(rust::Edition::Rust2015);
+    /// toks.format_file(&mut w.as_formatter(&fmt), &config)?;
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "extern crate serde;",
+    ///         "",
+    ///         "use serde::Serialize;",
+    ///         "use std::collections::HashMap;",
+    ///         "",
+    ///         "#[derive(Serialize)]",
+    ///         "struct Foo(HashMap<u32, u32>);",
+    ///     ],
+    ///     w.into_vec(),
+    /// );
+    ///
+    /// let mut w = fmt::VecWriter::new();
+    /// let config = rust::Config::default().with_edition(rust::Edition::Rust2018);
+    /// toks.format_file(&mut w.as_formatter(&fmt), &config)?;
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "use serde::Serialize;",
+    ///         "use std::collections::HashMap;",
+    ///         "",
+    ///         "#[derive(Serialize)]",
+    ///         "struct Foo(HashMap<u32, u32>);",
+    ///     ],
+    ///     w.into_vec(),
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn with_edition(self, edition: Edition) -> Self {
+        Self { edition, ..self }
+    }
+
+    /// Configure whether a space should be emitted between two consecutive
+    /// closing angle brackets of nested generics, like `Vec<Vec<u8> >`.
+    ///
+    /// This is only needed for legacy tools which parse `>>` as a shift
+    /// operator, and defaults to `false`.
+    ///
+    /// Angle brackets are recognized as generics if the opening bracket
+    /// directly follows a name or a turbofish (`::<`), so operators like
+    /// `a >> b` and `->` are left untouched. This is only applied when
+    /// formatting files, like with [Tokens::format_file].
+    ///
+    /// [Tokens::format_file]: crate::Tokens::format_file
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    /// use genco::fmt;
+    ///
+    /// let map = rust::import("std::collections", "HashMap");
+    ///
+    /// let toks: rust::Tokens = quote! {
+    ///     fn foo(a: u32) -> Vec<$map<u32, Vec<u8>>> {
+    ///         let b = a >> 2;
+    ///         Vec::<Vec<u8>>::new()
+    ///     }
+    /// };
+    ///
+    /// let mut w = fmt::VecWriter::new();
+    /// let fmt = fmt::Config::from_lang::<Rust>();
+    /// let config = rust::Config::default().with_space_in_double_angle(true);
+    /// toks.format_file(&mut w.as_formatter(&fmt), &config)?;
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "use std::collections::HashMap;",
+    ///         "",
+    ///         "fn foo(a: u32) -> Vec<HashMap<u32, Vec<u8> > > {",
+    ///         "    let b = a >> 2;",
+    ///         "    Vec::<Vec<u8> >::new()",
+    ///         "}",
+    ///     ],
+    ///     w.into_vec(),
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn with_space_in_double_angle(self, space_in_double_angle: bool) -> Self {
+        Self {
+            space_in_double_angle,
+            ..self
+        }
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            default_import: ImportMode::Direct,
+            edition: Edition::Rust2018,
+            space_in_double_angle: false,
+        }
+    }
+}
+
+/// The edition of Rust to generate code for.
+///
+/// Only editions which change how imports are written have a variant of
+/// their own, so code for the 2021 edition and later is generated with
+/// [Edition::Rust2018].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Edition {
+    /// The 2015 edition.
+    ///
+    /// External crates have to be declared with `extern crate`, which is
+    /// emitted for every crate which is imported from except for `std`,
+    /// including `core` and `alloc`. Paths outside of `use` declarations are
+    /// relative to the current module in this edition, so names which are
+    /// used qualified by a crate, like `serde::Serialize`, are written with a
+    /// leading `::`. Since `use` paths are relative to the crate root, `use`
+    /// declarations are otherwise written the same way as in later editions.
+    Rust2015,
+    /// The 2018 edition and later.
+    Rust2018,
+}
+
+/// The import mode to use when generating import statements.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ImportMode {
+    /// Import names without a module prefix.
+    ///
+    /// so for `std::fmt::Debug` it would import `std::fmt::Debug`, and use
+    /// `Debug`.
+    Direct,
+    /// Import qualified names with a module prefix.
+    ///
+    /// so for `std::fmt::Debug` it would import `std::fmt`, and use
+    /// `fmt::Debug`.
+    Qualified,
+}
+
+#[derive(Debug, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+enum Module {
+    /// Type imported directly from module with the specified mode.
+    Module {
+        import: Option<ImportMode>,
+        module: ItemStr,
+    },
+    /// Prefixed with an alias.
+    Aliased { module: ItemStr, alias: ItemStr },
+}
+
+impl Module {
+    /// Convert into an aliased import, or keep as same in case that's not
+    /// feasible.
+    fn into_module_aliased<A>(self, alias: A) -> Self
+    where
+        A: Into<ItemStr>,
+    {
+        match self {
+            Self::Module { module, .. } => Self::Aliased {
+                module,
+                alias: alias.into(),
+            },
+            other => other,
+        }
+    }
+
+    /// Aliasing a type explicitly means you no longer want to import it by
+    /// module. Set the correct import here.
+    fn into_aliased(self) -> Self {
+        match self {
+            Self::Module { module, .. } => Self::Module {
+                import: Some(ImportMode::Direct),
+                module,
+            },
+            other => other,
+        }
+    }
+
+    /// Switch to a direct import mode.
+    ///
+    /// See [ImportMode::Direct].
+    fn direct(self) -> Self {
+        match self {
+            Self::Module { module, .. } => Self::Module {
+                module,
+                import: Some(ImportMode::Direct),
+            },
+            other => other,
+        }
+    }
+
+    /// Switch into a qualified import mode.
+    ///
+    /// See [ImportMode::Qualified].
+    fn qualified(self) -> Self {
+        match self {
+            Self::Module { module, .. } => Self::Module {
+                module,
+                import: Some(ImportMode::Qualified),
+            },
+            other => other,
+        }
+    }
+}
+
+/// The import of a Rust type `use std::collections::HashMap`.
+///
+/// Created through the [import()] function.
+#[derive(Debug, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct Import {
+    /// How the type is imported.
+    module: Module,
+    /// Name of type.
+    name: ItemStr,
+    /// Alias to use for the type.
+    alias: Option<ItemStr>,
+}
+
+impl Import {
+    /// Alias the given type as it's imported.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    ///
+    /// let ty = rust::import("std::fmt", "Debug").with_alias("FmtDebug");
+    ///
+    /// let toks = quote!($ty);
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "use std::fmt::Debug as FmtDebug;",
+    ///         "",
+    ///         "FmtDebug",
+    ///     ],
+    ///     toks.to_file_vec()?
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn with_alias<A: Into<ItemStr>>(self, alias: A) -> Self {
+        Self {
+            module: self.module.into_aliased(),
+            alias: Some(alias.into()),
+            ..self
+        }
+    }
+
+    /// Alias the module being imported.
+    ///
+    /// This also implies that the import is [qualified()].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    ///
+    /// let ty = rust::import("std::fmt", "Debug").with_module_alias("other");
+    ///
+    /// let toks = quote!($ty);
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "use std::fmt as other;",
+    ///         "",
+    ///         "other::Debug",
+    ///     ],
+    ///     toks.to_file_vec()?
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    ///
+    /// [qualified()]: Self::qualified()
+    pub fn with_module_alias<A: Into<ItemStr>>(self, alias: A) -> Self {
+        Self {
+            module: self.module.into_module_aliased(alias),
+            ..self
+        }
+    }
+
+    /// Switch to a qualified import mode.
+    ///
+    /// See [ImportMode::Qualified].
+    ///
+    /// So importing `std::fmt::Debug` will cause the module to be referenced as
+    /// `fmt::Debug` instead of `Debug`.
+    ///
+    /// This is implied if [with_module_alias()][Self::with_module_alias()] is used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    ///
+    /// let ty = rust::import("std::fmt", "Debug").qualified();
+    ///
+    /// let toks = quote!($ty);
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "use std::fmt;",
+    ///         "",
+    ///         "fmt::Debug",
+    ///     ],
+    ///     toks.to_file_vec()?
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn qualified(self) -> Self {
+        Self {
+            module: self.module.qualified(),
+            ..self
+        }
+    }
+
+    /// Switch into a direct import mode.
+    ///
+    /// See [ImportMode::Direct].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    ///
+    /// let ty = rust::import("std::fmt", "Debug").direct();
+    ///
+    /// let toks = quote!($ty);
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "use std::fmt::Debug;",
+    ///         "",
+    ///         "Debug",
+    ///     ],
+    ///     toks.to_file_vec()?
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn direct(self) -> Self {
+        Self {
+            module: self.module.direct(),
+            ..self
+        }
+    }
+
+    /// Write the direct name of the type.
+    fn write_direct(
+        &self,
+        out: &mut fmt::Formatter<'_>,
+        format: &Format,
+        module: &ItemStr,
+    ) -> fmt::Result {
+        if let Some(alias) = &self.alias {
+            out.write_str(alias)?;
+        } else if let Some(alias) = format.alias(module, &self.name) {
+            out.write_str(alias)?;
+        } else {
+            out.write_str(&self.name)?;
+        }
+
+        Ok(())
+    }
+
+    /// Write the prefixed name of the type.
+    fn write_prefixed(
+        &self,
+        out: &mut fmt::Formatter<'_>,
+        config: &Config,
+        module: &ItemStr,
+    ) -> fmt::Result {
+        // Crates aren't imported, so they have to be referenced from the
+        // crate root in modules other than it.
+        if config.edition == Edition::Rust2015
+            && !module.contains(SEP)
+            && !matches!(module.as_ref(), "crate" | "self" | "super")
+        {
+            out.write_str(SEP)?;
+        }
+
+        if let Some(module) = module.rsplit(SEP).next() {
+            out.write_str(module)?;
+            out.write_str(SEP)?;
+        }
+
+        out.write_str(&self.name)?;
+        Ok(())
+    }
+}
+
+impl Rust {
+    /// Insert a space between consecutive closing angle brackets of nested
+    /// generics.
+    fn space_double_angles(tokens: &Tokens) -> Tokens {
+        use crate::tokens::Item;
+
+        let mut out = Tokens::new();
+        // Number of generic angle brackets currently open.
+        let mut depth = 0usize;
+        // The last character written to the output, if it's part of code.
+        let mut last = None::<char>;
+        // If the last character was a closing generic bracket.
+        let mut closed = false;
+        let mut in_quote = false;
+
+        for item in tokens.iter() {
+            let literal = match item {
+                Item::Literal(literal) if !in_quote => literal,
+                Item::OpenQuote(..) => {
+                    in_quote = true;
+                    out.extend(Some(item.clone()));
+                    continue;
+                }
+                Item::CloseQuote => {
+                    in_quote = false;
+                    last = Some('"');
+                    closed = false;
+                    out.extend(Some(item.clone()));
+                    continue;
+                }
+                Item::Lang(..) if !in_quote => {
+                    // Names are always valid before generic arguments.
+                    last = Some('_');
+                    closed = false;
+                    out.extend(Some(item.clone()));
+                    continue;
+                }
+                Item::Space | Item::SoftBreak | Item::Push | Item::Line | Item::BlankLine => {
+                    last = Some(' ');
+                    closed = false;
+                    out.extend(Some(item.clone()));
+                    continue;
+                }
+                _ => {
+                    out.extend(Some(item.clone()));
+                    continue;
+                }
+            };
+
+            let mut buf = String::new();
+            let mut it = literal.chars().peekable();
+
+            while let Some(c) = it.next() {
+                match c {
+                    '<' if last.map_or(false, |l| l == ':' || l == '_' || l.is_alphanumeric())
+                        && !matches!(it.peek(), Some('<' | '=')) =>
+                    {
+                        depth += 1;
+                        closed = false;
+                    }
+                    '>' if depth > 0 && !matches!(last, Some('-' | '=')) => {
+                        if closed {
+                            buf.push(' ');
+                        }
+
+                        depth -= 1;
+                        closed = true;
+                    }
+                    _ => {
+                        closed = false;
+                    }
+                }
+
+                buf.push(c);
+                last = Some(c);
+            }
+
+            if buf.len() == literal.len() {
+                out.extend(Some(item.clone()));
+            } else {
+                out.append(buf);
+            }
+        }
+
+        out
+    }
+
+    /// Assign aliases to directly imported names which would otherwise
+    /// collide with each other.
+    ///
+    /// Names with an explicit alias are never changed, and the aliases they
+    /// use are reserved up front. The remaining names are visited in module
+    /// order, where the first import of a name keeps it and the following
+    /// ones are aliased by prefixing them with the last segment of their
+    /// module, like `IoResult` for `std::io::Result`.
+    fn aliases(config: &Config, tokens: &Tokens) -> BTreeMap<ItemStr, BTreeMap<ItemStr, ItemStr>> {
+        let mut taken = BTreeSet::<String>::new();
+        let mut names = BTreeSet::<(&ItemStr, &ItemStr)>::new();
+
+        for import in tokens.walk_imports() {
+            let module = match &import.module {
+                Module::Module {
+                    module,
+                    import: Some(ImportMode::Direct),
+                } => module,
+                Module::Module {
+                    module,
+                    import: None,
+                } if config.default_import == ImportMode::Direct => module,
+                _ => continue,
+            };
+
+            match &import.alias {
+                Some(alias) => {
+                    taken.insert(alias.to_string());
+                }
+                None => {
+                    names.insert((module, &import.name));
+                }
+            }
+        }
+
+        let mut aliases = BTreeMap::<ItemStr, BTreeMap<ItemStr, ItemStr>>::new();
+
+        for (module, name) in names {
+            if taken.insert(name.to_string()) {
+                continue;
+            }
+
+            let prefix = module.rsplit(SEP).next().unwrap_or_default();
+
+            let base = if name.starts_with(char::is_lowercase) {
+                format!("{}_{}", prefix, name)
+            } else {
+                let mut base = String::new();
+
+                for part in prefix.split('_') {
+                    let mut chars = part.chars();
+
+                    if let Some(c) = chars.next() {
+                        base.extend(c.to_uppercase());
+                        base.push_str(chars.as_str());
+                    }
+                }
+
+                base.push_str(name);
+                base
+            };
+
+            let mut alias = base.clone();
+            let mut n = 2;
+
+            while !taken.insert(alias.clone()) {
+                alias = format!("{}{}", base, n);
+                n += 1;
+            }
+
+            aliases
+                .entry(module.clone())
+                .or_default()
+                .insert(name.clone(), ItemStr::from(alias));
+        }
+
+        aliases
+    }
+
+    fn imports(
+        out: &mut Tokens,
+        config: &Config,
+        format: &Format,
+        tokens: &Tokens,
+        max_width: Option<usize>,
+    ) {
+        use crate as genco;
+        use crate::quote_in;
+        use std::collections::btree_set;
+
+        let mut modules = BTreeMap::<&ItemStr, Import>::new();
+
+        let mut queue = VecDeque::new();
+
+        for import in tokens.walk_imports() {
+            queue.push_back(import);
+        }
+
+        while let Some(import) = queue.pop_front() {
+            match &import.module {
+                Module::Module {
+                    module: m,
+                    import: Some(ImportMode::Direct),
+                } => {
+                    let alias = import
+                        .alias
+                        .as_ref()
+                        .or_else(|| format.alias(m, &import.name));
+                    let module = modules.entry(m).or_default();
+                    module.names.insert((&import.name, alias));
+                }
+                Module::Module {
+                    module,
+                    import: Some(ImportMode::Qualified),
+                } => {
+                    let module = modules.entry(module).or_default();
+                    module.self_import = true;
+                }
+                Module::Module {
+                    module: m,
+                    import: None,
+                } => match config.default_import {
+                    ImportMode::Direct => {
+                        let alias = import
+                            .alias
+                            .as_ref()
+                            .or_else(|| format.alias(m, &import.name));
+                        let module = modules.entry(m).or_default();
+                        module.names.insert((&import.name, alias));
+                    }
+                    ImportMode::Qualified => {
+                        let module = modules.entry(m).or_default();
+                        module.self_import = true;
+                    }
+                },
+                Module::Aliased { module, alias } => {
+                    let module = modules.entry(module).or_default();
+                    module.self_aliases.insert(alias);
+                }
+            }
+        }
+
+        let mut has_any = false;
+
+        if config.edition == Edition::Rust2015 {
+            let crates = modules
+                .keys()
+                .filter_map(|m| m.split(SEP).next())
+                .filter(|c| !matches!(*c, "std" | "crate" | "self" | "super" | ""))
+                .collect::<BTreeSet<_>>();
+
+            for c in &crates {
+                out.push();
+                quote_in!(*out => extern crate $(*c););
+            }
+
+            if !crates.is_empty() {
+                out.line();
+            }
+        }
+
+        for (m, module) in modules {
+            let mut render = module.iter(m);
+
+            if let Some(first) = render.next() {
+                has_any = true;
+                out.push();
+
+                // render as a group if there's more than one thing being
+                // imported.
+                if let Some(second) = render.next() {
+                    let mut items = vec![first, second];
+                    items.extend(render);
+
+                    // Length of the group if rendered on a single line, like
+                    // `use {m}::{{{items}}};`.
+                    let width = "use ::{};".len()
+                        + m.chars().count()
+                        + items.iter().map(|item| item.width()).sum::<usize>()
+                        + (items.len() - 1) * ", ".len();
+
+                    if max_width.map_or(false, |max_width| width > max_width) {
+                        quote_in! { *out =>
+                            use $m::{
+                                $(for item in items join (,$['\r']) => $(ref o => item.render(o))),
+                            };
+                        };
+                    } else {
+                        quote_in! { *out =>
+                            use $m::{$(for item in items join (, ) => $(ref o => item.render(o)))};
+                        };
+                    }
+                } else {
+                    match first {
+                        RenderItem::SelfImport => {
+                            quote_in!(*out => use $m;);
+                        }
+                        RenderItem::SelfAlias { alias } => {
+                            quote_in!(*out => use $m as $alias;);
+                        }
+                        RenderItem::Name {
+                            name,
+                            alias: Some(alias),
+                        } => {
+                            quote_in!(*out => use $m::$name as $alias;);
+                        }
+                        RenderItem::Name { name, alias: None } => {
+                            quote_in!(*out => use $m::$name;);
+                        }
+                    }
+                }
+            }
+        }
+
+        if has_any {
+            out.line();
+        }
+
+        return;
+
+        /// An imported module.
+        #[derive(Debug, Default)]
+        struct Import<'a> {
+            /// If we need the module (e.g. through an alias).
+            self_import: bool,
+            /// Aliases for the own module.
+            self_aliases: BTreeSet<&'a ItemStr>,
+            /// Set of imported names.
+            names: BTreeSet<(&'a ItemStr, Option<&'a ItemStr>)>,
+        }
+
+        impl<'a> Import<'a> {
+            fn iter(self, module: &'a str) -> ImportedIter<'a> {
+                ImportedIter {
+                    module,
+                    self_import: self.self_import,
+                    self_aliases: self.self_aliases.into_iter(),
+                    names: self.names.into_iter(),
+                }
+            }
+        }
+
+        struct ImportedIter<'a> {
+            module: &'a str,
+            self_import: bool,
+            self_aliases: btree_set::IntoIter<&'a ItemStr>,
+            names: btree_set::IntoIter<(&'a ItemStr, Option<&'a ItemStr>)>,
+        }
+
+        impl<'a> Iterator for ImportedIter<'a> {
+            type Item = RenderItem<'a>;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                if std::mem::take(&mut self.self_import) {
+                    // Only render self-import if it's not a top level module.
+                    if self.module.split(SEP).count() > 1 {
+                        return Some(RenderItem::SelfImport);
+                    }
+                }
+
+                if let Some(alias) = self.self_aliases.next() {
+                    return Some(RenderItem::SelfAlias { alias });
+                }
+
+                if let Some((name, alias)) = self.names.next() {
+                    return Some(RenderItem::Name { name, alias });
+                }
+
+                None
+            }
+        }
+
+        #[derive(Clone, Copy)]
+        enum RenderItem<'a> {
+            SelfImport,
+            SelfAlias {
+                alias: &'a ItemStr,
+            },
+            Name {
+                name: &'a ItemStr,
+                alias: Option<&'a ItemStr>,
+            },
+        }
+
+        impl RenderItem<'_> {
+            /// The width of the item when rendered.
+            fn width(&self) -> usize {
+                match *self {
+                    Self::SelfImport => "self".len(),
+                    Self::SelfAlias { alias } => "self as ".len() + alias.chars().count(),
+                    Self::Name {
+                        name,
+                        alias: Some(alias),
+                    } => name.chars().count() + " as ".len() + alias.chars().count(),
+                    Self::Name { name, alias: None } => name.chars().count(),
+                }
+            }
+
+            fn render(self, out: &mut Tokens) {
+                match self {
+                    Self::SelfImport => {
+                        quote_in!(*out => self);
+                    }
+                    Self::SelfAlias { alias } => {
+                        quote_in!(*out => self as $alias);
+                    }
+                    Self::Name {
+                        name,
+                        alias: Some(alias),
+                    } => {
+                        quote_in!(*out => $name as $alias);
+                    }
+                    Self::Name { name, alias: None } => {
+                        quote_in!(*out => $name);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// The import of a Rust type `use std::collections::HashMap`.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let a = rust::import("std::fmt", "Debug").qualified();
+/// let b = rust::import("std::fmt", "Debug").with_module_alias("fmt2");
+/// let c = rust::import("std::fmt", "Debug");
+/// let d = rust::import("std::fmt", "Debug").with_alias("FmtDebug");
+///
+/// let toks = quote!{
+///     $a
+///     $b
+///     $c
+///     $d
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "use std::fmt::{self, self as fmt2, Debug, Debug as FmtDebug};",
+///         "",
+///         "fmt::Debug",
+///         "fmt2::Debug",
+///         "Debug",
+///         "FmtDebug",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+///
+/// # Example with an alias
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let ty = rust::import("std::fmt", "Debug").with_alias("FmtDebug");
+///
+/// let toks = quote!{
+///     $ty
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "use std::fmt::Debug as FmtDebug;",
+///         "",
+///         "FmtDebug",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+///
+/// # Example with a module alias
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let ty = rust::import("std::fmt", "Debug").with_module_alias("fmt2");
+///
+/// let toks = quote!{
+///     $ty
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "use std::fmt as fmt2;",
+///         "",
+///         "fmt2::Debug",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+///
+/// # Example with multiple aliases
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let a = rust::import("std::fmt", "Debug").with_alias("FmtDebug");
+/// let b = rust::import("std::fmt", "Debug").with_alias("FmtDebug2");
+///
+/// let toks = quote!{
+///     $a
+///     $b
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "use std::fmt::{Debug as FmtDebug, Debug as FmtDebug2};",
+///         "",
+///         "FmtDebug",
+///         "FmtDebug2",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+///
+/// # Example with colliding names
+///
+/// Directly imported names which would collide with each other are aliased
+/// automatically when formatting a file. The first module in sorted order
+/// keeps the name, and the others are prefixed with the last segment of
+/// their module.
+///
+/// Explicit aliases always take precedence. They are used as-is, even if
+/// they collide with each other, and an automatic alias never reuses a name
+/// which has been picked explicitly. So below `std::io::Error` can't be
+/// aliased to the already taken `IoError`, and receives a numeric suffix
+/// instead.
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let fmt_result = rust::import("std::fmt", "Result");
+/// let io_result = rust::import("std::io", "Result");
+/// let fmt_error = rust::import("std::fmt", "Error");
+/// let io_error = rust::import("std::io", "Error");
+/// let my_error = rust::import("crate::error", "Error").with_alias("IoError");
+///
+/// let toks = quote! {
+///     $fmt_result $io_result
+///     $fmt_error $io_error $my_error
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "use crate::error::Error as IoError;",
+///         "use std::fmt::{Error, Result};",
+///         "use std::io::{Error as IoError2, Result as IoResult};",
+///         "",
+///         "Result IoResult",
+///         "Error IoError2 IoError",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn import<M, N>(module: M, name: N) -> Import
+where
+    M: Into<ItemStr>,
+    N: Into<ItemStr>,
+{
+    Import {
+        module: Module::Module {
+            import: None,
+            module: module.into(),
+        },
+        name: name.into(),
+        alias: None,
+    }
+}
+
+/// Construct a `#[serde(rename = "...")]` attribute.
+///
+/// Serde attributes can be merged into a single attribute using
+/// [SerdeAttr::and].
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let toks = quote! {
+///     #[derive(Deserialize)]
+///     struct Foo {
+///         $(rust::serde_rename("type"))
+///         ty: String,
+///         $(rust::serde_default().and(rust::serde_skip_if("Option::is_none")))
+///         value: Option<u32>,
+///     }
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "#[derive(Deserialize)]",
+///         "struct Foo {",
+///         "    #[serde(rename = \"type\")]",
+///         "    ty: String,",
+///         "    #[serde(default, skip_serializing_if = \"Option::is_none\")]",
+///         "    value: Option<u32>,",
+///         "}",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn serde_rename<N>(name: N) -> SerdeAttr
+where
+    N: Into<ItemStr>,
+{
+    SerdeAttr::rename(name.into())
+}
+
+/// Construct a `#[serde(default)]` attribute.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let toks: rust::Tokens = quote!($(rust::serde_default()));
+/// assert_eq!("#[serde(default)]", toks.to_string()?);
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn serde_default() -> SerdeAttr {
+    SerdeAttr::default()
+}
+
+/// Construct a `#[serde(skip_serializing_if = "...")]` attribute, where the
+/// argument is the path to the predicate function.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let toks: rust::Tokens = quote!($(rust::serde_skip_if("Vec::is_empty")));
+/// assert_eq!("#[serde(skip_serializing_if = \"Vec::is_empty\")]", toks.to_string()?);
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn serde_skip_if<P>(path: P) -> SerdeAttr
+where
+    P: Into<ItemStr>,
+{
+    SerdeAttr::skip_serializing_if(path.into())
+}
+
+/// Construct a `#[serde(skip)]` attribute.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let toks: rust::Tokens = quote!($(rust::serde_skip()));
+/// assert_eq!("#[serde(skip)]", toks.to_string()?);
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn serde_skip() -> SerdeAttr {
+    SerdeAttr::skip()
+}
+
+/// Construct a `#[serde(flatten)]` attribute.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let toks: rust::Tokens = quote!($(rust::serde_flatten()));
+/// assert_eq!("#[serde(flatten)]", toks.to_string()?);
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn serde_flatten() -> SerdeAttr {
+    SerdeAttr::flatten()
+}
+
+/// Construct a `macro_rules!` definition, where each rule is a tuple of a
+/// matcher and its expansion.
+///
+/// Since `$` is used for interpolation in [quote!], metavariables in matchers
+/// and expansions have to be escaped as `$$`.
+///
+/// [quote!]: crate::quote!
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let rules = vec![
+///     (quote!(), quote!(0)),
+///     (quote!($$head:expr $$(, $$tail:expr)*), quote!($$head + count!($$($$tail),*))),
+/// ];
+///
+/// let toks = quote! {
+///     $(rust::macro_rules("count", rules))
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "macro_rules! count {",
+///         "    () => {",
+///         "        0",
+///         "    };",
+///         "    ($head:expr $(, $tail:expr)*) => {",
+///         "        $head + count!($($tail),*)",
+///         "    };",
+///         "}",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn macro_rules<N, I>(name: N, rules: I) -> MacroRules<I>
+where
+    N: Into<ItemStr>,
+{
+    MacroRules {
+        name: name.into(),
+        rules,
+    }
+}
+
+/// Construct a macro invocation, like `vec![..]`, `println!(..)` or
+/// `thread_local! { .. }` depending on the given [Delimiter].
+///
+/// The path can be an imported macro, in which case it's imported as usual.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+/// use genco::lang::rust::Delimiter;
+///
+/// let info = rust::import("log", "info");
+/// let lazy = rust::import("once_cell", "sync_lazy");
+///
+/// let toks: rust::Tokens = quote! {
+///     let v = $(rust::macro_call("vec", Delimiter::Bracket, quote!(1, 2, 3)));
+///     $(rust::macro_call(info, Delimiter::Paren, quote!($(quoted("{}")), v.len())));
+///
+///     $(rust::macro_call("thread_local", Delimiter::Brace, quote! {
+///         static COUNT: Cell<u32> = Cell::new(0);
+///     }))
+///
+///     $(rust::macro_call(lazy, Delimiter::Paren, ""));
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "use log::info;",
+///         "use once_cell::sync_lazy;",
+///         "",
+///         "let v = vec![1, 2, 3];",
+///         "info!(\"{}\", v.len());",
+///         "",
+///         "thread_local! {",
+///         "    static COUNT: Cell<u32> = Cell::new(0);",
+///         "}",
+///         "",
+///         "sync_lazy!();",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn macro_call<P, B>(path: P, delimiter: Delimiter, body: B) -> MacroCall<P, B>
+where
+    P: FormatInto<Rust>,
+    B: FormatInto<Rust>,
+{
+    MacroCall {
+        path,
+        delimiter,
+        body,
+    }
+}
+
+/// Construct an `if let` expression, with an optional `else` branch.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let toks: rust::Tokens = quote! {
+///     $(rust::if_let(quote!(Some(value)), "input", quote!(value), Some(quote!(0))))
+///     $(rust::if_let(quote!(Err(e)), "result", quote!(return Err(e);), None::<rust::Tokens>))
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "if let Some(value) = input {",
+///         "    value",
+///         "} else {",
+///         "    0",
+///         "}",
+///         "if let Err(e) = result {",
+///         "    return Err(e);",
+///         "}",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn if_let<P, X, B, E>(pattern: P, expr: X, body: B, else_body: Option<E>) -> IfLet<P, X, B, E>
+where
+    P: FormatInto<Rust>,
+    X: FormatInto<Rust>,
+    B: FormatInto<Rust>,
+    E: FormatInto<Rust>,
+{
+    IfLet {
+        pattern,
+        expr,
+        body,
+        else_body,
+    }
+}
+
+/// Construct a `while let` loop.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let toks: rust::Tokens = quote! {
+///     $(rust::while_let(quote!(Some(value)), "stack.pop()", quote!(println!("{}", value);)))
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "while let Some(value) = stack.pop() {",
+///         "    println!(\"{}\", value);",
+///         "}",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn while_let<P, X, B>(pattern: P, expr: X, body: B) -> WhileLet<P, X, B>
+where
+    P: FormatInto<Rust>,
+    X: FormatInto<Rust>,
+    B: FormatInto<Rust>,
+{
+    WhileLet {
+        pattern,
+        expr,
+        body,
+    }
+}
+
+/// Construct a `let ... else` statement, including its trailing semicolon.
+///
+/// The diverging body must not fall through, like by using `return`,
+/// `break` or `panic!`.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let toks: rust::Tokens = quote! {
+///     fn first(values: &[u32]) -> u32 {
+///         $(rust::let_else(quote!([first, ..]), "values", quote!(return 0;)))
+///         *first
+///     }
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "fn first(values: &[u32]) -> u32 {",
+///         "    let [first, ..] = values else {",
+///         "        return 0;",
+///         "    };",
+///         "    *first",
+///         "}",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn let_else<P, X, D>(pattern: P, expr: X, diverging_body: D) -> LetElse<P, X, D>
+where
+    P: FormatInto<Rust>,
+    X: FormatInto<Rust>,
+    D: FormatInto<Rust>,
+{
+    LetElse {
+        pattern,
+        expr,
+        diverging_body,
+    }
+}
+
+/// Construct an enum declaration, with an optional `#[repr(...)]` attribute
+/// and explicit variant discriminants.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let decl = rust::enum_decl("Color")
+///     .repr("u8")
+///     .variant(rust::variant("Red").discriminant(1))
+///     .variant(rust::variant("Green").discriminant(quote!(1 << 1)))
+///     .variant(rust::variant("Blue"));
+///
+/// let toks = quote!($decl);
+///
+/// assert_eq!(
+///     vec![
+///         "#[repr(u8)]",
+///         "enum Color {",
+///         "    Red = 1,",
+///         "    Green = 1 << 1,",
+///         "    Blue,",
+///         "}",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn enum_decl<N>(name: N) -> EnumDecl
+where
+    N: Into<ItemStr>,
+{
+    EnumDecl::new(name.into())
+}
+
+/// Construct a variant for an [enum_decl()].
+pub fn variant<N>(name: N) -> Variant
+where
+    N: Into<ItemStr>,
+{
+    Variant::new(name.into())
+}
+
+/// Construct a closure expression from its parameters and body.
+///
+/// Each parameter is formatted as-is, so it can optionally include a type
+/// annotation like `x: u32`. Types used in parameters and the return type
+/// are imported like any other interpolated item.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let map = rust::import("std::collections", "HashMap");
+///
+/// let callback = rust::closure([quote!(m: &$(&map)<u32, u32>), quote!(key)], quote!(m[&key] + 1))
+///     .returns("u32")
+///     .move_(true);
+///
+/// let toks = quote! {
+///     let f = $callback;
+///     let g = values.map($(rust::closure(["x"], quote!(x * 2))));
+///     let h = $(rust::closure(Vec::<&str>::new(), quote!($map::new())));
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "use std::collections::HashMap;",
+///         "",
+///         "let f = move |m: &HashMap<u32, u32>, key| -> u32 {",
+///         "    m[&key] + 1",
+///         "};",
+///         "let g = values.map(|x| {",
+///         "    x * 2",
+///         "});",
+///         "let h = || {",
+///         "    HashMap::new()",
+///         "};",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn closure<I, B>(params: I, body: B) -> Closure
+where
+    I: IntoIterator,
+    I::Item: FormatInto<Rust>,
+    B: FormatInto<Rust>,
+{
+    let params = params
+        .into_iter()
+        .map(|p| {
+            let mut param = Tokens::new();
+            param.append(p);
+            param
+        })
+        .collect();
+
+    let mut tokens = Tokens::new();
+    tokens.append(body);
+    Closure::new(params, tokens)
+}
+
+/// Construct a destructuring `let` binding of a struct, like
+/// `let Point { x, y, .. } = point;`.
+///
+/// Fields are added with [Destructure::field], and the remaining fields can
+/// be ignored with [Destructure::rest].
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let point = rust::import("crate::geom", "Point");
+///
+/// let toks: rust::Tokens = quote! {
+///     $(rust::destructure(&point, "p").field("x").field("y: height").rest(true))
+///     $(rust::destructure(&point, "q").rest(true))
+///     $(rust::destructure(quote!(Empty), "e"))
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "use crate::geom::Point;",
+///         "",
+///         "let Point { x, y: height, .. } = p;",
+///         "let Point { .. } = q;",
+///         "let Empty {} = e;",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn destructure<P, X>(path: P, expr: X) -> Destructure
+where
+    P: FormatInto<Rust>,
+    X: FormatInto<Rust>,
+{
+    let mut p = Tokens::new();
+    p.append(path);
+    let mut x = Tokens::new();
+    x.append(expr);
+    Destructure::new(p, x)
+}
+
+/// Construct a destructuring `let` binding of a tuple, like
+/// `let (a, b) = pair;`.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let toks: rust::Tokens = quote! {
+///     $(rust::destructure_tuple(["key", "value"], "entry"))
+///     $(rust::destructure_tuple(["only"], "single"))
+///     $(rust::destructure_tuple([quote!(mut a), quote!(_)], "pair"))
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "let (key, value) = entry;",
+///         "let (only,) = single;",
+///         "let (mut a, _) = pair;",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn destructure_tuple<I, X>(bindings: I, expr: X) -> DestructureTuple
+where
+    I: IntoIterator,
+    I::Item: FormatInto<Rust>,
+    X: FormatInto<Rust>,
+{
+    let bindings = bindings
+        .into_iter()
+        .map(|b| {
+            let mut t = Tokens::new();
+            t.append(b);
+            t
+        })
+        .collect();
+
+    let mut x = Tokens::new();
+    x.append(expr);
+    DestructureTuple::new(bindings, x)
+}
+
+/// Construct a type alias, like `type Name<T> = Target<T>;`.
+///
+/// Generic parameters are rendered in `<...>` after the name, and are left
+/// out if there are none. The target contributes imports as usual. The
+/// visibility can be set with [TypeAlias::visibility].
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let hash_map = rust::import("std::collections", "HashMap");
+/// let error = rust::import("crate::error", "Error");
+///
+/// let toks: rust::Tokens = quote! {
+///     $(rust::type_alias("Map", ["V"], quote!($hash_map<String, V>)).visibility("pub"))
+///     $(rust::type_alias("Result", [quote!(T), quote!(E = $error)], quote!(std::result::Result<T, E>)))
+///     $(rust::type_alias("Id", None::<&str>, "u64").visibility("pub(crate)"))
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "use crate::error::Error;",
+///         "use std::collections::HashMap;",
+///         "",
+///         "pub type Map<V> = HashMap<String, V>;",
+///         "type Result<T, E = Error> = std::result::Result<T, E>;",
+///         "pub(crate) type Id = u64;",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn type_alias<N, I, T>(name: N, generics: I, target: T) -> TypeAlias
+where
+    N: Into<ItemStr>,
+    I: IntoIterator,
+    I::Item: FormatInto<Rust>,
+    T: FormatInto<Rust>,
+{
+    let generics = generics
+        .into_iter()
+        .map(|g| {
+            let mut generic = Tokens::new();
+            generic.append(g);
+            generic
+        })
+        .collect();
+
+    let mut tokens = Tokens::new();
+    tokens.append(target);
+    TypeAlias::new(name.into(), generics, tokens)
+}
+
+/// A raw string literal in Rust, like `r"C:\Windows"` or `r#"say "hi""#`.
+///
+/// The fewest number of `#` needed for the closing delimiter to be
+/// unambiguous are used. Escape sequences are not processed in raw strings,
+/// which makes them convenient for paths and regular expressions. If the
+/// content can't be represented as a raw string because it contains null
+/// bytes or carriage returns, this falls back to a regular quoted string.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let toks: rust::Tokens = quote! {
+///     let path = $(rust::raw_quoted("C:\\Windows"));
+///     let re = $(rust::raw_quoted("^\"\\d+\"$"));
+///     let hash = $(rust::raw_quoted("a \"#b\"# c"));
+///     let null = $(rust::raw_quoted("a\0b"));
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "let path = r\"C:\\Windows\";",
+///         "let re = r#\"^\"\\d+\"$\"#;",
+///         "let hash = r##\"a \"#b\"# c\"##;",
+///         "let null = \"a\\0b\";",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn raw_quoted<S>(value: S) -> RawQuoted
+where
+    S: Into<ItemStr>,
+{
+    RawQuoted::new(value.into())
+}
+
+/// Construct trait and lifetime bounds, like `Clone + Send + 'static`.
+///
+/// The bounds are joined by ` + `, and nothing is rendered if there are
+/// none. Imported traits are imported as usual. Use [Bounds::is_empty] to
+/// decide if the `:` which precedes the bounds should be left out.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let debug = rust::import("std::fmt", "Debug");
+/// let hash = rust::import("std::hash", "Hash");
+///
+/// let bounds = rust::bounds([quote!(Clone), quote!($debug), quote!($hash), quote!('static)]);
+/// let none = rust::bounds(Vec::<rust::Tokens>::new());
+///
+/// let toks: rust::Tokens = quote! {
+///     fn foo<T: $bounds>(value: T) {}
+///     fn bar<T>(value: T) where T: $(rust::bounds(["Send", "Sync"])) {}
+///     fn baz<$(if none.is_empty() { T } else { T: $none })>() {}
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "use std::fmt::Debug;",
+///         "use std::hash::Hash;",
+///         "",
+///         "fn foo<T: Clone + Debug + Hash + 'static>(value: T) {}",
+///         "fn bar<T>(value: T) where T: Send + Sync {}",
+///         "fn baz<T>() {}",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn bounds<I>(bounds: I) -> Bounds
+where
+    I: IntoIterator,
+    I::Item: FormatInto<Rust>,
+{
+    let bounds = bounds
+        .into_iter()
+        .map(|b| {
+            let mut bound = Tokens::new();
+            bound.append(b);
+            bound
+        })
+        .collect();
+
+    Bounds::new(bounds)
+}
+
+/// Construct a derive attribute, like `#[derive(Debug, Serialize)]`.
+///
+/// Derive macros from the prelude are given by name, while imported derive
+/// macros like `serde::Serialize` are imported as usual and referenced by
+/// their imported name. Nothing is rendered if there are no derives.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let serialize = rust::import("serde", "Serialize");
+/// let deserialize = rust::import("serde", "Deserialize");
+///
+/// let derive = rust::attr_derive([
+///     quote!(Debug),
+///     quote!(Clone),
+///     quote!($serialize),
+///     quote!($deserialize),
+/// ]);
+///
+/// let toks: rust::Tokens = quote! {
+///     $derive
+///     struct Config {}
+///
+///     $(rust::attr_derive(["PartialEq", "Eq"]))
+///     struct Id(u32);
+///
+///     $(rust::attr_derive(Vec::<rust::Tokens>::new()))
+///     struct Plain;
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "use serde::{Deserialize, Serialize};",
+///         "",
+///         "#[derive(Debug, Clone, Serialize, Deserialize)]",
+///         "struct Config {}",
+///         "",
+///         "#[derive(PartialEq, Eq)]",
+///         "struct Id(u32);",
+///         "",
+///         "struct Plain;",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn attr_derive<I>(derives: I) -> AttrDerive
+where
+    I: IntoIterator,
+    I::Item: FormatInto<Rust>,
+{
+    let derives = derives
+        .into_iter()
+        .map(|d| {
+            let mut derive = Tokens::new();
+            derive.append(d);
+            derive
+        })
+        .collect();
+
+    AttrDerive::new(derives)
+}
+
+/// Construct a conditional compilation attribute, like
+/// `#[cfg(feature = "serde")]`.
+///
+/// The predicate is written as it's given, so a string like
+/// `"feature = \"serde\""` can be used as well as tokens where string
+/// literals are quoted. Use [Cfg::wrap] to put the attribute above an item.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let serialize = rust::import("serde", "Serialize");
+///
+/// let toks: rust::Tokens = quote! {
+///     $(rust::cfg(quote!(feature = "serde")))
+///     impl $serialize for Config {}
+///
+///     $(rust::cfg("any(unix, windows)").wrap(quote!(fn native() {})))
+///
+///     $(rust::cfg_attr(quote!(feature = "serde"), "derive(Deserialize)"))
+///     struct Config;
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "use serde::Serialize;",
+///         "",
+///         "#[cfg(feature = \"serde\")]",
+///         "impl Serialize for Config {}",
+///         "",
+///         "#[cfg(any(unix, windows))]",
+///         "fn native() {}",
+///         "",
+///         "#[cfg_attr(feature = \"serde\", derive(Deserialize))]",
+///         "struct Config;",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn cfg<P>(predicate: P) -> Cfg
+where
+    P: FormatInto<Rust>,
+{
+    let mut tokens = Tokens::new();
+    tokens.append(predicate);
+    Cfg::new(tokens)
+}
+
+/// Construct an attribute which is only applied if the predicate holds, like
+/// `#[cfg_attr(test, derive(Debug))]`.
+///
+/// The attribute is given without the surrounding `#[...]`.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let attr = rust::cfg_attr("docsrs", quote!(doc(cfg(feature = "std"))));
+///
+/// let toks: rust::Tokens = quote! {
+///     $attr
+///     pub mod io {}
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "#[cfg_attr(docsrs, doc(cfg(feature = \"std\")))]",
+///         "pub mod io {}",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn cfg_attr<P, A>(predicate: P, attr: A) -> CfgAttr
+where
+    P: FormatInto<Rust>,
+    A: FormatInto<Rust>,
+{
+    let mut p = Tokens::new();
+    p.append(predicate);
+    let mut a = Tokens::new();
+    a.append(attr);
+    CfgAttr::new(p, a)
+}
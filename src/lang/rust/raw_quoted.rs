@@ -0,0 +1,23 @@
+use crate::lang::Rust;
+use crate::tokens::{raw_quoted, FormatInto, ItemStr};
+use crate::Tokens;
+
+/// A raw string literal, like `r#"say "hi""#`.
+///
+/// This struct is created by the [raw_quoted][super::raw_quoted()] function.
+#[derive(Debug, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct RawQuoted {
+    value: ItemStr,
+}
+
+impl RawQuoted {
+    pub(super) fn new(value: ItemStr) -> Self {
+        Self { value }
+    }
+}
+
+impl FormatInto<Rust> for RawQuoted {
+    fn format_into(self, tokens: &mut Tokens<Rust>) {
+        tokens.append(raw_quoted(self.value));
+    }
+}
@@ -0,0 +1,107 @@
+use crate as genco;
+use crate::lang::Rust;
+use crate::quote_in;
+use crate::tokens::{quoted, FormatInto, ItemStr};
+use crate::Tokens;
+
+/// A single option inside of a `#[serde(...)]` attribute.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+enum SerdeOption {
+    /// `rename = "..."`.
+    Rename(ItemStr),
+    /// `default`.
+    Default,
+    /// `skip_serializing_if = "..."`.
+    SkipSerializingIf(ItemStr),
+    /// `skip`.
+    Skip,
+    /// `flatten`.
+    Flatten,
+}
+
+/// A `#[serde(...)]` attribute.
+///
+/// This is created by functions like [serde_rename][super::serde_rename()] and
+/// [serde_default][super::serde_default()]. Multiple attributes can be merged
+/// into one with [SerdeAttr::and].
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SerdeAttr {
+    options: Vec<SerdeOption>,
+}
+
+impl SerdeAttr {
+    fn new(option: SerdeOption) -> Self {
+        Self {
+            options: vec![option],
+        }
+    }
+
+    pub(super) fn rename(name: ItemStr) -> Self {
+        Self::new(SerdeOption::Rename(name))
+    }
+
+    pub(super) fn default() -> Self {
+        Self::new(SerdeOption::Default)
+    }
+
+    pub(super) fn skip_serializing_if(path: ItemStr) -> Self {
+        Self::new(SerdeOption::SkipSerializingIf(path))
+    }
+
+    pub(super) fn skip() -> Self {
+        Self::new(SerdeOption::Skip)
+    }
+
+    pub(super) fn flatten() -> Self {
+        Self::new(SerdeOption::Flatten)
+    }
+
+    /// Merge the options of another serde attribute into this one, so that
+    /// they are emitted as a single `#[serde(...)]` attribute.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    ///
+    /// let attr = rust::serde_rename("type").and(rust::serde_default());
+    ///
+    /// let toks = quote! {
+    ///     $attr
+    ///     ty: String,
+    /// };
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "#[serde(rename = \"type\", default)]",
+    ///         "ty: String,",
+    ///     ],
+    ///     toks.to_file_vec()?
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn and(mut self, other: SerdeAttr) -> Self {
+        self.options.extend(other.options);
+        self
+    }
+}
+
+impl FormatInto<Rust> for SerdeAttr {
+    fn format_into(self, tokens: &mut Tokens<Rust>) {
+        quote_in! { *tokens =>
+            #[serde($(for option in self.options join (, ) => $(match option {
+                SerdeOption::Rename(name) => rename = $(quoted(name)),
+                SerdeOption::Default => default,
+                SerdeOption::SkipSerializingIf(path) => skip_serializing_if = $(quoted(path)),
+                SerdeOption::Skip => skip,
+                SerdeOption::Flatten => flatten,
+            })))]
+        }
+    }
+}
+
+impl FormatInto<Rust> for &SerdeAttr {
+    fn format_into(self, tokens: &mut Tokens<Rust>) {
+        self.clone().format_into(tokens);
+    }
+}
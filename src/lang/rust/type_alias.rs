@@ -0,0 +1,57 @@
+use crate as genco;
+use crate::lang::Rust;
+use crate::quote_in;
+use crate::tokens::{FormatInto, ItemStr};
+use crate::Tokens;
+
+/// A type alias, like `pub type Map<V> = HashMap<String, V>;`.
+///
+/// This struct is created by the [type_alias][super::type_alias()] function.
+#[derive(Debug, Clone)]
+pub struct TypeAlias {
+    visibility: Option<Tokens<Rust>>,
+    name: ItemStr,
+    generics: Vec<Tokens<Rust>>,
+    target: Tokens<Rust>,
+}
+
+impl TypeAlias {
+    pub(super) fn new(name: ItemStr, generics: Vec<Tokens<Rust>>, target: Tokens<Rust>) -> Self {
+        Self {
+            visibility: None,
+            name,
+            generics,
+            target,
+        }
+    }
+
+    /// Set the visibility of the type alias, like `pub` or `pub(crate)`.
+    pub fn visibility<V>(self, visibility: V) -> Self
+    where
+        V: FormatInto<Rust>,
+    {
+        let mut tokens = Tokens::new();
+        tokens.append(visibility);
+
+        Self {
+            visibility: Some(tokens),
+            ..self
+        }
+    }
+}
+
+impl FormatInto<Rust> for TypeAlias {
+    fn format_into(self, tokens: &mut Tokens<Rust>) {
+        if let Some(visibility) = self.visibility {
+            quote_in!(*tokens => $visibility$[' ']);
+        }
+
+        quote_in!(*tokens => type $(self.name));
+
+        if !self.generics.is_empty() {
+            quote_in!(*tokens => <$(for g in self.generics join (, ) => $g)>);
+        }
+
+        quote_in!(*tokens => $[' ']= $(self.target););
+    }
+}
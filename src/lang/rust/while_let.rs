@@ -0,0 +1,29 @@
+use crate as genco;
+use crate::lang::Rust;
+use crate::quote_in;
+use crate::tokens::FormatInto;
+use crate::Tokens;
+
+/// A `while let` loop.
+///
+/// This is created by the [while_let][super::while_let()] function.
+pub struct WhileLet<P, X, B> {
+    pub(super) pattern: P,
+    pub(super) expr: X,
+    pub(super) body: B,
+}
+
+impl<P, X, B> FormatInto<Rust> for WhileLet<P, X, B>
+where
+    P: FormatInto<Rust>,
+    X: FormatInto<Rust>,
+    B: FormatInto<Rust>,
+{
+    fn format_into(self, tokens: &mut Tokens<Rust>) {
+        quote_in! { *tokens =>
+            while let $(self.pattern) = $(self.expr) {
+                $(self.body)
+            }
+        }
+    }
+}
@@ -0,0 +1,529 @@
+//! Specialization for Scala code generation.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use genco::prelude::*;
+//!
+//! # fn main() -> genco::fmt::Result {
+//! let future = scala::import("scala.concurrent", "Future");
+//! let context = scala::import("scala.concurrent", "ExecutionContext");
+//! let list = scala::import("scala.collection.immutable", "List");
+//!
+//! let toks: scala::Tokens = quote! {
+//!     def fetch(ids: $(&list)[Int])(implicit ec: $context): $(&future)[$list[String]] =
+//!         $future.traverse(ids)(lookup)
+//! };
+//!
+//! assert_eq!(
+//!     vec![
+//!         "import scala.collection.immutable.List",
+//!         "import scala.concurrent.{ExecutionContext, Future}",
+//!         "",
+//!         "def fetch(ids: List[Int])(implicit ec: ExecutionContext): Future[List[String]] =",
+//!         "    Future.traverse(ids)(lookup)",
+//!     ],
+//!     toks.to_file_vec()?
+//! );
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # String Quoting in Scala
+//!
+//! Strings are quoted with Java-style escape sequences. [Quoted string
+//! interpolation] is rendered as an `s` interpolated string, in which `$` is
+//! escaped as `$$`.
+//!
+//! [Quoted string interpolation]: https://docs.rs/genco/0/genco/macro.quote.html#quoted-string-interpolation
+//!
+//! ```rust
+//! use genco::prelude::*;
+//!
+//! # fn main() -> genco::fmt::Result {
+//! let toks: scala::Tokens = quote!("start π $ \n end");
+//! assert_eq!("\"start π $ \\n end\"", toks.to_string()?);
+//!
+//! let toks: scala::Tokens = quote!($[str](Cost: $$ $(price) and $(a + b)));
+//! assert_eq!("s\"Cost: $$ $price and ${a + b}\"", toks.to_string()?);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate as genco;
+use crate::fmt;
+use crate::quote_in;
+use crate::tokens::ItemStr;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fmt::Write as _;
+
+/// Tokens container specialized for Scala.
+pub type Tokens = crate::Tokens<Scala>;
+
+impl genco::lang::LangSupportsEval for Scala {}
+
+impl_lang! {
+    /// Language specialization for Scala.
+    pub Scala {
+        type Config = Config;
+        type Format = Format;
+        type Item = Any;
+
+        fn capabilities() -> crate::lang::Capabilities {
+            crate::lang::Capabilities {
+                string_interpolation: true,
+                import_aliasing: true,
+                block_comments: true,
+                ..Default::default()
+            }
+        }
+
+        fn block_comment_delimiters() -> Option<(&'static str, &'static str)> {
+            Some(("/*", "*/"))
+        }
+
+        fn unit_type() -> Option<&'static str> {
+            Some("Unit")
+        }
+
+        fn statement_terminator() -> &'static str {
+            ""
+        }
+
+        fn open_quote(
+            out: &mut fmt::Formatter<'_>,
+            _config: &Self::Config,
+            _format: &Self::Format,
+            has_eval: bool,
+        ) -> fmt::Result {
+            if has_eval {
+                out.write_char('s')?;
+            }
+
+            out.write_char('"')?;
+            Ok(())
+        }
+
+        fn string_eval_literal(
+            out: &mut fmt::Formatter<'_>,
+            _config: &Self::Config,
+            _format: &Self::Format,
+            literal: &str,
+        ) -> fmt::Result {
+            write!(out, "${}", literal)?;
+            Ok(())
+        }
+
+        fn start_string_eval(
+            out: &mut fmt::Formatter<'_>,
+            _config: &Self::Config,
+            _format: &Self::Format,
+        ) -> fmt::Result {
+            out.write_str("${")?;
+            Ok(())
+        }
+
+        fn end_string_eval(
+            out: &mut fmt::Formatter<'_>,
+            _config: &Self::Config,
+            _format: &Self::Format,
+        ) -> fmt::Result {
+            out.write_char('}')?;
+            Ok(())
+        }
+
+        fn write_quoted(out: &mut fmt::Formatter<'_>, input: &str) -> fmt::Result {
+            // From: https://docs.scala-lang.org/scala3/reference/syntax.html
+            for c in input.chars() {
+                match c {
+                    '\t' => out.write_str("\\t")?,
+                    '\u{0008}' => out.write_str("\\b")?,
+                    '\n' => out.write_str("\\n")?,
+                    '\r' => out.write_str("\\r")?,
+                    '\u{000c}' => out.write_str("\\f")?,
+                    '"' => out.write_str("\\\"")?,
+                    '\\' => out.write_str("\\\\")?,
+                    c if !c.is_control() => out.write_char(c)?,
+                    c => {
+                        for c in c.encode_utf16(&mut [0u16; 2]) {
+                            write!(out, "\\u{:04x}", c)?;
+                        }
+                    }
+                }
+            }
+
+            Ok(())
+        }
+
+        fn write_eval_quoted(out: &mut fmt::Formatter<'_>, input: &str) -> fmt::Result {
+            // A literal `$` in an interpolated string is written as `$$`.
+            let mut last = 0;
+
+            for (n, _) in input.match_indices('$') {
+                Self::write_quoted(out, &input[last..n])?;
+                out.write_str("$$")?;
+                last = n + 1;
+            }
+
+            Self::write_quoted(out, &input[last..])
+        }
+
+        fn format_file(
+            tokens: &Tokens,
+            out: &mut fmt::Formatter<'_>,
+            config: &Self::Config,
+        ) -> fmt::Result {
+            let mut header = Tokens::new();
+
+            if let Some(ref package) = config.package {
+                quote_in!(header => package $package);
+                header.line();
+            }
+
+            let mut format = Format::default();
+            Self::imports(&mut header, tokens, config, &mut format.imported);
+            header.format(out, config, &format)?;
+            tokens.format(out, config, &format)?;
+            Ok(())
+        }
+
+        fn format_imports(
+            tokens: &Tokens,
+            out: &mut fmt::Formatter<'_>,
+            config: &Self::Config,
+        ) -> fmt::Result {
+            let mut imports = Tokens::new();
+            let mut format = Format::default();
+            Self::imports(&mut imports, tokens, config, &mut format.imported);
+            imports.format(out, config, &format)
+        }
+    }
+
+    Import {
+        fn format(&self, out: &mut fmt::Formatter<'_>, config: &Config, format: &Format) -> fmt::Result {
+            let local = self.alias.as_ref().unwrap_or(&self.name);
+            let imported = format.imported.get(local.as_ref());
+
+            if imported == Some(&(self.package.clone(), self.name.clone())) {
+                out.write_str(local)?;
+                return Ok(());
+            }
+
+            let file_package = config.package.as_deref();
+
+            if self.alias.is_some() || !is_implicit(&self.package, file_package) {
+                out.write_str(&self.package)?;
+                out.write_str(SEP)?;
+            }
+
+            out.write_str(&self.name)?;
+            Ok(())
+        }
+    }
+
+    Wildcard {
+        fn format(&self, _: &mut fmt::Formatter<'_>, _: &Config, _: &Format) -> fmt::Result {
+            Ok(())
+        }
+    }
+}
+
+const SEP: &str = ".";
+
+/// Packages which are imported by default in Scala.
+///
+/// See: <https://docs.scala-lang.org/tour/packages-and-imports.html>
+const DEFAULT_PACKAGES: &[&str] = &["scala", "scala.Predef", "java.lang"];
+
+/// Test if the given package doesn't have to be imported.
+fn is_implicit(package: &str, file_package: Option<&str>) -> bool {
+    DEFAULT_PACKAGES.contains(&package) || Some(package) == file_package
+}
+
+/// Format state for Scala.
+#[derive(Debug, Default)]
+pub struct Format {
+    /// Names which have been imported into the local namespace, mapped to
+    /// the package and name they were imported from.
+    imported: HashMap<String, (ItemStr, ItemStr)>,
+}
+
+/// Configuration for Scala.
+#[derive(Debug, Default)]
+pub struct Config {
+    /// Package to use.
+    package: Option<ItemStr>,
+}
+
+impl Config {
+    /// Configure package to use for the file generated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    /// use genco::fmt;
+    ///
+    /// let foo = scala::import("com.example", "Foo");
+    /// let bar = scala::import("com.example.other", "Bar");
+    ///
+    /// let toks = quote!($foo $bar);
+    ///
+    /// let config = scala::Config::default().with_package("com.example");
+    /// let fmt = fmt::Config::from_lang::<Scala>();
+    ///
+    /// let mut w = fmt::VecWriter::new();
+    ///
+    /// toks.format_file(&mut w.as_formatter(&fmt), &config)?;
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "package com.example",
+    ///         "",
+    ///         "import com.example.other.Bar",
+    ///         "",
+    ///         "Foo Bar",
+    ///     ],
+    ///     w.into_vec(),
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn with_package<P>(self, package: P) -> Self
+    where
+        P: Into<ItemStr>,
+    {
+        Self {
+            package: Some(package.into()),
+        }
+    }
+}
+
+/// The import of a Scala type `import scala.concurrent.Future`.
+///
+/// Created through the [import()] function.
+#[derive(Debug, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct Import {
+    /// Package of the type.
+    package: ItemStr,
+    /// Name of the type.
+    name: ItemStr,
+    /// Alias to import the type as.
+    alias: Option<ItemStr>,
+}
+
+impl Import {
+    /// Import the type under an alias, like `import a.b.{C => D}`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    ///
+    /// let a = scala::import("java.util", "List").with_alias("JList");
+    /// let b = scala::import("scala.collection.immutable", "List");
+    ///
+    /// let toks = quote!($a $b);
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "import java.util.{List => JList}",
+    ///         "import scala.collection.immutable.List",
+    ///         "",
+    ///         "JList List",
+    ///     ],
+    ///     toks.to_file_vec()?
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn with_alias<A>(self, alias: A) -> Self
+    where
+        A: Into<ItemStr>,
+    {
+        Self {
+            alias: Some(alias.into()),
+            ..self
+        }
+    }
+}
+
+/// The wildcard import of a Scala package `import scala.concurrent._`.
+///
+/// Created through the [wildcard()] function.
+#[derive(Debug, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct Wildcard {
+    /// Package to import everything from.
+    package: ItemStr,
+}
+
+/// Names imported from a single package.
+#[derive(Default)]
+struct Selectors<'a> {
+    /// If everything in the package is imported.
+    wildcard: bool,
+    /// Names and aliases which are imported.
+    names: BTreeSet<(&'a ItemStr, Option<&'a ItemStr>)>,
+}
+
+impl Scala {
+    fn imports(
+        out: &mut Tokens,
+        tokens: &Tokens,
+        config: &Config,
+        imported: &mut HashMap<String, (ItemStr, ItemStr)>,
+    ) {
+        let mut packages = BTreeMap::<&ItemStr, Selectors<'_>>::new();
+
+        let file_package = config.package.as_deref();
+
+        for import in tokens.walk_imports() {
+            match import {
+                Any::Import(import) => {
+                    if import.alias.is_none() && is_implicit(&import.package, file_package) {
+                        continue;
+                    }
+
+                    packages
+                        .entry(&import.package)
+                        .or_default()
+                        .names
+                        .insert((&import.name, import.alias.as_ref()));
+                }
+                Any::Wildcard(wildcard) => {
+                    packages.entry(&wildcard.package).or_default().wildcard = true;
+                }
+            }
+        }
+
+        if packages.is_empty() {
+            return;
+        }
+
+        for (package, selectors) in packages {
+            let mut names = Vec::new();
+
+            for (name, alias) in selectors.names {
+                let local = alias.unwrap_or(name);
+
+                // Names which are already in use are left fully qualified.
+                if imported.contains_key(local.as_ref()) {
+                    continue;
+                }
+
+                imported.insert(local.to_string(), (package.clone(), name.clone()));
+
+                // Plain names are covered by a wildcard import.
+                if selectors.wildcard && alias.is_none() {
+                    continue;
+                }
+
+                names.push((name, alias));
+            }
+
+            match (names.as_slice(), selectors.wildcard) {
+                ([], false) => continue,
+                ([], true) => quote_in!(*out => import $package$(SEP)_),
+                ([(name, None)], false) => quote_in!(*out => import $package$(SEP)$(*name)),
+                (names, wildcard) => {
+                    quote_in! { *out =>
+                        import $package$(SEP){$(for (name, alias) in names join (, ) {
+                            $(*name)$(if let Some(alias) = alias => $[' ']=> $(*alias))
+                        })$(if wildcard => , _)}
+                    }
+                }
+            }
+
+            out.push();
+        }
+
+        out.line();
+    }
+}
+
+/// The import of a Scala type `import scala.concurrent.Future`.
+///
+/// Types from packages which are imported by default, like `scala` or
+/// `java.lang`, are never imported. Names which are imported from the same
+/// package are grouped into a single import, like `import a.b.{C, D}`. If
+/// two imported types have the same name, the first one in sorted order is
+/// imported and the other one is left fully qualified.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let a = scala::import("com.example.a", "Node");
+/// let b = scala::import("com.example.b", "Node");
+/// let edge = scala::import("com.example.a", "Edge");
+/// let option = scala::import("scala", "Option");
+///
+/// let toks = quote! {
+///     val nodes: Map[$(&a), $b] = Map.empty
+///     val first: $option[$a] = None
+///     val edge: $edge = null
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "import com.example.a.{Edge, Node}",
+///         "",
+///         "val nodes: Map[Node, com.example.b.Node] = Map.empty",
+///         "val first: Option[Node] = None",
+///         "val edge: Edge = null",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn import<P, N>(package: P, name: N) -> Import
+where
+    P: Into<ItemStr>,
+    N: Into<ItemStr>,
+{
+    Import {
+        package: package.into(),
+        name: name.into(),
+        alias: None,
+    }
+}
+
+/// The wildcard import of a Scala package `import scala.concurrent._`.
+///
+/// The import doesn't render anything, so it's typically added through
+/// [register][crate::tokens::register()]. Names imported from the same
+/// package are covered by the wildcard import, except for aliased ones which
+/// are grouped with it like `import a.b.{C => D, _}`.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let future = scala::import("scala.concurrent", "Future");
+/// let duration = scala::import("scala.concurrent.duration", "Duration").with_alias("D");
+///
+/// let toks = quote! {
+///     $(register(scala::wildcard("scala.concurrent")))
+///     $(register(scala::wildcard("scala.concurrent.duration")))
+///     val f: $future[$duration] = ???
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "import scala.concurrent._",
+///         "import scala.concurrent.duration.{Duration => D, _}",
+///         "",
+///         "val f: Future[D] = ???",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn wildcard<P>(package: P) -> Wildcard
+where
+    P: Into<ItemStr>,
+{
+    Wildcard {
+        package: package.into(),
+    }
+}
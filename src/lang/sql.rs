@@ -0,0 +1,271 @@
+//! Specialization for SQL code generation.
+//!
+//! SQL doesn't have imports, but the way identifiers are quoted depends on
+//! the database, which is configured through [Config::with_dialect].
+//! Identifiers are written through [ident()], which quotes them according to
+//! the configured [Dialect].
+//!
+//! # Examples
+//!
+//! ```rust
+//! use genco::prelude::*;
+//! use genco::fmt;
+//!
+//! # fn main() -> genco::fmt::Result {
+//! let toks: sql::Tokens = quote! {
+//!     SELECT $(sql::ident("name")) FROM $(sql::ident("user"))
+//!     WHERE $(sql::ident("role")) = $(quoted("admin"));
+//! };
+//!
+//! assert_eq!(
+//!     vec![
+//!         "SELECT \"name\" FROM \"user\"",
+//!         "WHERE \"role\" = 'admin';",
+//!     ],
+//!     toks.to_file_vec()?
+//! );
+//!
+//! let config = sql::Config::default().with_dialect(sql::Dialect::MySql);
+//! let fmt = fmt::Config::from_lang::<Sql>();
+//!
+//! let mut w = fmt::VecWriter::new();
+//! toks.format_file(&mut w.as_formatter(&fmt), &config)?;
+//!
+//! assert_eq!(
+//!     vec![
+//!         "SELECT `name` FROM `user`",
+//!         "WHERE `role` = 'admin';",
+//!     ],
+//!     w.into_vec()
+//! );
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # String Quoting in SQL
+//!
+//! Strings are quoted with single quotes, in which a single quote is written
+//! as `''`. No other characters are escaped, so backslashes are written as
+//! they are, except for [Dialect::MySql] which treats backslashes in strings
+//! as escapes, so they are written as `\\`.
+//!
+//! ```rust
+//! use genco::prelude::*;
+//! use genco::fmt;
+//!
+//! # fn main() -> genco::fmt::Result {
+//! let toks: sql::Tokens = quote!($(quoted("it's \"here\" \\o/")));
+//! assert_eq!("'it''s \"here\" \\o/'", toks.to_string()?);
+//!
+//! let config = sql::Config::default().with_dialect(sql::Dialect::MySql);
+//! let fmt = fmt::Config::from_lang::<Sql>();
+//!
+//! let mut w = fmt::VecWriter::new();
+//! toks.format_file(&mut w.as_formatter(&fmt), &config)?;
+//! assert_eq!(vec!["'it''s \"here\" \\\\o/'"], w.into_vec());
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::fmt;
+use crate::tokens::ItemStr;
+use std::fmt::Write as _;
+
+/// Tokens container specialization for SQL.
+pub type Tokens = crate::Tokens<Sql>;
+
+impl_lang! {
+    /// Language specialization for SQL.
+    pub Sql {
+        type Config = Config;
+        type Format = Format;
+        type Item = Any;
+
+        fn capabilities() -> crate::lang::Capabilities {
+            crate::lang::Capabilities {
+                block_comments: true,
+                ..Default::default()
+            }
+        }
+
+        fn line_comment_prefix() -> Option<&'static str> {
+            Some("--")
+        }
+
+        fn block_comment_delimiters() -> Option<(&'static str, &'static str)> {
+            Some(("/*", "*/"))
+        }
+
+        fn open_quote(
+            out: &mut fmt::Formatter<'_>,
+            _config: &Self::Config,
+            _format: &Self::Format,
+            _has_eval: bool,
+        ) -> fmt::Result {
+            out.write_char('\'')?;
+            Ok(())
+        }
+
+        fn close_quote(
+            out: &mut fmt::Formatter<'_>,
+            _config: &Self::Config,
+            _format: &Self::Format,
+            _has_eval: bool,
+        ) -> fmt::Result {
+            out.write_char('\'')?;
+            Ok(())
+        }
+
+        fn write_quoted(out: &mut fmt::Formatter<'_>, input: &str) -> fmt::Result {
+            write_doubled(out, input, '\'')
+        }
+
+        fn write_quoted_with_config(
+            out: &mut fmt::Formatter<'_>,
+            config: &Self::Config,
+            _format: &Self::Format,
+            input: &str,
+        ) -> fmt::Result {
+            if config.dialect != Dialect::MySql {
+                return Self::write_quoted(out, input);
+            }
+
+            for c in input.chars() {
+                match c {
+                    '\'' => out.write_str("''")?,
+                    '\\' => out.write_str("\\\\")?,
+                    c => out.write_char(c)?,
+                }
+            }
+
+            Ok(())
+        }
+    }
+
+    Ident {
+        fn format(&self, out: &mut fmt::Formatter<'_>, config: &Config, _: &Format) -> fmt::Result {
+            let (open, close) = match config.dialect {
+                Dialect::Standard => ('"', '"'),
+                Dialect::MySql => ('`', '`'),
+                Dialect::SqlServer => ('[', ']'),
+            };
+
+            out.write_char(open)?;
+            write_doubled(out, &self.name, close)?;
+            out.write_char(close)?;
+            Ok(())
+        }
+    }
+}
+
+/// Write the input, where every occurence of `quote` is doubled.
+fn write_doubled(out: &mut fmt::Formatter<'_>, input: &str, quote: char) -> fmt::Result {
+    for c in input.chars() {
+        if c == quote {
+            out.write_char(c)?;
+        }
+
+        out.write_char(c)?;
+    }
+
+    Ok(())
+}
+
+/// The SQL dialect to generate code for.
+///
+/// This determines how [ident()] quotes identifiers, and how strings are
+/// quoted.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Dialect {
+    /// Standard SQL, where identifiers are quoted like `"name"`. This is
+    /// used by PostgreSQL, SQLite and Oracle.
+    #[default]
+    Standard,
+    /// MySQL and MariaDB, where identifiers are quoted like `` `name` ``, and
+    /// backslashes in strings are escaped.
+    MySql,
+    /// Microsoft SQL Server, where identifiers are quoted like `[name]`.
+    SqlServer,
+}
+
+/// Format state for SQL.
+#[derive(Debug, Default)]
+pub struct Format {}
+
+/// Configuration for SQL.
+#[derive(Debug, Default)]
+pub struct Config {
+    /// The dialect to generate code for.
+    dialect: Dialect,
+}
+
+impl Config {
+    /// Set the dialect to generate code for. Defaults to
+    /// [Dialect::Standard].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    /// use genco::fmt;
+    ///
+    /// let toks: sql::Tokens = quote!(DROP TABLE $(sql::ident("order")));
+    ///
+    /// let config = sql::Config::default().with_dialect(sql::Dialect::SqlServer);
+    /// let fmt = fmt::Config::from_lang::<Sql>();
+    ///
+    /// let mut w = fmt::VecWriter::new();
+    /// toks.format_file(&mut w.as_formatter(&fmt), &config)?;
+    ///
+    /// assert_eq!(vec!["DROP TABLE [order]"], w.into_vec());
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn with_dialect(self, dialect: Dialect) -> Self {
+        Self { dialect }
+    }
+}
+
+/// A quoted identifier, like `"name"`.
+///
+/// Created through the [ident()] function.
+#[derive(Debug, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct Ident {
+    /// The name of the identifier.
+    name: ItemStr,
+}
+
+/// A quoted identifier, like a table or column name.
+///
+/// The identifier is quoted according to the configured [Dialect], and
+/// closing quotes in the name are escaped by doubling them. Quoting
+/// identifiers makes it safe to use names which are reserved keywords, or
+/// which contain spaces or other special characters.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let toks: sql::Tokens = quote! {
+///     CREATE TABLE $(sql::ident("order")) (
+///         $(sql::ident("my \"id\"")) INTEGER PRIMARY KEY
+///     );
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "CREATE TABLE \"order\" (",
+///         "    \"my \"\"id\"\"\" INTEGER PRIMARY KEY",
+///         ");",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn ident<N>(name: N) -> Ident
+where
+    N: Into<ItemStr>,
+{
+    Ident { name: name.into() }
+}
@@ -1,5 +1,38 @@
 //! Specialization for Swift code generation.
 //!
+//! # Examples
+//!
+//! ```rust
+//! use genco::prelude::*;
+//!
+//! # fn main() -> genco::fmt::Result {
+//! let date = swift::import("Foundation", "Date");
+//! let url = swift::import("Foundation", "URL");
+//!
+//! let toks: swift::Tokens = quote! {
+//!     struct Event {
+//!         let at: $date
+//!         let link: $(swift::optional(&url))
+//!         let links: Array<$url>
+//!     }
+//! };
+//!
+//! assert_eq!(
+//!     vec![
+//!         "import Foundation",
+//!         "",
+//!         "struct Event {",
+//!         "    let at: Date",
+//!         "    let link: URL?",
+//!         "    let links: Array<URL>",
+//!         "}",
+//!     ],
+//!     toks.to_file_vec()?
+//! );
+//! # Ok(())
+//! # }
+//! ```
+//!
 //! # String Quoting in Swift
 //!
 //! Swift uses UTF-8 internally, string quoting is with the exception of escape
@@ -13,15 +46,42 @@
 //! assert_eq!("\"start π 😊 \\n \\u{7f} ÿ $ end\"", toks.to_string()?);
 //! # Ok(())
 //! # }
+//! ```
+//!
+//! # String Interpolation in Swift
+//!
+//! Swift supports [quoted string interpolation] through `\(expr)`. Literal
+//! backslashes are escaped, so they can't be confused with it.
+//!
+//! [quoted string interpolation]: https://docs.rs/genco/0/genco/macro.quote.html#quoted-string-interpolation
+//!
+//! ```rust
+//! use genco::prelude::*;
+//!
+//! # fn main() -> genco::fmt::Result {
+//! let toks: swift::Tokens = quote!($[str](Hello $(name) and $(a + b)));
+//! assert_eq!("\"Hello \\(name) and \\(a + b)\"", toks.to_string()?);
+//!
+//! let toks: swift::Tokens = quote!("not \\(interpolated)");
+//! assert_eq!("\"not \\\\(interpolated)\"", toks.to_string()?);
+//! # Ok(())
+//! # }
+//! ```
 
+use crate as genco;
 use crate::fmt;
-use crate::tokens::ItemStr;
+use crate::quote_in;
+use crate::tokens::{self, FormatInto, ItemStr};
+
+pub use crate::tokens::Nullable as Optional;
 use std::collections::BTreeSet;
 use std::fmt::Write as _;
 
 /// Tokens container specialization for Rust.
 pub type Tokens = crate::Tokens<Swift>;
 
+impl genco::lang::LangSupportsEval for Swift {}
+
 impl_lang! {
     /// Swift token specialization.
     pub Swift {
@@ -29,6 +89,44 @@ impl_lang! {
         type Format = Format;
         type Item = Import;
 
+        fn capabilities() -> crate::lang::Capabilities {
+            crate::lang::Capabilities {
+                string_interpolation: true,
+                block_comments: true,
+                ..Default::default()
+            }
+        }
+
+        fn block_comment_delimiters() -> Option<(&'static str, &'static str)> {
+            Some(("/*", "*/"))
+        }
+
+        fn unit_type() -> Option<&'static str> {
+            Some("Void")
+        }
+
+        fn statement_terminator() -> &'static str {
+            ""
+        }
+
+        fn start_string_eval(
+            out: &mut fmt::Formatter<'_>,
+            _config: &Self::Config,
+            _format: &Self::Format,
+        ) -> fmt::Result {
+            out.write_str("\\(")?;
+            Ok(())
+        }
+
+        fn end_string_eval(
+            out: &mut fmt::Formatter<'_>,
+            _config: &Self::Config,
+            _format: &Self::Format,
+        ) -> fmt::Result {
+            out.write_char(')')?;
+            Ok(())
+        }
+
         fn write_quoted(out: &mut fmt::Formatter<'_>, input: &str) -> fmt::Result {
             // From: https://docs.swift.org/swift-book/LanguageGuide/StringsAndCharacters.html
 
@@ -63,11 +161,22 @@ impl_lang! {
             tokens.format(out, config, &format)?;
             Ok(())
         }
+
+        fn format_imports(
+            tokens: &Tokens,
+            out: &mut fmt::Formatter<'_>,
+            config: &Self::Config,
+        ) -> fmt::Result {
+            let mut imports = Tokens::new();
+            Self::imports(&mut imports, tokens);
+            imports.format(out, config, &Format::default())
+        }
     }
 
     Import {
         fn format(&self, out: &mut fmt::Formatter<'_>, _: &Config, _: &Format) -> fmt::Result {
-            out.write_str(&self.name)
+            out.write_str(&self.name)?;
+            Ok(())
         }
     }
 }
@@ -85,21 +194,21 @@ pub struct Config {}
 /// Created through the [import()] function.
 #[derive(Debug, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
 pub struct Import {
-    /// Module of the imported name.
-    module: ItemStr,
+    /// Module of the imported name, or `None` if the name is declared in the
+    /// current module.
+    module: Option<ItemStr>,
     /// Name imported.
     name: ItemStr,
 }
 
 impl Swift {
     fn imports(out: &mut Tokens, tokens: &Tokens) {
-        use crate as genco;
-        use crate::quote_in;
-
         let mut modules = BTreeSet::new();
 
         for import in tokens.walk_imports() {
-            modules.insert(&import.module);
+            if let Some(module) = &import.module {
+                modules.insert(module);
+            }
         }
 
         if !modules.is_empty() {
@@ -137,7 +246,72 @@ where
     N: Into<ItemStr>,
 {
     Import {
-        module: module.into(),
+        module: Some(module.into()),
+        name: name.into(),
+    }
+}
+
+/// A name which is declared in the current module, and which doesn't need to
+/// be imported.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let data = swift::import("Foundation", "Data");
+/// let message = swift::local("Message");
+///
+/// let toks = quote!(func decode(_ data: $data) -> $message);
+///
+/// assert_eq!(
+///     vec![
+///         "import Foundation",
+///         "",
+///         "func decode(_ data: Data) -> Message",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn local<N>(name: N) -> Import
+where
+    N: Into<ItemStr>,
+{
+    Import {
+        module: None,
         name: name.into(),
     }
 }
+
+/// An optional type, like `Foo?`.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let data = swift::import("Foundation", "Data");
+///
+/// let toks: swift::Tokens = quote! {
+///     var a: $(swift::optional("String")) = nil
+///     var b: $(swift::optional(quote!(Array<$data>))) = nil
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "import Foundation",
+///         "",
+///         "var a: String? = nil",
+///         "var b: Array<Data>? = nil",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn optional<T>(inner: T) -> Optional<T>
+where
+    T: FormatInto<Swift>,
+{
+    tokens::nullable(inner)
+}
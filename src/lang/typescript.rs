@@ -0,0 +1,583 @@
+//! Specialization for TypeScript code generation.
+//!
+//! This mirrors the [JavaScript][super::js] specialization, and adds support
+//! for type-only imports through [Import::into_type_only]. Type annotations,
+//! including generic type arguments like `Array<T>`, are written directly in
+//! the token stream using imported names.
+//!
+//! # Examples
+//!
+//! Basic example:
+//!
+//! ```rust
+//! use genco::prelude::*;
+//!
+//! # fn main() -> genco::fmt::Result {
+//! let user = typescript::import("./model", "User").into_type_only();
+//! let fetch_all = typescript::import("./api", "fetchAll");
+//!
+//! let toks: typescript::Tokens = quote! {
+//!     export async function users(): Promise<Array<$(&user)>> {
+//!         return await $fetch_all<$user>("users");
+//!     }
+//! };
+//!
+//! assert_eq!(
+//!     vec![
+//!         "import {fetchAll} from \"./api\";",
+//!         "import type {User} from \"./model\";",
+//!         "",
+//!         "export async function users(): Promise<Array<User>> {",
+//!         "    return await fetchAll<User>(\"users\");",
+//!         "}",
+//!     ],
+//!     toks.to_file_vec()?
+//! );
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # String Quoting in TypeScript
+//!
+//! String quoting is the same as in [JavaScript][super::js].
+//!
+//! ```rust
+//! use genco::prelude::*;
+//!
+//! # fn main() -> genco::fmt::Result {
+//! let toks: typescript::Tokens = quote!("start π 😊 \n \x7f ÿ $ \\ end");
+//! assert_eq!("\"start π 😊 \\n \\x7f ÿ $ \\\\ end\"", toks.to_string()?);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::fmt;
+use crate::lang::JavaScript;
+use crate::tokens::ItemStr;
+use relative_path::{RelativePath, RelativePathBuf};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write as _;
+
+pub use super::js::Module;
+
+/// Tokens container specialization for TypeScript.
+pub type Tokens = crate::Tokens<TypeScript>;
+
+impl crate::lang::LangSupportsEval for TypeScript {}
+
+impl_lang! {
+    /// TypeScript language specialization.
+    pub TypeScript {
+        type Config = Config;
+        type Format = Format;
+        type Item = Import;
+
+        fn capabilities() -> crate::lang::Capabilities {
+            crate::lang::Capabilities {
+                string_interpolation: true,
+                import_aliasing: true,
+                block_comments: true,
+                ..Default::default()
+            }
+        }
+
+        fn block_comment_delimiters() -> Option<(&'static str, &'static str)> {
+            Some(("/*", "*/"))
+        }
+
+        fn unit_type() -> Option<&'static str> {
+            Some("void")
+        }
+
+        /// Start a string quote.
+        fn open_quote(
+            out: &mut fmt::Formatter<'_>,
+            _config: &Self::Config,
+            _format: &Self::Format,
+            has_eval: bool,
+        ) -> fmt::Result {
+            if has_eval {
+                out.write_char('`')?;
+            } else {
+                out.write_char(out.quote_style().as_char())?;
+            }
+
+            Ok(())
+        }
+
+        /// End a string quote.
+        fn close_quote(
+            out: &mut fmt::Formatter<'_>,
+            _config: &Self::Config,
+            _format: &Self::Format,
+            has_eval: bool,
+        ) -> fmt::Result {
+            if has_eval {
+                out.write_char('`')?;
+            } else {
+                out.write_char(out.quote_style().as_char())?;
+            }
+
+            Ok(())
+        }
+
+        fn start_string_eval(
+            out: &mut fmt::Formatter<'_>,
+            _config: &Self::Config,
+            _format: &Self::Format,
+        ) -> fmt::Result {
+            out.write_str("${")?;
+            Ok(())
+        }
+
+        fn end_string_eval(
+            out: &mut fmt::Formatter<'_>,
+            _config: &Self::Config,
+            _format: &Self::Format,
+        ) -> fmt::Result {
+            out.write_char('}')?;
+            Ok(())
+        }
+
+        fn write_quoted(out: &mut fmt::Formatter<'_>, input: &str) -> fmt::Result {
+            JavaScript::write_quoted(out, input)
+        }
+
+        fn format_file(
+            tokens: &Tokens,
+            out: &mut fmt::Formatter<'_>,
+            config: &Self::Config,
+        ) -> fmt::Result {
+            let mut imports = Tokens::new();
+            Self::imports(&mut imports, tokens, config);
+            let format = Format::default();
+            imports.format(out, config, &format)?;
+            tokens.format(out, config, &format)?;
+            Ok(())
+        }
+
+        fn format_imports(
+            tokens: &Tokens,
+            out: &mut fmt::Formatter<'_>,
+            config: &Self::Config,
+        ) -> fmt::Result {
+            let mut imports = Tokens::new();
+            Self::imports(&mut imports, tokens, config);
+            imports.format(out, config, &Format::default())
+        }
+    }
+
+    Import {
+        fn format(&self, out: &mut fmt::Formatter<'_>, _: &Config, _: &Format) -> fmt::Result {
+            let name = match self.kind {
+                ImportKind::Named => self.alias.as_ref().unwrap_or(&self.name),
+                _ => &self.name,
+            };
+
+            out.write_str(name)?;
+            Ok(())
+        }
+    }
+}
+
+/// Format state for TypeScript.
+#[derive(Debug, Default)]
+pub struct Format {}
+
+/// Configuration for TypeScript.
+#[derive(Debug, Default)]
+pub struct Config {
+    module_path: Option<RelativePathBuf>,
+}
+
+impl Config {
+    /// Configure the path to the current module being renderer.
+    ///
+    /// This setting will determine what path imports are renderer relative
+    /// towards. So importing a module from `"foo/bar"`, and setting this to
+    /// `"foo/baz"` will cause the import to be rendered relatively as
+    /// `"../bar"`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    /// use genco::fmt;
+    ///
+    /// let foo = typescript::import(typescript::Module::Path("foo/bar.ts".into()), "Foo");
+    ///
+    /// let toks: typescript::Tokens = quote!($foo);
+    ///
+    /// let mut w = fmt::VecWriter::new();
+    ///
+    /// let config = typescript::Config::default().with_module_path("foo/baz.ts");
+    /// let fmt = fmt::Config::from_lang::<TypeScript>();
+    ///
+    /// toks.format_file(&mut w.as_formatter(&fmt), &config)?;
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "import {Foo} from \"../bar.ts\";",
+    ///         "",
+    ///         "Foo",
+    ///     ],
+    ///     w.into_vec()
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn with_module_path<M>(self, module_path: M) -> Self
+    where
+        M: Into<RelativePathBuf>,
+    {
+        Self {
+            module_path: Some(module_path.into()),
+        }
+    }
+}
+
+/// Internal type to determine the kind of import used.
+#[derive(Debug, Clone, Copy, Hash, PartialOrd, Ord, PartialEq, Eq)]
+enum ImportKind {
+    Named,
+    Default,
+    Wildcard,
+}
+
+/// The import of a TypeScript type `import {foo} from "module"`.
+///
+/// Created through the [import()] function.
+#[derive(Debug, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct Import {
+    /// The kind of the import.
+    kind: ImportKind,
+    /// Module of the imported name.
+    module: Module,
+    /// Name imported.
+    name: ItemStr,
+    /// Alias of an imported item.
+    alias: Option<ItemStr>,
+    /// If the import is only used as a type.
+    type_only: bool,
+}
+
+impl Import {
+    /// Change alias of imported item.
+    ///
+    /// This implies that the import is a named import.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    ///
+    /// let a = typescript::import("collections", "vec");
+    /// let b = typescript::import("collections", "vec").with_alias("list");
+    ///
+    /// let toks = quote!($a $b);
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "import {vec, vec as list} from \"collections\";",
+    ///         "",
+    ///         "vec list",
+    ///     ],
+    ///     toks.to_file_vec()?
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn with_alias<N>(self, alias: N) -> Self
+    where
+        N: Into<ItemStr>,
+    {
+        Self {
+            kind: ImportKind::Named,
+            alias: Some(alias.into()),
+            ..self
+        }
+    }
+
+    /// Convert into a default import.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    ///
+    /// let react = typescript::import("react", "React").into_default();
+    ///
+    /// let toks = quote!($react);
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "import React from \"react\";",
+    ///         "",
+    ///         "React",
+    ///     ],
+    ///     toks.to_file_vec()?
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn into_default(self) -> Self {
+        Self {
+            kind: ImportKind::Default,
+            alias: None,
+            ..self
+        }
+    }
+
+    /// Convert into a wildcard import.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    ///
+    /// let path = typescript::import("path", "path").into_wildcard();
+    ///
+    /// let toks = quote!($path);
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "import * as path from \"path\";",
+    ///         "",
+    ///         "path",
+    ///     ],
+    ///     toks.to_file_vec()?
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn into_wildcard(self) -> Self {
+        Self {
+            kind: ImportKind::Wildcard,
+            alias: None,
+            ..self
+        }
+    }
+
+    /// Convert into a type-only import, like `import type {Foo} from "foo"`.
+    ///
+    /// Type-only imports are erased when compiled to JavaScript. If the same
+    /// name is also imported as a value, the regular import is used instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    ///
+    /// let props = typescript::import("react", "Props").into_type_only();
+    /// let react = typescript::import("react", "React").into_default();
+    /// let ty = typescript::import("react", "FC").into_type_only();
+    /// let value = typescript::import("react", "FC");
+    ///
+    /// let toks = quote!($react $props $ty $value);
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "import React, {FC} from \"react\";",
+    ///         "import type {Props} from \"react\";",
+    ///         "",
+    ///         "React Props FC FC",
+    ///     ],
+    ///     toks.to_file_vec()?
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn into_type_only(self) -> Self {
+        Self {
+            type_only: true,
+            ..self
+        }
+    }
+}
+
+impl TypeScript {
+    /// Translate imports into the necessary tokens.
+    fn imports(out: &mut Tokens, tokens: &Tokens, config: &Config) {
+        use crate as genco;
+        use crate::prelude::*;
+
+        let mut modules = BTreeMap::<&Module, ResolvedModule<'_>>::new();
+        let mut wildcards = BTreeSet::new();
+
+        for import in tokens.walk_imports() {
+            match import.kind {
+                ImportKind::Named => {
+                    let module = modules.entry(&import.module).or_default();
+
+                    let element = match &import.alias {
+                        None => ImportedElement::Plain(&import.name),
+                        Some(alias) => ImportedElement::Aliased(&import.name, alias),
+                    };
+
+                    if import.type_only {
+                        module.types.insert(element);
+                    } else {
+                        module.set.insert(element);
+                    }
+                }
+                ImportKind::Default => {
+                    let module = modules.entry(&import.module).or_default();
+
+                    if import.type_only {
+                        module.default_type = Some(&import.name);
+                    } else {
+                        module.default_import = Some(&import.name);
+                    }
+                }
+                ImportKind::Wildcard => {
+                    wildcards.insert((&import.module, &import.name));
+                }
+            }
+        }
+
+        if modules.is_empty() && wildcards.is_empty() {
+            return;
+        }
+
+        for (module, name) in wildcards {
+            out.push();
+            quote_in! { *out =>
+                import * as $name from $(ref t => render_from(t, config.module_path.as_deref(), module));
+            }
+        }
+
+        for (name, module) in &mut modules {
+            // Names imported as values can also be used as types.
+            let ResolvedModule { set, types, .. } = module;
+            types.retain(|el| !set.contains(el));
+
+            if module.default_type == module.default_import {
+                module.default_type = None;
+            }
+
+            if module.default_import.is_some() || !module.set.is_empty() {
+                out.push();
+                quote_in! { *out =>
+                    import $(ref t => render_elements(t, module.default_import, &module.set)) from $(ref t => render_from(t, config.module_path.as_deref(), name));
+                };
+            }
+
+            if module.default_type.is_some() || !module.types.is_empty() {
+                out.push();
+                quote_in! { *out =>
+                    import type $(ref t => render_elements(t, module.default_type, &module.types)) from $(ref t => render_from(t, config.module_path.as_deref(), name));
+                };
+            }
+        }
+
+        out.line();
+
+        #[derive(Default)]
+        struct ResolvedModule<'a> {
+            default_import: Option<&'a ItemStr>,
+            default_type: Option<&'a ItemStr>,
+            set: BTreeSet<ImportedElement<'a>>,
+            types: BTreeSet<ImportedElement<'a>>,
+        }
+
+        #[derive(PartialEq, Eq, PartialOrd, Ord, Hash)]
+        enum ImportedElement<'a> {
+            Plain(&'a ItemStr),
+            Aliased(&'a ItemStr, &'a ItemStr),
+        }
+
+        fn render_elements(
+            tokens: &mut typescript::Tokens,
+            default: Option<&ItemStr>,
+            set: &BTreeSet<ImportedElement<'_>>,
+        ) {
+            if let Some(default) = default {
+                tokens.append(ItemStr::from(default));
+
+                if !set.is_empty() {
+                    tokens.append(",");
+                    tokens.space();
+                }
+            }
+
+            if !set.is_empty() {
+                tokens.append("{");
+
+                let mut it = set.iter().peekable();
+
+                while let Some(el) = it.next() {
+                    match *el {
+                        ImportedElement::Plain(name) => {
+                            tokens.append(name);
+                        }
+                        ImportedElement::Aliased(name, alias) => {
+                            quote_in!(*tokens => $name as $alias);
+                        }
+                    }
+
+                    if it.peek().is_some() {
+                        tokens.append(",");
+                        tokens.space();
+                    }
+                }
+
+                tokens.append("}");
+            }
+        }
+
+        fn render_from(
+            t: &mut typescript::Tokens,
+            module_path: Option<&RelativePath>,
+            module: &Module,
+        ) {
+            quote_in! { *t =>
+                $(match (module_path, module) {
+                    (_, Module::Global(from)) => $(quoted(from)),
+                    (None, Module::Path(path)) => $(quoted(path.as_str())),
+                    (Some(module_path), Module::Path(path)) => $(quoted(module_path.relative(path).as_str())),
+                })
+            }
+        }
+    }
+}
+
+/// The import of a TypeScript type `import {foo} from "module"`.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let default_vec = typescript::import("collections", "defaultVec").into_default();
+/// let all = typescript::import("collections", "all").into_wildcard();
+/// let vec = typescript::import("collections", "vec");
+/// let list = typescript::import("collections", "List").into_type_only();
+///
+/// let toks = quote! {
+///     const a: $list<number> = $default_vec();
+///     $all
+///     $vec
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "import * as all from \"collections\";",
+///         "import defaultVec, {vec} from \"collections\";",
+///         "import type {List} from \"collections\";",
+///         "",
+///         "const a: List<number> = defaultVec();",
+///         "all",
+///         "vec",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn import<M, N>(module: M, name: N) -> Import
+where
+    M: Into<Module>,
+    N: Into<ItemStr>,
+{
+    Import {
+        kind: ImportKind::Named,
+        module: module.into(),
+        name: name.into(),
+        alias: None,
+        type_only: false,
+    }
+}
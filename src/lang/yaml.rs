@@ -0,0 +1,364 @@
+//! Specialization for YAML code generation.
+//!
+//! Nesting in [quote!][crate::quote] maps directly to the indentation of
+//! YAML mappings and sequences, which defaults to two spaces.
+//!
+//! Scalars which might be misinterpreted, like `no` which is read as a
+//! boolean by YAML 1.1 parsers, should be written through [scalar()], which
+//! only leaves a value unquoted if it's read back as the same string.
+//! Multi-line strings can be written as block scalars through [block()].
+//!
+//! # Examples
+//!
+//! ```rust
+//! use genco::prelude::*;
+//!
+//! # fn main() -> genco::fmt::Result {
+//! let toks: yaml::Tokens = quote! {
+//!     name: $(yaml::scalar("build"))
+//!     on:
+//!       push:
+//!         branches: [$(yaml::scalar("main"))]
+//!     env:
+//!       DEBUG: $(yaml::scalar("no"))
+//!       VERSION: $(yaml::scalar("1.10"))
+//!     steps:
+//!       - name: test
+//!         run: $(yaml::block("cargo build\ncargo test\n"))
+//! };
+//!
+//! assert_eq!(
+//!     vec![
+//!         "name: build",
+//!         "on:",
+//!         "  push:",
+//!         "    branches: [main]",
+//!         "env:",
+//!         "  DEBUG: 'no'",
+//!         "  VERSION: '1.10'",
+//!         "steps:",
+//!         "  - name: test",
+//!         "    run: |",
+//!         "      cargo build",
+//!         "      cargo test",
+//!     ],
+//!     toks.to_file_vec()?
+//! );
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # String Quoting in YAML
+//!
+//! Strings are quoted with double quotes, which supports escape sequences
+//! for any character. [scalar()] instead picks the simplest representation
+//! of a value, which is either unquoted, single quoted where `'` is written
+//! as `''`, or double quoted if the value contains control characters like
+//! line breaks.
+//!
+//! ```rust
+//! use genco::prelude::*;
+//!
+//! # fn main() -> genco::fmt::Result {
+//! let toks: yaml::Tokens = quote!($(quoted("it's \"here\"\n")));
+//! assert_eq!("\"it's \\\"here\\\"\\n\"", toks.to_string()?);
+//!
+//! let toks: yaml::Tokens = quote!($(yaml::scalar("it's: here")));
+//! assert_eq!("'it''s: here'", toks.to_string()?);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::fmt;
+use crate::tokens::{quoted, static_literal, FormatInto, ItemStr};
+use std::fmt::Write as _;
+
+/// Tokens container specialization for YAML.
+pub type Tokens = crate::Tokens<Yaml>;
+
+impl_lang! {
+    /// Language specialization for YAML.
+    pub Yaml {
+        type Config = Config;
+        type Format = Format;
+        type Item = Any;
+
+        fn default_indentation() -> fmt::Indentation {
+            fmt::Indentation::Space(2)
+        }
+
+        fn capabilities() -> crate::lang::Capabilities {
+            // YAML only has line comments, and no imports.
+            crate::lang::Capabilities::default()
+        }
+
+        fn line_comment_prefix() -> Option<&'static str> {
+            Some("#")
+        }
+
+        fn unit_type() -> Option<&'static str> {
+            Some("null")
+        }
+
+        fn statement_terminator() -> &'static str {
+            ""
+        }
+
+        fn write_quoted(out: &mut fmt::Formatter<'_>, input: &str) -> fmt::Result {
+            // From: https://yaml.org/spec/1.2.2/#57-escaped-characters
+
+            for c in input.chars() {
+                match c {
+                    '\0' => out.write_str("\\0")?,
+                    '\u{0007}' => out.write_str("\\a")?,
+                    '\u{0008}' => out.write_str("\\b")?,
+                    '\t' => out.write_str("\\t")?,
+                    '\n' => out.write_str("\\n")?,
+                    '\u{000b}' => out.write_str("\\v")?,
+                    '\u{000c}' => out.write_str("\\f")?,
+                    '\r' => out.write_str("\\r")?,
+                    '\u{001b}' => out.write_str("\\e")?,
+                    '"' => out.write_str("\\\"")?,
+                    '\\' => out.write_str("\\\\")?,
+                    '\u{0085}' => out.write_str("\\N")?,
+                    '\u{2028}' => out.write_str("\\L")?,
+                    '\u{2029}' => out.write_str("\\P")?,
+                    c if c.is_control() => {
+                        write!(out, "\\u{:04x}", c as u32)?;
+                    }
+                    c => out.write_char(c)?,
+                }
+            }
+
+            Ok(())
+        }
+    }
+}
+
+/// Format state for YAML.
+#[derive(Debug, Default)]
+pub struct Format {}
+
+/// Configuration for YAML.
+#[derive(Debug, Default)]
+pub struct Config {}
+
+/// Words which YAML 1.1 or YAML 1.2 parsers read as booleans or null.
+const RESERVED: &[&str] = &[
+    "~", "null", "Null", "NULL", "true", "True", "TRUE", "false", "False", "FALSE", "y", "Y",
+    "yes", "Yes", "YES", "n", "N", "no", "No", "NO", "on", "On", "ON", "off", "Off", "OFF",
+];
+
+/// Test if the value can be written without quotes and still be read back as
+/// the same string.
+fn is_plain_safe(value: &str) -> bool {
+    let (first, last) = match (value.chars().next(), value.chars().last()) {
+        (Some(first), Some(last)) => (first, last),
+        _ => return false,
+    };
+
+    if RESERVED.contains(&value) {
+        return false;
+    }
+
+    // Indicators, and characters which might start a number, timestamp,
+    // `.inf` or `.nan`.
+    if "-?:,[]{}#&*!|>'\"%@`+.<=".contains(first) || first.is_ascii_digit() {
+        return false;
+    }
+
+    if first.is_whitespace() || last.is_whitespace() || last == ':' {
+        return false;
+    }
+
+    if value.contains(": ") || value.contains(" #") {
+        return false;
+    }
+
+    !value
+        .chars()
+        .any(|c| c.is_control() || "\u{feff}\u{2028}\u{2029}".contains(c))
+}
+
+/// A scalar value, which is quoted only if needed.
+///
+/// Created through the [scalar()] function.
+#[derive(Debug, Clone)]
+pub struct Scalar {
+    value: ItemStr,
+}
+
+impl FormatInto<Yaml> for Scalar {
+    fn format_into(self, tokens: &mut Tokens) {
+        if is_plain_safe(&self.value) {
+            tokens.append(self.value);
+            return;
+        }
+
+        if self.value.chars().any(char::is_control) {
+            tokens.append(quoted(self.value));
+            return;
+        }
+
+        let mut s = String::with_capacity(self.value.len() + 2);
+        s.push('\'');
+
+        for c in self.value.chars() {
+            if c == '\'' {
+                s.push('\'');
+            }
+
+            s.push(c);
+        }
+
+        s.push('\'');
+        tokens.append(s);
+    }
+}
+
+/// A block scalar for multi-line strings, like `|`.
+///
+/// Created through the [block()] function.
+#[derive(Debug, Clone)]
+pub struct Block {
+    text: ItemStr,
+}
+
+impl Block {
+    /// Test if the text can be represented as a block scalar.
+    ///
+    /// A leading space on the first line would need an explicit indentation
+    /// indicator, consecutive empty lines can't be represented in a token
+    /// stream and other control characters can't be written unescaped.
+    fn is_representable(&self) -> bool {
+        let text = self.text.strip_suffix('\n').unwrap_or(&self.text);
+
+        !(text.is_empty()
+            || text.ends_with('\n')
+            || text.starts_with(' ')
+            || text.contains("\n\n\n")
+            || text.contains('\r')
+            || text
+                .chars()
+                .any(|c| c.is_control() && c != '\n' && c != '\t'))
+    }
+}
+
+impl FormatInto<Yaml> for Block {
+    fn format_into(self, tokens: &mut Tokens) {
+        if !self.is_representable() {
+            tokens.append(quoted(self.text));
+            return;
+        }
+
+        let (text, indicator) = match self.text.strip_suffix('\n') {
+            Some(text) => (text, "|"),
+            None => (&*self.text, "|-"),
+        };
+
+        tokens.append(static_literal(indicator));
+        tokens.indent();
+
+        for line in text.split('\n') {
+            if line.is_empty() {
+                tokens.line();
+            } else {
+                tokens.push();
+                tokens.append(line.to_owned());
+            }
+        }
+
+        tokens.unindent();
+    }
+}
+
+/// A scalar value, which is written without quotes if it's unambiguous.
+///
+/// Values which would be read as something other than a string, like
+/// booleans (`yes`, `no`, `on`, `off`), null (`~`, `null`) or numbers, or
+/// values which contain characters with a special meaning in YAML are
+/// quoted. Single quotes are preferred, and double quotes are used if the
+/// value contains control characters which need to be escaped.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let toks: yaml::Tokens = quote! {
+///     - $(yaml::scalar("hello world"))
+///     - $(yaml::scalar("yes"))
+///     - $(yaml::scalar("8080"))
+///     - $(yaml::scalar("*.rs"))
+///     - $(yaml::scalar("key: value"))
+///     - $(yaml::scalar(""))
+///     - $(yaml::scalar("line\nbreak"))
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "- hello world",
+///         "- 'yes'",
+///         "- '8080'",
+///         "- '*.rs'",
+///         "- 'key: value'",
+///         "- ''",
+///         "- \"line\\nbreak\"",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn scalar<V>(value: V) -> Scalar
+where
+    V: Into<ItemStr>,
+{
+    Scalar {
+        value: value.into(),
+    }
+}
+
+/// A literal block scalar for multi-line strings, like `|`.
+///
+/// The lines of the text are indented one level deeper than the current
+/// line. Note that this isn't deep enough for a compact sequence entry like
+/// `- run: |`, where the key should be put on a line of its own. If the text doesn't end with a line break, the block is written as
+/// `|-` to strip the final line break.
+///
+/// Text which can't be represented as a block scalar falls back to being
+/// double quoted. This is the case for text where the first line starts
+/// with a space, which has more than one trailing line break or more than
+/// one consecutive empty line, or which contains control characters other
+/// than tabs and line feeds.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let toks: yaml::Tokens = quote! {
+///     script: $(yaml::block("set -e\n\nmake\n"))
+///     message: $(yaml::block("no trailing newline"))
+///     indented: $(yaml::block("  leading space"))
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "script: |",
+///         "  set -e",
+///         "",
+///         "  make",
+///         "message: |-",
+///         "  no trailing newline",
+///         "indented: \"  leading space\"",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn block<T>(text: T) -> Block
+where
+    T: Into<ItemStr>,
+{
+    Block { text: text.into() }
+}
@@ -196,7 +196,9 @@
 /// Expressions can be interpolated with `$(<expr>)`.
 ///
 /// > *Note:* The `$` punctuation itself can be escaped by repeating it twice.
-/// > So `$$` would produce a single `$` token.
+/// > So `$$` would produce a single `$` token. Other punctuation like `#` has
+/// > no special meaning and is emitted as-is, so Rust attributes like
+/// > `#[derive(Debug)]` or C directives like `#include` don't need escaping.
 ///
 /// ```
 /// use genco::prelude::*;
@@ -388,6 +390,8 @@
 ///   backticks).
 /// * Dart - With [interpolated strings] like `"Hello $a"` or `"Hello ${a +
 ///   b}"`.
+/// * Python - With [f-strings] like `f"Hello {a}"`.
+/// * Swift - With [string interpolation] like `"Hello \(a)"`.
 ///
 /// The [quote!] macro supports this through `$[str](<content>)`. This will
 /// produce literal strings with the appropriate language-specific quoting and
@@ -432,7 +436,9 @@
 /// <br>
 ///
 /// [template literals]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Template_literals
+/// [f-strings]: https://docs.python.org/3/reference/lexical_analysis.html#f-strings
 /// [interpolated strings]: https://medium.com/run-dart/dart-dartlang-introduction-string-interpolation-8ed99174119a
+/// [string interpolation]: https://docs.swift.org/swift-book/documentation/the-swift-programming-language/stringsandcharacters#String-Interpolation
 ///
 /// # Control Flow
 ///
@@ -496,6 +502,22 @@
 /// # Ok::<_, genco::fmt::Error>(())
 /// ```
 ///
+/// [Escape sequences](#escape-sequences) can be used to join elements
+/// with line breaks, like `join ($['\r'])` for statement lists.
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let fields = ["a", "b"];
+///
+/// let tokens: rust::Tokens = quote! {
+///     $(for f in fields join ($['\r']) => self.$f = $f;)
+/// };
+///
+/// assert_eq!(vec!["self.a = a;", "self.b = b;"], tokens.to_vec()?);
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+///
 /// <br>
 ///
 /// # Conditionals
@@ -551,6 +573,28 @@
 ///
 /// <br>
 ///
+/// Spaces in a token stream are merged, so a conditional between two tokens
+/// which doesn't produce anything doesn't leave a double space behind. A line
+/// which starts with conditionals only gets spacing after them once one of
+/// them produced something. This makes conditionals convenient for optional
+/// modifiers:
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// fn function(public: bool, is_async: bool) -> rust::Tokens {
+///     quote!($(if public => pub) $(if is_async => async) fn run() {})
+/// }
+///
+/// assert_eq!("pub async fn run() {}", function(true, true).to_string()?);
+/// assert_eq!("pub fn run() {}", function(true, false).to_string()?);
+/// assert_eq!("async fn run() {}", function(false, true).to_string()?);
+/// assert_eq!("fn run() {}", function(false, false).to_string()?);
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+///
+/// <br>
+///
 /// # Match Statements
 ///
 /// You can specify a match expression using `$(match <expr> { [<pattern> =>
@@ -143,17 +143,33 @@ macro_rules! impl_lang {
                     Self::$ty(lang)
                 }
             }
+
+            impl<'a> ::core::convert::TryFrom<&'a Any> for &'a $ty {
+                type Error = &'a Any;
+
+                #[allow(irrefutable_let_patterns)]
+                fn try_from(any: &'a Any) -> ::core::result::Result<Self, Self::Error> {
+                    if let Any::$ty(lang) = any {
+                        Ok(lang)
+                    } else {
+                        Err(any)
+                    }
+                }
+            }
         )*
 
         impl $crate::lang::LangItem<$lang> for Any {
+            #[allow(unused_variables)]
             fn format(
                 &self,
                 out: &mut $crate::fmt::Formatter<'_>,
                 config: &<$lang as $crate::lang::Lang>::Config,
                 format: &<$lang as $crate::lang::Lang>::Format,
             ) -> $crate::fmt::Result {
-                match self {
-                    $(Self::$ty(lang) => lang.format(out, config, format),)*
+                // Dereferenced so that languages without any items can be
+                // declared, in which case the enum is empty.
+                match *self {
+                    $(Self::$ty(ref lang) => lang.format(out, config, format),)*
                 }
             }
         }
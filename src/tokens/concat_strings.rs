@@ -0,0 +1,77 @@
+use crate::lang::Lang;
+use crate::tokens::{quoted, FormatInto, ItemStr, Tokens};
+
+/// Function to render a sequence of strings as a single string literal.
+///
+/// In languages which concatenate adjacent string literals, like C and
+/// Python, each string is kept as a separate literal on the same line.
+/// Otherwise the strings are merged into one properly escaped literal. An
+/// empty sequence is rendered as an empty string literal.
+///
+/// See [Lang::concatenates_adjacent_strings].
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+/// use genco::tokens::concat_strings;
+///
+/// let parts = ["Hello ", "\"World\""];
+///
+/// let c: c::Tokens = quote!(puts($(concat_strings(parts))););
+/// assert_eq!("puts(\"Hello \" \"\\\"World\\\"\");", c.to_string()?);
+///
+/// let java: java::Tokens = quote!(System.out.println($(concat_strings(parts))););
+/// assert_eq!("System.out.println(\"Hello \\\"World\\\"\");", java.to_string()?);
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn concat_strings<I>(strings: I) -> ConcatStrings<I>
+where
+    I: IntoIterator,
+    I::Item: Into<ItemStr>,
+{
+    ConcatStrings { strings }
+}
+
+/// Struct containing a sequence of strings to render as a single literal.
+///
+/// This is constructed with the [concat_strings()] function.
+#[derive(Clone, Copy, Debug)]
+pub struct ConcatStrings<I> {
+    strings: I,
+}
+
+impl<I, L> FormatInto<L> for ConcatStrings<I>
+where
+    L: Lang,
+    I: IntoIterator,
+    I::Item: Into<ItemStr>,
+{
+    fn format_into(self, t: &mut Tokens<L>) {
+        if L::concatenates_adjacent_strings() {
+            let mut empty = true;
+
+            for (n, s) in self.strings.into_iter().enumerate() {
+                if n > 0 {
+                    t.space();
+                }
+
+                t.append(quoted(s.into()));
+                empty = false;
+            }
+
+            // No strings still make for an empty string literal.
+            if empty {
+                t.append(quoted(""));
+            }
+        } else {
+            let mut buf = String::new();
+
+            for s in self.strings {
+                buf.push_str(&s.into());
+            }
+
+            t.append(quoted(buf));
+        }
+    }
+}
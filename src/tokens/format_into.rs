@@ -33,6 +33,55 @@ use std::rc::Rc;
 /// }
 /// # Ok::<_, genco::fmt::Error>(())
 /// ```
+///
+/// # Implementing for custom types
+///
+/// [FormatInto] is the extension point for interpolation, and is available
+/// in the [prelude][crate::prelude]. Implementing it for your own types,
+/// either for a specific language or for any language, makes them work with
+/// [quote!] and [quote_in!] just like the built-in types.
+///
+/// Note that [register()] serves a different purpose. It adds language items
+/// like imports to a token stream without rendering them.
+///
+/// [quote_in!]: macro.quote_in.html
+/// [register()]: crate::tokens::register()
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// enum Literal {
+///     Int(i64),
+///     Float(f64),
+///     Text(&'static str),
+/// }
+///
+/// impl FormatInto<Rust> for &Literal {
+///     fn format_into(self, tokens: &mut rust::Tokens) {
+///         match self {
+///             Literal::Int(n) => quote_in!(*tokens => $(n.to_string())i64),
+///             Literal::Float(n) => quote_in!(*tokens => $(format!("{n:?}"))f64),
+///             Literal::Text(s) => quote_in!(*tokens => $(quoted(*s))),
+///         }
+///     }
+/// }
+///
+/// let values = [Literal::Int(42), Literal::Float(1.5), Literal::Text("hello")];
+///
+/// let tokens: rust::Tokens = quote! {
+///     $(for (n, value) in values.iter().enumerate() join ($['\r']) => let v$n = $value;)
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "let v0 = 42i64;",
+///         "let v1 = 1.5f64;",
+///         "let v2 = \"hello\";",
+///     ],
+///     tokens.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
 pub trait FormatInto<L>
 where
     L: Lang,
@@ -265,6 +314,17 @@ where
 /// Implementation for [Arguments] which allows for arbitrary and efficient
 /// literal formatting.
 ///
+/// The formatted arguments are added as a single literal token, which is
+/// written as it is. It is not a quoted string, so nothing is escaped. Use
+/// [quoted()] around [format!] if the result should be a string literal in
+/// the target language.
+///
+/// Since the token stream outlives the arguments, they are formatted once
+/// into a string owned by the stream. Arguments without any interpolation,
+/// like `format_args!("static")`, are stored without allocating.
+///
+/// [quoted()]: crate::tokens::quoted()
+///
 /// # Examples
 ///
 /// ```
@@ -274,6 +334,11 @@ where
 /// let result: Tokens = quote!($(format_args!("Hello {name}")));
 ///
 /// assert_eq!("Hello John", result.to_string()?);
+///
+/// let (a, b) = ("get", "user");
+/// let result: js::Tokens = quote!($(format_args!("{a}_{b}"))($(format_args!("\"{b}\""))));
+///
+/// assert_eq!("get_user(\"user\")", result.to_string()?);
 /// # Ok::<_, genco::fmt::Error>(())
 /// ```
 impl<L> FormatInto<L> for Arguments<'_>
@@ -281,17 +346,26 @@ where
     L: Lang,
 {
     fn format_into(self, tokens: &mut Tokens<L>) {
-        tokens.item(Item::Literal(ItemStr::from(self.to_string())));
+        let s = match self.as_str() {
+            Some(s) => ItemStr::Static(s),
+            None => ItemStr::from(self.to_string()),
+        };
+
+        tokens.item(Item::Literal(s));
     }
 }
 
 /// Optional items are formatted if they are present.
 ///
+/// Nothing is emitted for `None`, but the surrounding tokens are still
+/// emitted as written. Spacing around a missing item collapses like any
+/// other whitespace, but separators like commas don't, so an optional item
+/// in a separated list should be filtered out before joining instead.
+///
 /// # Examples
 ///
 /// ```
 /// use genco::prelude::*;
-/// use std::rc::Rc;
 ///
 /// let foo = Some("foo");
 /// let bar = Some("bar");
@@ -300,6 +374,15 @@ where
 /// let result: Tokens = quote!($foo $bar baz $biz);
 ///
 /// assert_eq!("foo bar baz", result.to_string()?);
+///
+/// let timeout = None::<&str>;
+///
+/// let result: Tokens = quote!(call(a, $timeout));
+/// assert_eq!("call(a, )", result.to_string()?);
+///
+/// let args = vec![Some("a"), timeout];
+/// let result: Tokens = quote!(call($(for arg in args.into_iter().flatten() join (, ) => $arg)));
+/// assert_eq!("call(a)", result.to_string()?);
 /// # Ok::<_, genco::fmt::Error>(())
 /// ```
 impl<L, T> FormatInto<L> for Option<T>
@@ -1,10 +1,61 @@
 //! A single element
 
 use crate::lang::Lang;
-use crate::tokens::{FormatInto, ItemStr, Tokens};
+use crate::tokens::{Deferred, FormatInto, ItemStr, SourceLocation, Tokens};
 
 /// A single item in a stream of tokens.
+///
+/// Items can be used to construct a [Tokens] stream directly through its
+/// [FromIterator][std::iter::FromIterator] and [Extend] implementations, or
+/// with [Tokens::append]. This gives full control over the stream, which is
+/// useful for building custom combinators or adapters.
+///
+/// New kinds of items might be added in the future, so matching on an item
+/// outside of genco requires a wildcard arm.
+///
+/// # Invariants
+///
+/// The formatter relies on the following. Streams which violate them either
+/// fail to format or produce malformed output:
+///
+/// * Every [OpenQuote][Item::OpenQuote] is matched by a
+///   [CloseQuote][Item::CloseQuote].
+/// * [OpenEval][Item::OpenEval] and [CloseEval][Item::CloseEval] only occur
+///   as a matched pair inside of a quoted string which was opened with
+///   `OpenQuote(true)`.
+/// * [OpenRegion][Item::OpenRegion] and [CloseRegion][Item::CloseRegion] do
+///   not occur inside of quoted strings.
+///
+/// The position stored in [Lang][Item::Lang] and
+/// [Register][Item::Register] is used to find imports quickly, and is
+/// recomputed whenever the item is added to a stream. So it's fine to use
+/// any value when constructing them.
+///
+/// Whitespace items are normalized as they are added, so consecutive
+/// [Space][Item::Space] items collapse into one and whitespace is flushed on
+/// indentation changes.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+/// use genco::tokens::Item;
+///
+/// let tokens: rust::Tokens = vec![
+///     Item::literal("fn foo() {"),
+///     Item::indent(),
+///     Item::literal("bar()"),
+///     Item::unindent(),
+///     Item::literal("}"),
+/// ]
+/// .into_iter()
+/// .collect();
+///
+/// assert_eq!(vec!["fn foo() {", "    bar()", "}"], tokens.to_vec()?);
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
 pub enum Item<L>
 where
     L: Lang,
@@ -21,11 +72,23 @@ where
     Push,
     /// Push a line. Will be flushed on indentation changes.
     Line,
+    /// An empty line which is always written, unlike [Line][Item::Line] which
+    /// is merged with adjacent lines.
+    ///
+    /// See [Tokens::append_verbatim].
+    BlankLine,
     /// Space between language items. Typically a single space.
     ///
     /// Multiple spacings in sequence are collapsed into one.
     /// A spacing does nothing if at the beginning of a line.
     Space,
+    /// A space which is turned into a new line if the line would otherwise
+    /// exceed the configured [max width].
+    ///
+    /// It takes the place of any adjacent [Space][Item::Space].
+    ///
+    /// [max width]: crate::fmt::Config::with_max_width
+    SoftBreak,
     /// Manage indentation.
     ///
     /// An indentation of 0 has no effect.
@@ -40,10 +103,70 @@ where
     OpenQuote(bool),
     /// Close the current quote.
     CloseQuote,
+    /// A string which is quoted as a raw string if the language supports it.
+    ///
+    /// See [raw_quoted][crate::tokens::raw_quoted()].
+    RawQuoted(ItemStr),
     /// Switch on evaluation. Only valid during string handling.
     OpenEval,
     /// Close evaluation.
     CloseEval,
+    /// Open an editor region with the given label.
+    ///
+    /// The marker is rendered on its own line as a line comment, using the
+    /// syntax configured with [fmt::Config::with_region_style].
+    ///
+    /// [fmt::Config::with_region_style]: crate::fmt::Config::with_region_style
+    OpenRegion(ItemStr),
+    /// Close the current editor region.
+    CloseRegion,
+    /// Start a comment.
+    ///
+    /// Everything up until the matching [Item::CloseComment] is dropped when
+    /// the output is minified, since a line comment would otherwise swallow
+    /// the rest of the line. See
+    /// [line_comment][crate::tokens::line_comment()].
+    OpenComment,
+    /// Close the current comment.
+    CloseComment,
+    /// Start a region of tokens produced by the given source location.
+    ///
+    /// See [Tokens::append_with_source].
+    OpenSource(Box<SourceLocation>),
+    /// Close the current source location region.
+    CloseSource,
+    /// Tokens which are produced at format time using the configuration of
+    /// the language.
+    ///
+    /// See [with_config][crate::tokens::with_config()].
+    Deferred(Deferred<L>),
+}
+
+impl<L> Item<L>
+where
+    L: Lang,
+{
+    /// Construct a literal item.
+    pub fn literal<S>(literal: S) -> Self
+    where
+        S: Into<ItemStr>,
+    {
+        Self::Literal(literal.into())
+    }
+
+    /// Construct an item which increases indentation by one level.
+    ///
+    /// This is the same as [Tokens::indent].
+    pub fn indent() -> Self {
+        Self::Indentation(1)
+    }
+
+    /// Construct an item which decreases indentation by one level.
+    ///
+    /// This is the same as [Tokens::unindent].
+    pub fn unindent() -> Self {
+        Self::Indentation(-1)
+    }
 }
 
 /// Formatting an item is the same as simply adding that item to the token
@@ -0,0 +1,71 @@
+use crate::lang::Lang;
+use crate::tokens::{FormatInto, Tokens};
+
+/// Function to interleave a separator between each element of an iterator.
+///
+/// The separator is never emitted before the first or after the last
+/// element. This is the same as a [joining loop] in the [quote!] macro, for
+/// lists which are built dynamically in Rust code.
+///
+/// [joining loop]: https://docs.rs/genco/0/genco/macro.quote.html#joining-loops
+/// [quote!]: crate::quote!
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+/// use genco::tokens::join;
+///
+/// let args = ["a", "b", "c"];
+///
+/// let tokens: rust::Tokens = quote!(call($(join(args, quote!(,$[' '])))));
+/// assert_eq!("call(a, b, c)", tokens.to_string()?);
+///
+/// let empty: rust::Tokens = quote!(call($(join(Vec::<&str>::new(), ","))));
+/// assert_eq!("call()", empty.to_string()?);
+///
+/// let mut params = Vec::new();
+///
+/// for (n, ty) in vec!["u32", "String"].into_iter().enumerate() {
+///     params.push(quote!(arg$n: $ty));
+/// }
+///
+/// let tokens: rust::Tokens = quote!(fn f($(join(params, ", "))) {});
+/// assert_eq!("fn f(arg0: u32, arg1: String) {}", tokens.to_string()?);
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn join<I, S>(iter: I, separator: S) -> Join<I, S>
+where
+    I: IntoIterator,
+{
+    Join { iter, separator }
+}
+
+/// Elements interleaved with a separator.
+///
+/// This is constructed with the [join()] function.
+#[derive(Clone, Copy, Debug)]
+pub struct Join<I, S> {
+    iter: I,
+    separator: S,
+}
+
+impl<I, S, L> FormatInto<L> for Join<I, S>
+where
+    L: Lang,
+    I: IntoIterator,
+    I::Item: FormatInto<L>,
+    S: Clone + FormatInto<L>,
+{
+    fn format_into(self, tokens: &mut Tokens<L>) {
+        let mut it = self.iter.into_iter().peekable();
+
+        while let Some(item) = it.next() {
+            tokens.append(item);
+
+            if it.peek().is_some() {
+                tokens.append(self.separator.clone());
+            }
+        }
+    }
+}
@@ -0,0 +1,101 @@
+use crate::lang::Lang;
+use crate::tokens::{static_literal, FormatInto, Item, ItemStr, Tokens};
+
+/// Function to render the given lines as line comments, like `// foo`.
+///
+/// The comment uses the prefix provided by [Lang::line_comment_prefix], and
+/// every line is put on a line of its own at the current indentation. Each
+/// item is also split on line breaks, so multi-line strings are commented
+/// line by line. Empty lines are written as the prefix on its own.
+///
+/// Languages without line comments write each line as a block comment using
+/// [Lang::block_comment_delimiters] instead, and if the language has neither
+/// the comment is left out. The comment is also left out when the output is
+/// [minified][crate::fmt::Config::with_minify].
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+/// use genco::tokens::line_comment;
+///
+/// let tokens: rust::Tokens = quote! {
+///     fn main() {
+///         $(line_comment(["first", "second\n\nthird"]))
+///         let x = 5;
+///     }
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "fn main() {",
+///         "    // first",
+///         "    // second",
+///         "    //",
+///         "    // third",
+///         "    let x = 5;",
+///         "}",
+///     ],
+///     tokens.to_file_vec()?
+/// );
+///
+/// let tokens: python::Tokens = quote!($(line_comment(["generated", "do not edit"])));
+/// assert_eq!("# generated\n# do not edit", tokens.to_string()?);
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn line_comment<T>(lines: T) -> LineComment<T>
+where
+    T: IntoIterator,
+    T::Item: Into<ItemStr>,
+{
+    LineComment { lines }
+}
+
+/// Lines rendered as line comments.
+///
+/// This is constructed with the [line_comment()] function.
+#[derive(Clone, Debug)]
+pub struct LineComment<T> {
+    lines: T,
+}
+
+impl<T, L> FormatInto<L> for LineComment<T>
+where
+    L: Lang,
+    T: IntoIterator,
+    T::Item: Into<ItemStr>,
+{
+    fn format_into(self, tokens: &mut Tokens<L>) {
+        let (open, close) = match (L::line_comment_prefix(), L::block_comment_delimiters()) {
+            (Some(prefix), _) => (prefix, None),
+            (None, Some((open, close))) => (open, Some(close)),
+            (None, None) => return,
+        };
+
+        tokens.item(Item::OpenComment);
+
+        for text in self.lines {
+            let text = text.into();
+
+            for line in text.lines() {
+                tokens.push();
+                tokens.append(static_literal(open));
+
+                let line = line.trim_end();
+
+                if !line.is_empty() {
+                    tokens.space();
+                    tokens.append(line.to_owned());
+                }
+
+                if let Some(close) = close {
+                    tokens.space();
+                    tokens.append(static_literal(close));
+                }
+            }
+        }
+
+        tokens.item(Item::CloseComment);
+        tokens.push();
+    }
+}
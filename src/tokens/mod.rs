@@ -56,26 +56,54 @@
 //! # }
 //! ```
 
+mod concat_strings;
 mod display;
 mod format_into;
 mod from_fn;
 mod internal;
 mod item;
 mod item_str;
+mod join;
+mod line_comment;
+mod nullable;
 mod quoted;
+mod raw_quoted;
+mod region;
 mod register;
+#[cfg(feature = "serde")]
+mod serialize;
+mod soft_break;
+mod source_location;
+mod spaced;
+mod statement;
 mod static_literal;
 mod tokens;
+mod topo_order;
+mod trailing_comment;
+mod with_config;
 
+pub use self::concat_strings::{concat_strings, ConcatStrings};
 pub use self::display::{display, Display};
 pub use self::format_into::FormatInto;
 pub use self::from_fn::{from_fn, FromFn};
 pub use self::item::Item;
 pub use self::item_str::ItemStr;
+pub use self::join::{join, Join};
+pub use self::line_comment::{line_comment, LineComment};
+pub use self::nullable::{nullable, Nullable};
 pub use self::quoted::{quoted, QuotedFn};
+pub use self::raw_quoted::{raw_quoted, RawQuotedFn};
+pub use self::region::{region, Region};
 pub use self::register::{register, Register, RegisterFn};
+pub use self::soft_break::{soft_break, SoftBreak};
+pub use self::source_location::SourceLocation;
+pub use self::spaced::{leading_space, spaced, trailing_space, Spaced};
+pub use self::statement::{statement, Statement};
 pub use self::static_literal::static_literal;
-pub use self::tokens::Tokens;
+pub use self::tokens::{DisplayTokens, Tokens, WalkImports};
+pub use self::topo_order::{topo_order, Declaration, TopoOrder};
+pub use self::trailing_comment::{trailing_comment, TrailingComment};
+pub use self::with_config::{with_config, Deferred, WithConfig};
 
 #[doc(hidden)]
 pub use self::internal::__lang_item;
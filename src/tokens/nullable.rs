@@ -0,0 +1,54 @@
+use crate::lang::Lang;
+use crate::tokens::{static_literal, FormatInto};
+use crate::Tokens;
+
+/// Function to mark a type as nullable with a `?` suffix, like `Foo?`.
+///
+/// This is the syntax used by C#, Dart, Kotlin and Swift, which each
+/// re-export it as a helper of their own. Any imports of the wrapped type
+/// are emitted as usual.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+/// use genco::tokens::nullable;
+///
+/// let list = kotlin::import("kotlin.collections", "List");
+///
+/// let toks: kotlin::Tokens = quote! {
+///     val a: $(nullable("String")) = null
+///     val b: $(nullable(quote!($list<$(nullable("Int"))>))) = null
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "val a: String? = null",
+///         "val b: List<Int?>? = null",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn nullable<T>(inner: T) -> Nullable<T> {
+    Nullable { inner }
+}
+
+/// A nullable type, like `Foo?`.
+///
+/// Created using the [nullable()] function.
+#[derive(Debug, Clone, Copy)]
+pub struct Nullable<T> {
+    inner: T,
+}
+
+impl<L, T> FormatInto<L> for Nullable<T>
+where
+    L: Lang,
+    T: FormatInto<L>,
+{
+    fn format_into(self, tokens: &mut Tokens<L>) {
+        tokens.append(self.inner);
+        tokens.append(static_literal("?"));
+    }
+}
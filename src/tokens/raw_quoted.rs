@@ -0,0 +1,59 @@
+use crate::lang::Lang;
+use crate::tokens::{FormatInto, Item, ItemStr, Tokens};
+
+/// Function to provide raw string quoting.
+///
+/// The string is written as a raw string literal in languages which support
+/// them, in which case escape sequences are not processed. This is
+/// convenient for things like paths and regular expressions.
+///
+/// If the language doesn't have raw strings, or the string can't be
+/// represented as one, this falls back to regular [quoted][super::quoted()]
+/// strings.
+///
+/// See [Lang::raw_string].
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+/// use genco::tokens::raw_quoted;
+///
+/// let go: go::Tokens = quote!($(raw_quoted("C:\\Windows")));
+/// assert_eq!("`C:\\Windows`", go.to_string()?);
+///
+/// let csharp: csharp::Tokens = quote!($(raw_quoted("C:\\\"Windows\"")));
+/// assert_eq!("@\"C:\\\"\"Windows\"\"\"", csharp.to_string()?);
+///
+/// let python: python::Tokens = quote!($(raw_quoted("^\\d+$")));
+/// assert_eq!("r\"^\\d+$\"", python.to_string()?);
+///
+/// let java: java::Tokens = quote!($(raw_quoted("C:\\Windows")));
+/// assert_eq!("\"C:\\\\Windows\"", java.to_string()?);
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn raw_quoted<S>(input: S) -> RawQuotedFn
+where
+    S: Into<ItemStr>,
+{
+    RawQuotedFn {
+        input: input.into(),
+    }
+}
+
+/// Struct containing a string that is raw quoted.
+///
+/// This is constructed with the [raw_quoted()] function.
+#[derive(Clone, Debug)]
+pub struct RawQuotedFn {
+    input: ItemStr,
+}
+
+impl<L> FormatInto<L> for RawQuotedFn
+where
+    L: Lang,
+{
+    fn format_into(self, t: &mut Tokens<L>) {
+        t.item(Item::RawQuoted(self.input));
+    }
+}
@@ -0,0 +1,73 @@
+use crate::lang::Lang;
+use crate::tokens::{FormatInto, Item, ItemStr, Tokens};
+
+/// Wrap the given body in editor region markers, allowing sections of the
+/// generated code to be folded by editors which understand them.
+///
+/// The markers are rendered on their own lines as line comments, using the
+/// prefix provided by [Lang::line_comment_prefix], or as block comments in
+/// languages without line comments. The syntax of the markers is configured
+/// through [fmt::Config::with_region_style]. The body is indented normally
+/// between the markers.
+///
+/// [fmt::Config::with_region_style]: crate::fmt::Config::with_region_style
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+/// use genco::tokens::region;
+///
+/// let tokens: python::Tokens = quote! {
+///     def foo():
+///         $(region("Generated", quote! {
+///             a = 1
+///             b = 2
+///         }))
+///         return a + b
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "def foo():",
+///         "    # #region Generated",
+///         "    a = 1",
+///         "    b = 2",
+///         "    # #endregion",
+///         "    return a + b",
+///     ],
+///     tokens.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn region<L, T>(label: impl Into<ItemStr>, body: T) -> Region<T>
+where
+    L: Lang,
+    T: FormatInto<L>,
+{
+    Region {
+        label: label.into(),
+        body,
+    }
+}
+
+/// A body wrapped in editor region markers.
+///
+/// This is constructed with the [region()] function.
+#[derive(Debug, Clone)]
+pub struct Region<T> {
+    label: ItemStr,
+    body: T,
+}
+
+impl<L, T> FormatInto<L> for Region<T>
+where
+    L: Lang,
+    T: FormatInto<L>,
+{
+    fn format_into(self, tokens: &mut Tokens<L>) {
+        tokens.item(Item::OpenRegion(self.label));
+        self.body.format_into(tokens);
+        tokens.item(Item::CloseRegion);
+    }
+}
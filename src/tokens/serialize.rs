@@ -0,0 +1,139 @@
+//! Optional [serde] support for token streams.
+
+use crate::lang::Lang;
+use crate::tokens::{Item, ItemStr, SourceLocation, Tokens};
+use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Error as _, Serialize, SerializeSeq, Serializer};
+
+/// The serialized representation of an item.
+///
+/// Language items are opaque, so they are not represented here.
+#[derive(serde::Serialize, serde::Deserialize)]
+enum Repr<S> {
+    Literal(S),
+    Push,
+    Line,
+    BlankLine,
+    Space,
+    SoftBreak,
+    Indentation(i16),
+    OpenQuote(bool),
+    CloseQuote,
+    RawQuoted(S),
+    OpenEval,
+    CloseEval,
+    OpenRegion(S),
+    CloseRegion,
+    OpenComment,
+    CloseComment,
+    OpenSource { file: S, line: usize },
+    CloseSource,
+}
+
+/// Serialize a token stream as a sequence of items.
+///
+/// This is available with the `serde` feature, and allows token streams to
+/// be cached across runs of a generator. The representation doesn't depend
+/// on the language, so literals, whitespace, indentation, quoted strings,
+/// regions, comments and source locations are all preserved, and a stream which is
+/// deserialized formats exactly like the original.
+///
+/// Serialization fails if the stream contains language items, like imports,
+/// or deferred items created through [with_config()]. Language items are
+/// defined by each language and can carry arbitrary state, and deferred
+/// items are closures, so neither has a representation which can be read
+/// back. Generators which want to cache output that uses imports should
+/// either cache the formatted output, or cache the parts of a file which
+/// don't reference any imports and assemble them with the rest of the file
+/// after they've been deserialized.
+///
+/// [with_config()]: crate::tokens::with_config()
+impl<L> Serialize for Tokens<L>
+where
+    L: Lang,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(None)?;
+
+        for item in self.iter() {
+            let repr = match item {
+                Item::Literal(s) => Repr::Literal(&**s),
+                Item::Push => Repr::Push,
+                Item::Line => Repr::Line,
+                Item::BlankLine => Repr::BlankLine,
+                Item::Space => Repr::Space,
+                Item::SoftBreak => Repr::SoftBreak,
+                Item::Indentation(n) => Repr::Indentation(*n),
+                Item::OpenQuote(has_eval) => Repr::OpenQuote(*has_eval),
+                Item::CloseQuote => Repr::CloseQuote,
+                Item::RawQuoted(s) => Repr::RawQuoted(&**s),
+                Item::OpenEval => Repr::OpenEval,
+                Item::CloseEval => Repr::CloseEval,
+                Item::OpenRegion(name) => Repr::OpenRegion(&**name),
+                Item::CloseRegion => Repr::CloseRegion,
+                Item::OpenComment => Repr::OpenComment,
+                Item::CloseComment => Repr::CloseComment,
+                Item::OpenSource(loc) => Repr::OpenSource {
+                    file: loc.file(),
+                    line: loc.line(),
+                },
+                Item::CloseSource => Repr::CloseSource,
+                Item::Lang(..) | Item::Register(..) => {
+                    return Err(S::Error::custom("language items can't be serialized"));
+                }
+                Item::Deferred(..) => {
+                    return Err(S::Error::custom("deferred items can't be serialized"));
+                }
+            };
+
+            seq.serialize_element(&repr)?;
+        }
+
+        seq.end()
+    }
+}
+
+/// Deserialize a token stream previously serialized through its [Serialize]
+/// implementation.
+impl<'de, L> Deserialize<'de> for Tokens<L>
+where
+    L: Lang,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let items = Vec::<Repr<String>>::deserialize(deserializer)?;
+        let mut tokens = Tokens::with_capacity(items.len());
+
+        for repr in items {
+            tokens.item(match repr {
+                Repr::Literal(s) => Item::Literal(ItemStr::from(s)),
+                Repr::Push => Item::Push,
+                Repr::Line => Item::Line,
+                Repr::BlankLine => Item::BlankLine,
+                Repr::Space => Item::Space,
+                Repr::SoftBreak => Item::SoftBreak,
+                Repr::Indentation(n) => Item::Indentation(n),
+                Repr::OpenQuote(has_eval) => Item::OpenQuote(has_eval),
+                Repr::CloseQuote => Item::CloseQuote,
+                Repr::RawQuoted(s) => Item::RawQuoted(ItemStr::from(s)),
+                Repr::OpenEval => Item::OpenEval,
+                Repr::CloseEval => Item::CloseEval,
+                Repr::OpenRegion(name) => Item::OpenRegion(ItemStr::from(name)),
+                Repr::CloseRegion => Item::CloseRegion,
+                Repr::OpenComment => Item::OpenComment,
+                Repr::CloseComment => Item::CloseComment,
+                Repr::OpenSource { file, line } => {
+                    Item::OpenSource(Box::new(SourceLocation::new(file, line)))
+                }
+                Repr::CloseSource => Item::CloseSource,
+            });
+        }
+
+        Ok(tokens)
+    }
+}
@@ -0,0 +1,49 @@
+use crate::lang::Lang;
+use crate::tokens::FormatInto;
+use crate::Tokens;
+
+/// Function to insert a soft break, which is a space that turns into a new
+/// line if the line would otherwise exceed the configured max width.
+///
+/// This is the same as calling [Tokens::soft_break], so see it for details.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+/// use genco::fmt;
+/// use genco::tokens::soft_break;
+///
+/// let tokens: rust::Tokens = quote!(let sum = alpha +$(soft_break())beta +$(soft_break())gamma;);
+///
+/// let mut w = fmt::VecWriter::new();
+/// let fmt = fmt::Config::from_lang::<Rust>().with_max_width(24);
+/// tokens.format_file(&mut w.as_formatter(&fmt), &rust::Config::default())?;
+///
+/// assert_eq!(
+///     vec![
+///         "let sum = alpha + beta +",
+///         "    gamma;",
+///     ],
+///     w.into_vec()
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn soft_break() -> SoftBreak {
+    SoftBreak(())
+}
+
+/// A soft break.
+///
+/// Created using the [soft_break()] function.
+#[derive(Debug, Clone, Copy)]
+pub struct SoftBreak(());
+
+impl<L> FormatInto<L> for SoftBreak
+where
+    L: Lang,
+{
+    fn format_into(self, tokens: &mut Tokens<L>) {
+        tokens.soft_break();
+    }
+}
@@ -0,0 +1,47 @@
+use crate::tokens::ItemStr;
+
+/// A location in the input which produced a region of tokens.
+///
+/// This is attached to tokens using [Tokens::append_with_source], and can be
+/// recovered for each line of output through a [fmt::SourceMap].
+///
+/// [Tokens::append_with_source]: crate::Tokens::append_with_source
+/// [fmt::SourceMap]: crate::fmt::SourceMap
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SourceLocation {
+    file: ItemStr,
+    line: usize,
+}
+
+impl SourceLocation {
+    /// Construct a new source location from a file and a line number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::tokens::SourceLocation;
+    ///
+    /// let loc = SourceLocation::new("schema.json", 12);
+    /// assert_eq!("schema.json", loc.file());
+    /// assert_eq!(12, loc.line());
+    /// ```
+    pub fn new<F>(file: F, line: usize) -> Self
+    where
+        F: Into<ItemStr>,
+    {
+        Self {
+            file: file.into(),
+            line,
+        }
+    }
+
+    /// The file of the source location.
+    pub fn file(&self) -> &str {
+        &self.file
+    }
+
+    /// The line of the source location.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+}
@@ -0,0 +1,112 @@
+use crate::lang::Lang;
+use crate::tokens::FormatInto;
+use crate::Tokens;
+
+/// Function to surround the given tokens with exactly one space on each
+/// side, regardless of what precedes or follows them.
+///
+/// This is the same as combining [leading_space()] and [trailing_space()].
+///
+/// # Precedence
+///
+/// Spaces never accumulate. A space which meets another space, like one
+/// from an adjacent spaced fragment or from the [quote!] macro itself,
+/// collapses into a single space. Line breaks take precedence over spaces,
+/// so a trailing space at the end of a line is never emitted.
+///
+/// [quote!]: crate::quote!
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+/// use genco::tokens::spaced;
+///
+/// let op = spaced("+");
+/// let tokens: rust::Tokens = quote!(a$(op)b);
+/// assert_eq!("a + b", tokens.to_string()?);
+///
+/// let tokens: rust::Tokens = quote!(a $(spaced("+")) $(spaced("-"))b);
+/// assert_eq!("a + - b", tokens.to_string()?);
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn spaced<T>(inner: T) -> Spaced<T> {
+    Spaced {
+        inner,
+        leading: true,
+        trailing: true,
+    }
+}
+
+/// Function to prefix the given tokens with exactly one space.
+///
+/// See [spaced()] for how this interacts with other spacing.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+/// use genco::tokens::leading_space;
+///
+/// let tokens: rust::Tokens = quote!(x$(leading_space("as u32")));
+/// assert_eq!("x as u32", tokens.to_string()?);
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn leading_space<T>(inner: T) -> Spaced<T> {
+    Spaced {
+        inner,
+        leading: true,
+        trailing: false,
+    }
+}
+
+/// Function to suffix the given tokens with exactly one space.
+///
+/// See [spaced()] for how this interacts with other spacing.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+/// use genco::tokens::trailing_space;
+///
+/// let tokens: rust::Tokens = quote!($(trailing_space("mut"))x);
+/// assert_eq!("mut x", tokens.to_string()?);
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn trailing_space<T>(inner: T) -> Spaced<T> {
+    Spaced {
+        inner,
+        leading: false,
+        trailing: true,
+    }
+}
+
+/// Struct containing tokens which are surrounded by spaces.
+///
+/// This is constructed with the [spaced()], [leading_space()] and
+/// [trailing_space()] functions.
+#[derive(Clone, Copy, Debug)]
+pub struct Spaced<T> {
+    inner: T,
+    leading: bool,
+    trailing: bool,
+}
+
+impl<T, L> FormatInto<L> for Spaced<T>
+where
+    L: Lang,
+    T: FormatInto<L>,
+{
+    fn format_into(self, t: &mut Tokens<L>) {
+        if self.leading {
+            t.space();
+        }
+
+        self.inner.format_into(t);
+
+        if self.trailing {
+            t.space();
+        }
+    }
+}
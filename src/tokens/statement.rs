@@ -0,0 +1,56 @@
+use crate::lang::Lang;
+use crate::tokens::{static_literal, FormatInto, Tokens};
+
+/// Function to terminate the given tokens as a statement, using the
+/// terminator of the language.
+///
+/// This appends `;` in languages like Java, C# or Rust, and nothing in
+/// languages where statements end with the line, like Go or Python. This
+/// allows a generator which is shared between languages to emit correct
+/// statements for each of them.
+///
+/// See [Lang::statement_terminator].
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+/// use genco::tokens::statement;
+///
+/// let java: java::Tokens = quote!($(statement(quote!(x = 1))));
+/// assert_eq!("x = 1;", java.to_string()?);
+///
+/// let go: go::Tokens = quote!($(statement(quote!(x = 1))));
+/// assert_eq!("x = 1", go.to_string()?);
+///
+/// let python: python::Tokens = quote!($(statement(quote!(x = 1))));
+/// assert_eq!("x = 1", python.to_string()?);
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn statement<T>(body: T) -> Statement<T> {
+    Statement { body }
+}
+
+/// A statement which is terminated according to the language.
+///
+/// This is constructed with the [statement()] function.
+#[derive(Clone, Copy, Debug)]
+pub struct Statement<T> {
+    body: T,
+}
+
+impl<T, L> FormatInto<L> for Statement<T>
+where
+    L: Lang,
+    T: FormatInto<L>,
+{
+    fn format_into(self, tokens: &mut Tokens<L>) {
+        tokens.append(self.body);
+
+        let terminator = L::statement_terminator();
+
+        if !terminator.is_empty() {
+            tokens.append(static_literal(terminator));
+        }
+    }
+}
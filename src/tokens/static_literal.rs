@@ -23,18 +23,44 @@ where
 /// This is typically more efficient than using [append()] with a string
 /// directly, since it can avoid copying the string.
 ///
+/// # Allocations
+///
+/// The literal is stored as [ItemStr::Static], so appending it doesn't
+/// allocate beyond growing the token stream itself. In contrast, appending a
+/// `&str` copies it into an [ItemStr::Box] since its lifetime isn't known.
+///
+/// Literal text written in [quote!] and [quote_in!] is already stored as
+/// [ItemStr::Static], with adjacent text merged into a single literal, so
+/// this is only needed when building tokens programmatically or when
+/// interpolating a `&'static str` like `$(static_literal(keyword))`.
+///
 /// # Examples
 ///
 /// ```
 /// use genco::prelude::*;
-/// use genco::tokens;
+/// use genco::tokens::{self, Item, ItemStr};
 ///
 /// let mut tokens = Tokens::<()>::new();
 /// tokens.append(tokens::static_literal("hello"));
+/// tokens.append("world");
+///
+/// let mut it = tokens.iter();
+/// assert_eq!(Some(&Item::Literal(ItemStr::Static("hello"))), it.next());
+/// assert_eq!(Some(&Item::Literal(ItemStr::Box("world".into()))), it.next());
+///
+/// let tokens: Tokens = quote!(fn main());
+///
+/// let mut it = tokens.iter();
+/// assert_eq!(Some(&Item::Literal(ItemStr::Static("fn"))), it.next());
+/// assert_eq!(Some(&Item::Space), it.next());
+/// assert_eq!(Some(&Item::Literal(ItemStr::Static("main()"))), it.next());
+/// assert_eq!(None, it.next());
 /// # Ok::<_, genco::fmt::Error>(())
 /// ```
 ///
 /// [append()]: crate::Tokens::append()
+/// [quote!]: crate::quote
+/// [quote_in!]: crate::quote_in
 pub fn static_literal(literal: &'static str) -> StaticLiteral {
     StaticLiteral { literal }
 }
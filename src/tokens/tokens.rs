@@ -12,8 +12,9 @@
 
 use crate::fmt;
 use crate::lang::{Lang, LangSupportsEval};
-use crate::tokens::{FormatInto, Item, Register};
+use crate::tokens::{FormatInto, Item, ItemStr, Register, SourceLocation};
 use std::cmp;
+use std::convert::TryFrom;
 use std::iter::FromIterator;
 use std::mem;
 use std::slice;
@@ -174,6 +175,142 @@ where
         tokens.format_into(self)
     }
 
+    /// Append pre-rendered text, like a license header or the output of
+    /// another tool, line by line.
+    ///
+    /// The text starts on a new line, and anything appended afterwards
+    /// starts on a new line as well. Each line is added as a literal, so the
+    /// leading whitespace of a line is kept relative to the current
+    /// indentation instead of being reflowed. Lines are otherwise kept as they
+    /// are, including lines which only contain whitespace, and empty lines are
+    /// added as [Item::BlankLine] so that consecutive empty lines are kept as
+    /// well. Line endings are replaced by the configured newline.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    ///
+    /// let generated = "match x {\n    1 => one(),\n\n\n    _ => other(),\n}\n";
+    ///
+    /// let mut body = rust::Tokens::new();
+    /// body.append("let x = 1;");
+    /// body.append_verbatim(generated);
+    /// body.append("done();");
+    ///
+    /// let tokens: rust::Tokens = quote! {
+    ///     fn main() {
+    ///         $body
+    ///     }
+    /// };
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "fn main() {",
+    ///         "    let x = 1;",
+    ///         "    match x {",
+    ///         "        1 => one(),",
+    ///         "",
+    ///         "",
+    ///         "        _ => other(),",
+    ///         "    }",
+    ///         "    done();",
+    ///         "}",
+    ///     ],
+    ///     tokens.to_file_vec()?
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn append_verbatim<T>(&mut self, text: T)
+    where
+        T: Into<ItemStr>,
+    {
+        let text = text.into();
+
+        for line in text.lines() {
+            self.push();
+
+            if line.is_empty() {
+                self.items.push(Item::BlankLine);
+            } else {
+                self.items.push(Item::Literal(ItemStr::from(line)));
+            }
+        }
+
+        self.push();
+    }
+
+    /// Insert the given tokens at the beginning of the token stream.
+    ///
+    /// This behaves as if the given tokens had been appended first, followed
+    /// by everything that is already in the stream. Whitespace between the two
+    /// is merged the same way as with [`extend`].
+    ///
+    /// Note that this shifts every item in the stream, so it takes time
+    /// proportional to the size of the stream. Prefer building the stream in
+    /// order when possible.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    ///
+    /// let mut tokens: Tokens<()> = quote!(bar baz);
+    /// tokens.prepend(quote!(foo$[' ']));
+    ///
+    /// assert_eq!(tokens, quote!(foo bar baz));
+    /// ```
+    ///
+    /// [`extend`]: Self::extend
+    pub fn prepend<T>(&mut self, tokens: T)
+    where
+        T: FormatInto<L>,
+    {
+        let mut prefix = Tokens::with_capacity(self.items.len());
+        prefix.append(tokens);
+        prefix.extend(mem::take(&mut self.items));
+        *self = prefix;
+    }
+
+    /// Insert the given tokens at the beginning of the token stream, separated
+    /// from what follows by an empty [`line`].
+    ///
+    /// This is useful for adding a preamble like a header comment after the
+    /// rest of the stream has been built. It has the same cost as
+    /// [`prepend`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    ///
+    /// let mut tokens: rust::Tokens = quote!(fn main() {});
+    /// tokens.prepend_line(quote!(#![allow(unused)]));
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "#![allow(unused)]",
+    ///         "",
+    ///         "fn main() {}",
+    ///     ],
+    ///     tokens.to_file_vec()?
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    ///
+    /// [`line`]: Self::line
+    /// [`prepend`]: Self::prepend
+    pub fn prepend_line<T>(&mut self, tokens: T)
+    where
+        T: FormatInto<L>,
+    {
+        let mut prefix = Tokens::with_capacity(self.items.len());
+        prefix.append(tokens);
+        prefix.line();
+        prefix.extend(mem::take(&mut self.items));
+        *self = prefix;
+    }
+
     /// Extend with another stream of tokens.
     ///
     /// This respects the structural requirements of adding one element at a
@@ -207,10 +344,180 @@ where
         }
     }
 
+    /// Extend with a number of sections, with a separator between each one.
+    ///
+    /// The existing content of this stream counts as the first section.
+    /// Empty sections, as determined by [`is_empty`], are skipped entirely,
+    /// and the separator is only added before a non-empty section if
+    /// something has been added before it. So the separator never occurs
+    /// first, last, or twice in a row because of empty sections.
+    ///
+    /// This generalizes the common pattern of separating a file header from
+    /// its body with an empty line, which is done by using [`Item::Line`] as
+    /// the separator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    /// use genco::tokens::Item;
+    ///
+    /// let imports: rust::Tokens = quote!(use std::fmt;);
+    /// let consts: rust::Tokens = rust::Tokens::new();
+    /// let body: rust::Tokens = quote!(fn main() {});
+    ///
+    /// let mut tokens = rust::Tokens::new();
+    /// tokens.extend_with_separator([imports, consts, body], Item::Line);
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "use std::fmt;",
+    ///         "",
+    ///         "fn main() {}",
+    ///     ],
+    ///     tokens.to_file_vec()?
+    /// );
+    ///
+    /// let mut tokens: Tokens<()> = quote!(a);
+    /// tokens.extend_with_separator([quote!(b), Tokens::new(), quote!(c)], quote!($[' ']|$[' ']));
+    /// assert_eq!("a | b | c", tokens.to_string()?);
+    ///
+    /// let mut tokens: Tokens<()> = Tokens::new();
+    /// tokens.extend_with_separator([Tokens::new(), quote!(b)], quote!($[' ']|$[' ']));
+    /// assert_eq!("b", tokens.to_string()?);
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    ///
+    /// [`is_empty`]: Self::is_empty
+    pub fn extend_with_separator<I, S>(&mut self, sections: I, separator: S)
+    where
+        I: IntoIterator<Item = Tokens<L>>,
+        S: Clone + FormatInto<L>,
+    {
+        for section in sections {
+            if section.is_empty() {
+                continue;
+            }
+
+            if !self.is_empty() {
+                self.append(separator.clone());
+            }
+
+            self.append(section);
+        }
+    }
+
+    /// Append the given tokens, annotated with the source location which
+    /// produced them.
+    ///
+    /// The location can be recovered for each line of output that the tokens
+    /// produce through a [fmt::SourceMap], which is populated by formatters
+    /// configured with [fmt::Formatter::with_source_map]. Annotations may be
+    /// nested, in which case the innermost location applies.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    /// use genco::fmt;
+    /// use genco::tokens::SourceLocation;
+    ///
+    /// let mut tokens = rust::Tokens::new();
+    /// tokens.append("// generated");
+    /// tokens.push();
+    /// tokens.append_with_source(SourceLocation::new("input.idl", 7), quote!(fn foo() {}));
+    ///
+    /// let mut map = fmt::SourceMap::new();
+    /// let mut w = fmt::VecWriter::new();
+    ///
+    /// let fmt = fmt::Config::from_lang::<Rust>();
+    /// tokens.format_file(&mut w.as_formatter(&fmt).with_source_map(&mut map), &rust::Config::default())?;
+    ///
+    /// assert_eq!(vec!["// generated", "fn foo() {}"], w.into_vec());
+    /// assert_eq!(Some(&SourceLocation::new("input.idl", 7)), map.get(2));
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn append_with_source<T>(&mut self, loc: SourceLocation, body: T)
+    where
+        T: FormatInto<L>,
+    {
+        self.item(Item::OpenSource(Box::new(loc)));
+        body.format_into(self);
+        self.item(Item::CloseSource);
+    }
+
+    /// Convert the token stream into a token stream for another language.
+    ///
+    /// Only language-agnostic items can be converted, like literals, quoted
+    /// strings and whitespace. If the token stream contains any
+    /// language-specific items, like imports, this returns `None`.
+    ///
+    /// Note that string interpolation is carried over as-is, even if the
+    /// target language does not support it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    ///
+    /// let tokens: Tokens = quote! {
+    ///     if a == $(quoted("hello")):
+    ///         pass
+    /// };
+    ///
+    /// let python = tokens.map_lang::<Python>().unwrap();
+    /// assert_eq!("if a == \"hello\":\n    pass", python.to_string()?);
+    ///
+    /// let js = quote!(import $(js::import("foo", "bar")));
+    /// assert!(js.map_lang::<Python>().is_none());
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn map_lang<U>(self) -> Option<Tokens<U>>
+    where
+        U: Lang,
+    {
+        let mut tokens = Tokens::new();
+
+        for item in self.items {
+            let item = match item {
+                Item::Literal(literal) => Item::Literal(literal),
+                Item::Lang(..) | Item::Register(..) | Item::Deferred(..) => return None,
+                Item::Push => Item::Push,
+                Item::Line => Item::Line,
+                Item::BlankLine => Item::BlankLine,
+                Item::Space => Item::Space,
+                Item::SoftBreak => Item::SoftBreak,
+                Item::Indentation(n) => Item::Indentation(n),
+                Item::OpenQuote(has_eval) => Item::OpenQuote(has_eval),
+                Item::CloseQuote => Item::CloseQuote,
+                Item::RawQuoted(input) => Item::RawQuoted(input),
+                Item::OpenEval => Item::OpenEval,
+                Item::CloseEval => Item::CloseEval,
+                Item::OpenRegion(label) => Item::OpenRegion(label),
+                Item::CloseRegion => Item::CloseRegion,
+                Item::OpenComment => Item::OpenComment,
+                Item::CloseComment => Item::CloseComment,
+                Item::OpenSource(loc) => Item::OpenSource(loc),
+                Item::CloseSource => Item::CloseSource,
+            };
+
+            tokens.items.push(item);
+        }
+
+        Some(tokens)
+    }
+
     /// Walk over all imports.
     ///
-    /// The order in which the imports are returned is *not* defined. So if you
-    /// need them in some particular order you need to sort them.
+    /// This includes both language items which are rendered and items which
+    /// are added through [register()][crate::tokens::register()], and can be
+    /// used to inspect which imports a file would have before it's
+    /// formatted.
+    ///
+    /// Imports are returned in reverse order, starting with the import that
+    /// was added last. Imports are returned once for every time they've been
+    /// added, so if you need them deduplicated or in some particular order
+    /// you need to sort them.
     ///
     /// # Examples
     ///
@@ -220,11 +527,15 @@ where
     /// let debug = rust::import("std::fmt", "Debug");
     /// let ty = rust::import("std::collections", "HashMap");
     ///
-    /// let tokens = quote!(foo $ty<u32, dyn $debug> baz);
+    /// let tokens = quote!(foo $(&ty)<u32, dyn $debug> $ty);
     ///
-    /// for import in tokens.walk_imports() {
-    ///     println!("{:?}", import);
-    /// }
+    /// let names = tokens
+    ///     .walk_imports()
+    ///     .map(|import| quote!($(import.clone())).to_string())
+    ///     .collect::<Result<Vec<_>, _>>()?;
+    ///
+    /// assert_eq!(vec!["HashMap", "Debug", "HashMap"], names);
+    /// # Ok::<_, genco::fmt::Error>(())
     /// ```
     pub fn walk_imports(&self) -> WalkImports<'_, L> {
         WalkImports {
@@ -233,6 +544,38 @@ where
         }
     }
 
+    /// Walk over all imports of one particular type of language item.
+    ///
+    /// This is a convenience over [walk_imports()][Self::walk_imports] for
+    /// languages with more than one kind of language item, and returns
+    /// imports in the same order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    ///
+    /// let json = ruby::import("json", "JSON");
+    ///
+    /// let tokens: ruby::Tokens = quote! {
+    ///     $(register(ruby::require("set")))
+    ///     $json.parse(input)
+    /// };
+    ///
+    /// let requires = tokens.imports_of::<ruby::Require>().count();
+    /// let imports = tokens.imports_of::<ruby::Import>().count();
+    ///
+    /// assert_eq!((1, 1), (requires, imports));
+    /// ```
+    pub fn imports_of<'a, T>(&'a self) -> impl Iterator<Item = &'a T> + 'a
+    where
+        T: 'a,
+        &'a T: TryFrom<&'a L::Item>,
+    {
+        self.walk_imports()
+            .filter_map(|item| <&'a T>::try_from(item).ok())
+    }
+
     /// Add an registered custom element that is _not_ rendered.
     ///
     /// Registration can be used to generate imports that do not render a
@@ -272,6 +615,24 @@ where
         self.items.is_empty()
     }
 
+    /// Get the number of items in the token stream.
+    ///
+    /// This counts [items][Item], including whitespace like spaces and line
+    /// breaks, and not the length of the rendered output.
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    ///
+    /// let tokens: Tokens<()> = quote!();
+    /// assert_eq!(0, tokens.len());
+    ///
+    /// let tokens: Tokens<()> = quote!(foo bar);
+    /// assert_eq!(3, tokens.len());
+    /// ```
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
     /// Add a single spacing to the token stream.
     ///
     /// Note that due to structural guarantees two consequent spaces may not
@@ -303,13 +664,73 @@ where
     /// # Ok::<_, genco::fmt::Error>(())
     /// ```
     pub fn space(&mut self) {
-        if let Some(Item::Space) = self.items.last() {
+        if let Some(Item::Space | Item::SoftBreak) = self.items.last() {
             return;
         }
 
         self.items.push(Item::Space);
     }
 
+    /// Add a soft break.
+    ///
+    /// A soft break is a space which is turned into a new line if the line
+    /// would otherwise exceed the [max width] configured when formatting.
+    /// Continuation lines are indented one level deeper than the line they
+    /// were broken from. Without a max width, a soft break is the same as a
+    /// [`space`].
+    ///
+    /// Breaking is best-effort: the formatter only looks at the next token
+    /// when deciding if a break is necessary, and it never splits tokens. So
+    /// a token which is wider than the max width on its own is put on a new
+    /// line and allowed to overflow.
+    ///
+    /// [max width]: crate::fmt::Config::with_max_width
+    /// [`space`]: Self::space
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    /// use genco::fmt;
+    /// use genco::tokens::soft_break;
+    ///
+    /// let args = ["first_argument", "second_argument", "third_argument"];
+    ///
+    /// let tokens: rust::Tokens = quote! {
+    ///     call($(for a in args join (,$(soft_break())) => $a));
+    /// };
+    ///
+    /// assert_eq!(
+    ///     "call(first_argument, second_argument, third_argument);",
+    ///     tokens.to_string()?
+    /// );
+    ///
+    /// let mut w = fmt::VecWriter::new();
+    /// let fmt = fmt::Config::from_lang::<Rust>().with_max_width(32);
+    /// tokens.format_file(&mut w.as_formatter(&fmt), &rust::Config::default())?;
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "call(first_argument,",
+    ///         "    second_argument,",
+    ///         "    third_argument);",
+    ///     ],
+    ///     w.into_vec()
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn soft_break(&mut self) {
+        match self.items.last() {
+            Some(Item::SoftBreak | Item::Push | Item::Line | Item::BlankLine) | None => return,
+            Some(Item::Space) => {
+                self.items.pop();
+            }
+            _ => {}
+        }
+
+        self.items.push(Item::SoftBreak);
+    }
+
     /// Add a single push operation.
     ///
     /// Push operations ensure that any following tokens are added to their own
@@ -348,7 +769,7 @@ where
                     self.items.push(Item::Line);
                     return;
                 }
-                Some(Item::Space | Item::Push) => continue,
+                Some(Item::Space | Item::SoftBreak | Item::Push) => continue,
                 item => break item,
             }
         };
@@ -547,6 +968,53 @@ where
         out.format_items(&self.items, config, format)
     }
 
+    /// Format the token stream with all indentation offset by `base_level`
+    /// levels, including the first line.
+    ///
+    /// This is useful when splicing the output into an already indented
+    /// position of externally managed text. Like [format][Self::format], this
+    /// doesn't write any imports.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    /// use genco::fmt;
+    ///
+    /// let tokens: rust::Tokens = quote! {
+    ///     if ready {
+    ///         go();
+    ///     }
+    /// };
+    ///
+    /// let mut w = fmt::VecWriter::new();
+    /// let fmt = fmt::Config::from_lang::<Rust>();
+    /// let config = rust::Config::default();
+    ///
+    /// tokens.format_with_base_indent(&mut w.as_formatter(&fmt), &config, 2)?;
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "        if ready {",
+    ///         "            go();",
+    ///         "        }",
+    ///     ],
+    ///     w.into_vec()
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn format_with_base_indent(
+        &self,
+        out: &mut fmt::Formatter<'_>,
+        config: &L::Config,
+        base_level: usize,
+    ) -> fmt::Result {
+        let format = L::Format::default();
+        out.with_base_indent(base_level, |out| {
+            out.format_items(&self.items, config, &format)
+        })
+    }
+
     /// Push a single item to the stream while checking for structural
     /// guarantees.
     ///
@@ -570,6 +1038,7 @@ where
             Item::Push => self.push(),
             Item::Line => self.line(),
             Item::Space => self.space(),
+            Item::SoftBreak => self.soft_break(),
             Item::Indentation(n) => self.indentation(n),
             Item::Lang(_, item) => self.lang_item(item),
             Item::Register(_, item) => self.lang_item_register(item),
@@ -637,6 +1106,174 @@ where
         Ok(())
     }
 
+    /// Format only the imports of the token stream, as they would be rendered
+    /// by [format_file][Self::format_file].
+    ///
+    /// The imports are sorted and deduplicated according to the conventions
+    /// of the language, and everything else is left out. This is useful for
+    /// regenerating the import header of a file which is otherwise maintained
+    /// by hand.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    /// use genco::fmt;
+    ///
+    /// let map = rust::import("std::collections", "HashMap");
+    /// let set = rust::import("std::collections", "HashSet");
+    /// let debug = rust::import("std::fmt", "Debug");
+    ///
+    /// let tokens: rust::Tokens = quote! {
+    ///     let m = $(&map)::<u32, $set<u32>>::new();
+    ///     println!("{:?}", &m as &dyn $debug);
+    ///     let m = $map::<u32, u32>::new();
+    /// };
+    ///
+    /// let mut w = fmt::VecWriter::new();
+    ///
+    /// let fmt = fmt::Config::from_lang::<Rust>();
+    /// let config = rust::Config::default();
+    ///
+    /// tokens.format_imports(&mut w.as_formatter(&fmt), &config)?;
+    ///
+    /// assert_eq! {
+    ///     vec![
+    ///         "use std::collections::{HashMap, HashSet};",
+    ///         "use std::fmt::Debug;",
+    ///     ],
+    ///     w.into_vec(),
+    /// };
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn format_imports(&self, out: &mut fmt::Formatter<'_>, config: &L::Config) -> fmt::Result {
+        L::format_imports(self, out, config)
+    }
+
+    /// Render the token stream as a file and wrap the result in a quoted
+    /// string of another language `Q`.
+    ///
+    /// The rendered output is escaped according to the quoting rules of `Q`,
+    /// which is useful for generators that emit code which itself generates
+    /// code. The given [fmt::Config] controls how the embedded file is
+    /// rendered, like its indentation and newlines.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    /// use genco::fmt;
+    ///
+    /// let tokens: rust::Tokens = quote! {
+    ///     fn main() {
+    ///         println!("Hello");
+    ///     }
+    /// };
+    ///
+    /// let fmt = fmt::Config::from_lang::<Rust>()
+    ///     .with_indentation(fmt::Indentation::Space(2));
+    /// let source = tokens.format_indented_quoted::<Python>(&fmt, &rust::Config::default())?;
+    ///
+    /// let tokens: python::Tokens = quote!(SOURCE = $source);
+    ///
+    /// assert_eq!(
+    ///     "SOURCE = \"fn main() {\\n  println!(\\\"Hello\\\");\\n}\\n\"",
+    ///     tokens.to_string()?
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn format_indented_quoted<Q>(
+        &self,
+        fmt: &fmt::Config,
+        config: &L::Config,
+    ) -> fmt::Result<Tokens<Q>>
+    where
+        Q: Lang,
+    {
+        let mut w = fmt::FmtWriter::new(String::new());
+        self.format_file(&mut w.as_formatter(fmt), config)?;
+
+        let mut tokens = Tokens::new();
+        tokens.append(crate::tokens::quoted(w.into_inner()));
+        Ok(tokens)
+    }
+
+    /// Format the token stream as a file to a string using the given
+    /// configurations.
+    ///
+    /// This is like [to_file_string][Self::to_file_string], except that the
+    /// formatting and language configurations are provided instead of using
+    /// the defaults. This function will render imports.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    /// use genco::fmt;
+    ///
+    /// let list = java::import("java.util", "List");
+    ///
+    /// let tokens: java::Tokens = quote! {
+    ///     class Foo {
+    ///         $list<String> items;
+    ///     }
+    /// };
+    ///
+    /// let fmt = fmt::Config::from_lang::<Java>().with_indentation(fmt::Indentation::Space(2));
+    /// let config = java::Config::default().with_package("com.example");
+    ///
+    /// assert_eq!(
+    ///     "package com.example;\n\nimport java.util.List;\n\nclass Foo {\n  List<String> items;\n}\n",
+    ///     tokens.to_file_string_with(&fmt, &config)?
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn to_file_string_with(
+        &self,
+        fmt: &fmt::Config,
+        config: &L::Config,
+    ) -> fmt::Result<String> {
+        let mut w = fmt::FmtWriter::new(String::new());
+        self.format_file(&mut w.as_formatter(fmt), config)?;
+        let mut output = w.into_inner();
+        L::post_process(&mut output);
+        Ok(output)
+    }
+
+    /// Format only the current token stream as a string using the given
+    /// configurations.
+    ///
+    /// This is like [to_string][Self::to_string], except that the formatting
+    /// and language configurations are provided instead of using the
+    /// defaults. This function _will not_ render imports.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    /// use genco::fmt;
+    ///
+    /// let tokens: rust::Tokens = quote! {
+    ///     fn main() {
+    ///         println!($(quoted("hello")));
+    ///     }
+    /// };
+    ///
+    /// let fmt = fmt::Config::from_lang::<Rust>().with_indentation(fmt::Indentation::Tab);
+    ///
+    /// assert_eq!(
+    ///     "fn main() {\n\tprintln!(\"hello\");\n}",
+    ///     tokens.to_string_with(&fmt, &rust::Config::default())?
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn to_string_with(&self, fmt: &fmt::Config, config: &L::Config) -> fmt::Result<String> {
+        let mut w = fmt::FmtWriter::new(String::new());
+        let format = L::Format::default();
+        self.format(&mut w.as_formatter(fmt), config, &format)?;
+        Ok(w.into_inner())
+    }
+
     /// Internal function to modify the indentation of the token stream.
     fn indentation(&mut self, mut n: i16) {
         let item = loop {
@@ -644,6 +1281,7 @@ where
             match self.items.pop() {
                 Some(Item::Push) => continue,
                 Some(Item::Space) => continue,
+                Some(Item::SoftBreak) => continue,
                 Some(Item::Line) => continue,
                 Some(Item::Indentation(u)) => n += u,
                 item => break item,
@@ -715,6 +1353,45 @@ where
         let mut formatter = w.as_formatter(&fmt);
         let config = L::Config::default();
         self.format_file(&mut formatter, &config)?;
+        let mut output = w.into_inner();
+        L::post_process(&mut output);
+        Ok(output)
+    }
+
+    /// Format only the imports of the token stream to a string using the
+    /// default configuration.
+    ///
+    /// This is a shorthand to using [FmtWriter][fmt::FmtWriter] directly in
+    /// combination with [format_imports][Self::format_imports].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    ///
+    /// let list = java::import("java.util", "List");
+    /// let map = java::import("java.util", "Map");
+    ///
+    /// let tokens: java::Tokens = quote! {
+    ///     $map<String, $(&list)<String>> a;
+    ///     $list<String> b;
+    /// };
+    ///
+    /// assert_eq!(
+    ///     "import java.util.List;\nimport java.util.Map;",
+    ///     tokens.to_imports_string()?
+    /// );
+    ///
+    /// let tokens: java::Tokens = quote!(int a;);
+    /// assert_eq!("", tokens.to_imports_string()?);
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn to_imports_string(&self) -> fmt::Result<String> {
+        let mut w = fmt::FmtWriter::new(String::new());
+        let fmt = fmt::Config::from_lang::<L>();
+        let mut formatter = w.as_formatter(&fmt);
+        let config = L::Config::default();
+        self.format_imports(&mut formatter, &config)?;
         Ok(w.into_inner())
     }
 
@@ -754,11 +1431,61 @@ where
         Ok(w.into_inner())
     }
 
+    /// Get an adapter which implements [Display][std::fmt::Display] for the
+    /// current token stream, so that it can be used in format strings like
+    /// `format!("{}", tokens.display(&fmt))`.
+    ///
+    /// The token stream is formatted like [to_string][Self::to_string], using
+    /// the given formatting configuration. This function _will not_ render
+    /// imports.
+    ///
+    /// Since formatting the token stream can fail, while a
+    /// [Display][std::fmt::Display] implementation should only fail if the
+    /// underlying writer does, an error is written as a marker like
+    /// `<error: maximum nesting depth exceeded>` instead of being returned.
+    /// Use [to_string][Self::to_string] if you need to handle errors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    /// use genco::fmt;
+    ///
+    /// let map = rust::import("std::collections", "HashMap");
+    /// let tokens: rust::Tokens = quote!(let m = $map::new(););
+    ///
+    /// let fmt = fmt::Config::from_lang::<Rust>();
+    /// assert_eq!(
+    ///     "generated: let m = HashMap::new();",
+    ///     format!("generated: {}", tokens.display(&fmt))
+    /// );
+    ///
+    /// let tokens: rust::Tokens = quote! {
+    ///     {
+    ///         value
+    ///     }
+    /// };
+    ///
+    /// let fmt = fmt::Config::from_lang::<Rust>().with_max_depth(0);
+    /// assert_eq!(
+    ///     "<error: maximum nesting depth exceeded>",
+    ///     tokens.display(&fmt).to_string()
+    /// );
+    /// ```
+    pub fn display<'a>(&'a self, fmt: &'a fmt::Config) -> DisplayTokens<'a, L> {
+        DisplayTokens { tokens: self, fmt }
+    }
+
     /// Format tokens into a vector, where each entry equals a line in the
     /// resulting file using the default configuration.
     ///
-    /// This is a shorthand to using [VecWriter][fmt::VecWriter] directly in
-    /// combination with [format][Self::format_file].
+    /// This produces the same lines as using [VecWriter][fmt::VecWriter]
+    /// directly in combination with [format_file][Self::format_file], so line
+    /// breaks which are part of literals stay inside of the line they were
+    /// written to. The output is also passed through [Lang::post_process].
+    /// If that changes the output, the line boundaries of the formatter can't
+    /// be recovered, and the processed output is split on
+    /// [Lang::default_newline] instead.
     ///
     /// This function will render imports.
     ///
@@ -817,7 +1544,22 @@ where
         let mut formatter = w.as_formatter(&fmt);
         let config = L::Config::default();
         self.format_file(&mut formatter, &config)?;
-        Ok(w.into_vec())
+        let lines = w.into_vec();
+
+        // Post-process the lines as they would be written by
+        // to_file_string, and only split the output again if that changed
+        // anything.
+        let newline = L::default_newline();
+        let output = lines.join(newline) + newline;
+        let mut processed = output.clone();
+        L::post_process(&mut processed);
+
+        if processed == output {
+            return Ok(lines);
+        }
+
+        let processed = processed.strip_suffix(newline).unwrap_or(&processed);
+        Ok(processed.split(newline).map(String::from).collect())
     }
 
     /// Helper function to format tokens into a vector, where each entry equals
@@ -1016,6 +1758,38 @@ where
     }
 }
 
+/// Adapter which implements [Display][std::fmt::Display] for a token stream.
+///
+/// Constructed using the [Tokens::display] method.
+pub struct DisplayTokens<'a, L>
+where
+    L: Lang,
+{
+    tokens: &'a Tokens<L>,
+    fmt: &'a fmt::Config,
+}
+
+impl<L> std::fmt::Display for DisplayTokens<'_, L>
+where
+    L: Lang,
+    L::Config: Default,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut w = fmt::FmtWriter::new(String::new());
+        let config = L::Config::default();
+        let format = L::Format::default();
+
+        let result = self
+            .tokens
+            .format(&mut w.as_formatter(self.fmt), &config, &format);
+
+        match result {
+            Ok(()) => f.write_str(&w.into_inner()),
+            Err(e) => write!(f, "<error: {}>", e),
+        }
+    }
+}
+
 /// An iterator over language-specific imported items.
 ///
 /// Constructed using the [Tokens::walk_imports] method.
@@ -1074,7 +1848,8 @@ mod tests {
         Import {
             fn format(&self, out: &mut fmt::Formatter<'_>, _: &(), _: &()) -> fmt::Result {
                 use std::fmt::Write as _;
-                write!(out, "{}", self.0)
+                write!(out, "{}", self.0)?;
+                Ok(())
             }
         }
     }
@@ -1095,4 +1870,30 @@ mod tests {
 
         assert_eq!(expected, output);
     }
+
+    #[test]
+    fn test_map_lang() {
+        use crate::lang::Rust;
+        use crate::tokens::{quoted, register};
+
+        let toks: Tokens<Lang> = quote! {
+            fn foo() {
+                $(quoted("bar"))
+            }
+        };
+
+        let expected: Tokens<Rust> = quote! {
+            fn foo() {
+                $(quoted("bar"))
+            }
+        };
+
+        assert_eq!(Some(expected), toks.map_lang::<Rust>());
+
+        let toks: Tokens<Lang> = quote!(foo $(Import(1)));
+        assert_eq!(None, toks.map_lang::<Rust>());
+
+        let toks: Tokens<Lang> = quote!(foo $(register(Import(1))));
+        assert_eq!(None, toks.map_lang::<Rust>());
+    }
 }
@@ -0,0 +1,235 @@
+use crate::lang::Lang;
+use crate::tokens::{FormatInto, ItemStr, Tokens};
+use std::collections::HashMap;
+
+/// A declaration which defines and depends on a set of names, used with
+/// [topo_order()].
+#[derive(Debug, Clone)]
+pub struct Declaration<L>
+where
+    L: Lang,
+{
+    tokens: Tokens<L>,
+    defines: Vec<ItemStr>,
+    depends_on: Vec<ItemStr>,
+}
+
+impl<L> Declaration<L>
+where
+    L: Lang,
+{
+    /// Construct a new declaration out of the given tokens.
+    pub fn new<T>(tokens: T) -> Self
+    where
+        T: FormatInto<L>,
+    {
+        let mut t = Tokens::new();
+        t.append(tokens);
+
+        Self {
+            tokens: t,
+            defines: Vec::new(),
+            depends_on: Vec::new(),
+        }
+    }
+
+    /// Add a name which is defined by this declaration.
+    pub fn defines<N>(mut self, name: N) -> Self
+    where
+        N: Into<ItemStr>,
+    {
+        self.defines.push(name.into());
+        self
+    }
+
+    /// Add a name which this declaration depends on.
+    ///
+    /// Names which aren't defined by any declaration, like ones coming from
+    /// an include, are ignored when ordering.
+    pub fn depends_on<N>(mut self, name: N) -> Self
+    where
+        N: Into<ItemStr>,
+    {
+        self.depends_on.push(name.into());
+        self
+    }
+}
+
+/// Function to emit declarations in dependency order, so that every
+/// declaration comes after the declarations it depends on.
+///
+/// Declarations which don't depend on each other keep the order they were
+/// provided in, and are separated by an empty line.
+///
+/// Dependencies which form a cycle can't be ordered. Declarations are
+/// visited depth-first in the order they were provided, and a cycle is
+/// broken wherever a declaration depends on a name whose declaration is
+/// still being visited. Only those names are forward declared, so a cycle
+/// usually needs a single forward declaration rather than one for each of
+/// its names. The `forward` callback is called once for each such name, and
+/// all forward declarations are put before the other declarations. A
+/// declaration depending on a name it defines itself is not considered a
+/// cycle.
+///
+/// In the example below, `list` depends on `node`, which depends on
+/// `value`, which depends on `list` again. Only `list` is forward declared,
+/// since that is where the cycle is broken.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+/// use genco::tokens::{topo_order, Declaration};
+///
+/// let declarations = vec![
+///     Declaration::new(quote!(struct list { struct node *head; };))
+///         .defines("list")
+///         .depends_on("node"),
+///     Declaration::new(quote!(struct node { struct node *next; struct value *value; };))
+///         .defines("node")
+///         .depends_on("node")
+///         .depends_on("value"),
+///     Declaration::new(quote!(struct value { struct list *children; };))
+///         .defines("value")
+///         .depends_on("list"),
+/// ];
+///
+/// let toks: c::Tokens = quote! {
+///     $(topo_order(declarations, |name, t| quote_in!(*t => struct $name;)))
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "struct list;",
+///         "",
+///         "struct value { struct list *children; };",
+///         "",
+///         "struct node { struct node *next; struct value *value; };",
+///         "",
+///         "struct list { struct node *head; };",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn topo_order<L, I, F>(declarations: I, forward: F) -> TopoOrder<L, F>
+where
+    L: Lang,
+    I: IntoIterator<Item = Declaration<L>>,
+    F: FnMut(&ItemStr, &mut Tokens<L>),
+{
+    TopoOrder {
+        declarations: declarations.into_iter().collect(),
+        forward,
+    }
+}
+
+/// Declarations to emit in dependency order.
+///
+/// This is constructed with the [topo_order()] function.
+pub struct TopoOrder<L, F>
+where
+    L: Lang,
+{
+    declarations: Vec<Declaration<L>>,
+    forward: F,
+}
+
+impl<L, F> FormatInto<L> for TopoOrder<L, F>
+where
+    L: Lang,
+    F: FnMut(&ItemStr, &mut Tokens<L>),
+{
+    fn format_into(mut self, tokens: &mut Tokens<L>) {
+        let mut defined_by = HashMap::new();
+
+        for (index, decl) in self.declarations.iter().enumerate() {
+            for name in &decl.defines {
+                defined_by.entry(name.clone()).or_insert(index);
+            }
+        }
+
+        let mut visitor = Visitor {
+            declarations: &self.declarations,
+            defined_by: &defined_by,
+            states: vec![State::New; self.declarations.len()],
+            order: Vec::with_capacity(self.declarations.len()),
+            forward: Vec::new(),
+        };
+
+        for index in 0..self.declarations.len() {
+            visitor.visit(index);
+        }
+
+        let Visitor { order, forward, .. } = visitor;
+
+        for name in &forward {
+            (self.forward)(name, tokens);
+            tokens.line();
+        }
+
+        let mut declarations = self.declarations.into_iter().map(Some).collect::<Vec<_>>();
+
+        for index in order {
+            if let Some(decl) = declarations[index].take() {
+                tokens.append(decl.tokens);
+                tokens.line();
+            }
+        }
+
+        #[derive(Clone, Copy, PartialEq, Eq)]
+        enum State {
+            New,
+            Visiting,
+            Done,
+        }
+
+        struct Visitor<'a, L>
+        where
+            L: Lang,
+        {
+            declarations: &'a [Declaration<L>],
+            defined_by: &'a HashMap<ItemStr, usize>,
+            states: Vec<State>,
+            order: Vec<usize>,
+            forward: Vec<ItemStr>,
+        }
+
+        impl<L> Visitor<'_, L>
+        where
+            L: Lang,
+        {
+            fn visit(&mut self, index: usize) {
+                if self.states[index] != State::New {
+                    return;
+                }
+
+                self.states[index] = State::Visiting;
+                let declarations = self.declarations;
+
+                for name in &declarations[index].depends_on {
+                    let Some(&dep) = self.defined_by.get(name) else {
+                        continue;
+                    };
+
+                    if dep == index {
+                        continue;
+                    }
+
+                    match self.states[dep] {
+                        State::New => self.visit(dep),
+                        State::Visiting => {
+                            if !self.forward.contains(name) {
+                                self.forward.push(name.clone());
+                            }
+                        }
+                        State::Done => (),
+                    }
+                }
+
+                self.states[index] = State::Done;
+                self.order.push(index);
+            }
+        }
+    }
+}
@@ -0,0 +1,104 @@
+use crate::lang::Lang;
+use crate::tokens::{static_literal, FormatInto, Item, ItemStr, Tokens};
+
+/// Function to render the given code followed by a line comment on the same
+/// line, like `let x = 5; // explanation`.
+///
+/// The comment uses the prefix provided by [Lang::line_comment_prefix], and
+/// is separated from the code by a single space. Since a line comment runs
+/// to the end of the line, anything which follows is pushed onto the next
+/// line. Line breaks in the text would end the comment early, so they are
+/// replaced with spaces.
+///
+/// Languages without line comments write the comment as a block comment
+/// using [Lang::block_comment_delimiters] instead, and if the language has
+/// neither only the code is written. Only the code is also written when the
+/// output is [minified][crate::fmt::Config::with_minify].
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+/// use genco::tokens::trailing_comment;
+///
+/// let tokens: rust::Tokens = quote! {
+///     fn main() {
+///         $(trailing_comment(quote!(let x = 5;), "explanation"))
+///         $(trailing_comment(quote!(let z = 7;), "first\nsecond"))
+///     }
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "fn main() {",
+///         "    let x = 5; // explanation",
+///         "    let z = 7; // first second",
+///         "}",
+///     ],
+///     tokens.to_file_vec()?
+/// );
+///
+/// let tokens: python::Tokens = quote!($(trailing_comment(quote!(x = 5), "explanation")));
+/// assert_eq!("x = 5 # explanation", tokens.to_string()?);
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn trailing_comment<T>(code: T, text: impl Into<ItemStr>) -> TrailingComment<T> {
+    TrailingComment {
+        code,
+        text: text.into(),
+    }
+}
+
+/// Code followed by a line comment on the same line.
+///
+/// This is constructed with the [trailing_comment()] function.
+#[derive(Clone, Debug)]
+pub struct TrailingComment<T> {
+    code: T,
+    text: ItemStr,
+}
+
+impl<T, L> FormatInto<L> for TrailingComment<T>
+where
+    L: Lang,
+    T: FormatInto<L>,
+{
+    fn format_into(self, tokens: &mut Tokens<L>) {
+        tokens.append(self.code);
+
+        let (open, close) = match (L::line_comment_prefix(), L::block_comment_delimiters()) {
+            (Some(prefix), _) => (prefix, None),
+            (None, Some((open, close))) => (open, Some(close)),
+            (None, None) => return,
+        };
+
+        tokens.item(Item::OpenComment);
+        tokens.space();
+        tokens.append(static_literal(open));
+
+        let mut words = self.text.lines().map(str::trim).filter(|l| !l.is_empty());
+
+        if let Some(first) = words.next() {
+            let mut text = first.to_owned();
+
+            for line in words {
+                text.push(' ');
+                text.push_str(line);
+            }
+
+            tokens.space();
+            tokens.append(text);
+        }
+
+        if let Some(close) = close {
+            tokens.space();
+            tokens.append(static_literal(close));
+        }
+
+        tokens.item(Item::CloseComment);
+
+        if close.is_none() {
+            tokens.push();
+        }
+    }
+}
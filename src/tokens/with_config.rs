@@ -0,0 +1,172 @@
+use std::cmp;
+use std::fmt;
+use std::hash;
+use std::sync::Arc;
+
+use crate::lang::Lang;
+use crate::tokens::{FormatInto, Item, Tokens};
+
+/// Function to defer rendering until the token stream is formatted, at which
+/// point the closure is called with the configuration of the language.
+///
+/// # Evaluation timing
+///
+/// The closure is called once each time the token stream is formatted, after
+/// the file header and imports have already been resolved and written. This
+/// means that language items like imports which are produced by the closure
+/// *will not* be registered with the file. Only use it for decisions which
+/// depend on the configuration, and reference imported items outside of the
+/// closure if they need to be imported.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+/// use genco::tokens::with_config;
+///
+/// let ty = with_config(|config: &java::Config| {
+///     let mut t = java::Tokens::new();
+///
+///     if config.package() == Some("com.example") {
+///         t.append("Foo");
+///     } else {
+///         t.append("com.example.Foo");
+///     }
+///
+///     t
+/// });
+///
+/// let tokens: java::Tokens = quote!($ty foo;);
+///
+/// let config = java::Config::default().with_package("com.example");
+/// let fmt = genco::fmt::Config::from_lang::<Java>();
+/// let mut w = genco::fmt::VecWriter::new();
+/// tokens.format_file(&mut w.as_formatter(&fmt), &config)?;
+///
+/// assert_eq!(vec!["package com.example;", "", "Foo foo;"], w.into_vec());
+///
+/// let config = java::Config::default().with_package("com.other");
+/// let mut w = genco::fmt::VecWriter::new();
+/// tokens.format_file(&mut w.as_formatter(&fmt), &config)?;
+///
+/// assert_eq!(vec!["package com.other;", "", "com.example.Foo foo;"], w.into_vec());
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn with_config<L, F>(f: F) -> WithConfig<F>
+where
+    L: Lang,
+    F: 'static + Send + Sync + Fn(&L::Config) -> Tokens<L>,
+{
+    WithConfig { f }
+}
+
+/// Struct containing a closure which is evaluated at format time.
+///
+/// This is constructed with the [with_config()] function.
+#[derive(Clone, Copy)]
+pub struct WithConfig<F> {
+    f: F,
+}
+
+impl<L, F> FormatInto<L> for WithConfig<F>
+where
+    L: Lang,
+    F: 'static + Send + Sync + Fn(&L::Config) -> Tokens<L>,
+{
+    fn format_into(self, tokens: &mut Tokens<L>) {
+        let f = self.f;
+
+        tokens.item(Item::Deferred(Deferred {
+            f: Arc::new(move |config, _| f(config)),
+        }));
+    }
+}
+
+type DeferredFn<L> = dyn Fn(&<L as Lang>::Config, Option<usize>) -> Tokens<L> + Send + Sync;
+
+/// A deferred sub-stream of tokens, see [with_config()].
+///
+/// The closure can't be inspected, so all deferred items compare as equal
+/// and hash the same. This keeps comparisons of token streams which contain
+/// them deterministic.
+pub struct Deferred<L>
+where
+    L: Lang,
+{
+    f: Arc<DeferredFn<L>>,
+}
+
+impl<L> Deferred<L>
+where
+    L: Lang,
+{
+    /// Construct deferred tokens which also depend on the width that is
+    /// available for the line, which is the maximum width of the formatter
+    /// minus the current indentation.
+    pub(crate) fn with_width<F>(f: F) -> Self
+    where
+        F: 'static + Send + Sync + Fn(&L::Config, Option<usize>) -> Tokens<L>,
+    {
+        Self { f: Arc::new(f) }
+    }
+
+    /// Evaluate the deferred tokens with the given configuration and
+    /// available width.
+    pub(crate) fn eval(&self, config: &L::Config, width: Option<usize>) -> Tokens<L> {
+        (self.f)(config, width)
+    }
+}
+
+impl<L> Clone for Deferred<L>
+where
+    L: Lang,
+{
+    fn clone(&self) -> Self {
+        Self { f: self.f.clone() }
+    }
+}
+
+impl<L> fmt::Debug for Deferred<L>
+where
+    L: Lang,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Deferred").finish_non_exhaustive()
+    }
+}
+
+impl<L> cmp::PartialEq for Deferred<L>
+where
+    L: Lang,
+{
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+impl<L> cmp::Eq for Deferred<L> where L: Lang {}
+
+impl<L> cmp::PartialOrd for Deferred<L>
+where
+    L: Lang,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<L> cmp::Ord for Deferred<L>
+where
+    L: Lang,
+{
+    fn cmp(&self, _: &Self) -> cmp::Ordering {
+        cmp::Ordering::Equal
+    }
+}
+
+impl<L> hash::Hash for Deferred<L>
+where
+    L: Lang,
+{
+    fn hash<H: hash::Hasher>(&self, _: &mut H) {}
+}
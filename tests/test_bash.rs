@@ -0,0 +1,58 @@
+use genco::fmt;
+use genco::prelude::*;
+
+#[test]
+fn test_quoting() -> genco::fmt::Result {
+    let cases = [
+        ("hello world", "'hello world'"),
+        ("$HOME", "'$HOME'"),
+        ("`rm -rf /`", "'`rm -rf /`'"),
+        ("$(whoami)", "'$(whoami)'"),
+        ("back\\slash", "'back\\slash'"),
+        ("\"double\"", "'\"double\"'"),
+        ("it's", "'it'\\''s'"),
+        ("''", "''\\'''\\'''"),
+        ("a;b|c&d", "'a;b|c&d'"),
+        ("line\nbreak", "'line\nbreak'"),
+        ("*.rs", "'*.rs'"),
+        ("", "''"),
+    ];
+
+    for (input, expected) in cases {
+        let t: bash::Tokens = quote!($(quoted(input)));
+        assert_eq!(expected, t.to_string()?, "quoting {:?}", input);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_file_without_shebang() -> genco::fmt::Result {
+    let t: bash::Tokens = quote! {
+        # comment
+        echo "done"
+    };
+
+    assert_eq!(vec!["# comment", "echo 'done'"], t.to_file_vec()?);
+    Ok(())
+}
+
+#[test]
+fn test_unsupported_char() {
+    let tokens: bash::Tokens = quote! {
+        echo ok
+        printf $(quoted("%s\0"))
+    };
+
+    let fmt = fmt::Config::from_lang::<Bash>();
+    let mut w = fmt::VecWriter::new();
+    let result = tokens.format_file(&mut w.as_formatter(&fmt), &bash::Config::default());
+
+    assert_eq!(
+        Err(fmt::Error::UnsupportedChar {
+            character: '\0',
+            index: 2
+        }),
+        result
+    );
+}
@@ -0,0 +1,132 @@
+use std::cell::RefCell;
+use std::io;
+use std::rc::Rc;
+
+use genco::fmt;
+use genco::prelude::*;
+
+/// Writer which records everything written to it, and how many writes were
+/// made.
+#[derive(Clone, Default)]
+struct Recorder {
+    data: Rc<RefCell<Vec<u8>>>,
+    writes: Rc<RefCell<usize>>,
+}
+
+impl Recorder {
+    fn output(&self) -> String {
+        String::from_utf8(self.data.borrow().clone()).unwrap()
+    }
+
+    fn writes(&self) -> usize {
+        *self.writes.borrow()
+    }
+}
+
+impl io::Write for Recorder {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        *self.writes.borrow_mut() += 1;
+        self.data.borrow_mut().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Writer which fails every write.
+struct Failing;
+
+impl io::Write for Failing {
+    fn write(&mut self, _: &[u8]) -> io::Result<usize> {
+        Err(io::Error::new(io::ErrorKind::Other, "failing"))
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+fn tokens() -> rust::Tokens {
+    quote! {
+        fn main() {
+            let a = 1;
+            let b = 2;
+            println!("{}", a + b);
+        }
+    }
+}
+
+const EXPECTED: &str =
+    "fn main() {\n    let a = 1;\n    let b = 2;\n    println!(\"{}\", a + b);\n}\n";
+
+#[test]
+fn test_into_inner_flushes() -> anyhow::Result<()> {
+    let recorder = Recorder::default();
+    let fmt = fmt::Config::from_lang::<Rust>();
+
+    let mut w = fmt::BufIoWriter::new(recorder.clone());
+    tokens().format_file(&mut w.as_formatter(&fmt), &rust::Config::default())?;
+    assert_eq!("", recorder.output());
+
+    w.into_inner()?;
+    assert_eq!(EXPECTED, recorder.output());
+    Ok(())
+}
+
+#[test]
+fn test_flush_on_drop() -> fmt::Result {
+    let recorder = Recorder::default();
+    let fmt = fmt::Config::from_lang::<Rust>();
+
+    {
+        let mut w = fmt::BufIoWriter::new(recorder.clone());
+        tokens().format_file(&mut w.as_formatter(&fmt), &rust::Config::default())?;
+    }
+
+    assert_eq!(EXPECTED, recorder.output());
+    Ok(())
+}
+
+#[test]
+fn test_fewer_writes_than_io_writer() -> anyhow::Result<()> {
+    let fmt = fmt::Config::from_lang::<Rust>();
+
+    let unbuffered = Recorder::default();
+    let mut w = fmt::IoWriter::new(unbuffered.clone());
+    tokens().format_file(&mut w.as_formatter(&fmt), &rust::Config::default())?;
+
+    let buffered = Recorder::default();
+    let mut w = fmt::BufIoWriter::new(buffered.clone());
+    tokens().format_file(&mut w.as_formatter(&fmt), &rust::Config::default())?;
+    w.into_inner()?;
+
+    assert_eq!(unbuffered.output(), buffered.output());
+    assert_eq!(1, buffered.writes());
+    assert!(unbuffered.writes() > buffered.writes());
+    Ok(())
+}
+
+#[test]
+fn test_errors_while_formatting() {
+    let fmt = fmt::Config::from_lang::<Rust>();
+
+    // A buffer which is smaller than the output forces writes to the failing
+    // writer while formatting.
+    let mut w = fmt::BufIoWriter::with_capacity(Failing, 4);
+    let result = tokens().format_file(&mut w.as_formatter(&fmt), &rust::Config::default());
+    assert_eq!(Err(fmt::Error::Fmt), result);
+}
+
+#[test]
+fn test_errors_while_flushing() -> fmt::Result {
+    let fmt = fmt::Config::from_lang::<Rust>();
+
+    let mut w = fmt::BufIoWriter::new(Failing);
+    tokens().format_file(&mut w.as_formatter(&fmt), &rust::Config::default())?;
+
+    let error = w.into_inner().err().expect("flushing should fail");
+    assert_eq!("failing", error.to_string());
+    Ok(())
+}
@@ -0,0 +1,23 @@
+use genco::lang::*;
+
+macro_rules! check_block_comments {
+    ($($lang:ty),* $(,)?) => {
+        $(
+            assert_eq!(
+                <$lang>::capabilities().block_comments,
+                <$lang>::block_comment_delimiters().is_some(),
+                "block comments of {}",
+                stringify!($lang),
+            );
+        )*
+    };
+}
+
+#[test]
+fn test_block_comments_match_delimiters() {
+    check_block_comments! {
+        Bash, C, Csharp, Css, Dart, Go, Html, Java, JavaScript, Kotlin, Lua,
+        Make, Nix, Php, Proto, Python, Ruby, Rust, Scala, Sql, Swift,
+        TypeScript, Yaml,
+    }
+}
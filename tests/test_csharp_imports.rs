@@ -0,0 +1,79 @@
+use genco::fmt;
+use genco::prelude::*;
+
+#[test]
+fn test_alias_in_namespace() -> fmt::Result {
+    let json = csharp::import("System.Text.Json", "JsonSerializer").with_alias("Json");
+    let list = csharp::import("System.Collections.Generic", "List");
+
+    let tokens: csharp::Tokens = quote! {
+        class Foo {
+            $(&list)<int> a = $json.Deserialize<$list<int>>(input);
+        }
+    };
+
+    let config = csharp::Config::default().with_namespace("Example");
+    let fmt = fmt::Config::from_lang::<Csharp>();
+
+    let mut w = fmt::VecWriter::new();
+    tokens.format_file(&mut w.as_formatter(&fmt), &config)?;
+
+    assert_eq!(
+        vec![
+            "using System.Collections.Generic;",
+            "using Json = System.Text.Json.JsonSerializer;",
+            "",
+            "namespace Example {",
+            "    class Foo {",
+            "        List<int> a = Json.Deserialize<List<int>>(input);",
+            "    }",
+            "}",
+        ],
+        w.into_vec(),
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_conflicting_aliases() -> fmt::Result {
+    let a = csharp::import("A", "Type").with_alias("T");
+    let b = csharp::import("B", "Type").with_alias("T");
+
+    let tokens: csharp::Tokens = quote!($b $a);
+
+    assert_eq!(
+        vec!["using T = A.Type;", "", "B.Type T"],
+        tokens.to_file_vec()?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_nullable_imports() -> fmt::Result {
+    let guid = csharp::import("System", "Guid");
+    let user = csharp::import("App.Models", "User");
+
+    let tokens: csharp::Tokens = quote! {
+        $(guid.nullable()) id;
+        $(csharp::nullable(user)) owner;
+        $(csharp::nullable("int")) count;
+        $(csharp::nullable("string")) name;
+    };
+
+    assert_eq!(
+        vec![
+            "using App.Models;",
+            "using System;",
+            "",
+            "Guid? id;",
+            "User? owner;",
+            "int? count;",
+            "string? name;",
+        ],
+        tokens.to_file_vec()?
+    );
+
+    Ok(())
+}
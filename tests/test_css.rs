@@ -0,0 +1,111 @@
+use genco::fmt;
+use genco::prelude::*;
+
+fn scss(tokens: &css::Tokens) -> fmt::Result<Vec<String>> {
+    let config = css::Config::default().with_scss(true);
+    let fmt = fmt::Config::from_lang::<Css>();
+
+    let mut w = fmt::VecWriter::new();
+    tokens.format_file(&mut w.as_formatter(&fmt), &config)?;
+    Ok(w.into_vec())
+}
+
+#[test]
+fn test_string_escaping() -> fmt::Result {
+    let toks: css::Tokens = quote!($(quoted("a\"b\\c\td\u{7f}e")));
+    assert_eq!("\"a\\\"b\\\\c\\9 d\\7f e\"", toks.to_string()?);
+    Ok(())
+}
+
+#[test]
+fn test_flatten_selectors() -> fmt::Result {
+    let rule = css::rule(":is(h1, h2), p")
+        .rule(css::rule("a, &.lead").decl("color", "red"))
+        .rule(css::rule("em").decl("font-style", "italic"));
+
+    let toks: css::Tokens = quote!($rule);
+
+    assert_eq!(
+        vec![
+            ":is(h1, h2) a, :is(h1, h2).lead, p a, p.lead {",
+            "    color: red;",
+            "}",
+            "",
+            ":is(h1, h2) em, p em {",
+            "    font-style: italic;",
+            "}",
+        ],
+        toks.to_file_vec()?
+    );
+
+    assert_eq!(
+        vec![
+            ":is(h1, h2), p {",
+            "    a, &.lead {",
+            "        color: red;",
+            "    }",
+            "",
+            "    em {",
+            "        font-style: italic;",
+            "    }",
+            "}",
+        ],
+        scss(&toks)?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_import_order() -> fmt::Result {
+    let toks: css::Tokens = quote! {
+        $(css::import("b.css"))
+        $(css::rule("p").decl("margin", "0"))
+
+        $(css::import("a.css"))
+        $(css::import("b.css"))
+        $(css::rule("a").decl("content", quoted("→")))
+    };
+
+    assert_eq!(
+        vec![
+            "@import \"b.css\";",
+            "@import \"a.css\";",
+            "",
+            "p {",
+            "    margin: 0;",
+            "}",
+            "",
+            "a {",
+            "    content: \"→\";",
+            "}",
+        ],
+        toks.to_file_vec()?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_comments() -> fmt::Result {
+    use genco::lang::Lang;
+    use genco::tokens::region;
+
+    let toks: css::Tokens = quote! {
+        $(region("Theme", css::rule("a").decl("color", "red")))
+    };
+
+    assert_eq!(
+        vec![
+            "/* #region Theme */",
+            "a {",
+            "    color: red;",
+            "}",
+            "/* #endregion */"
+        ],
+        toks.to_file_vec()?
+    );
+
+    assert!(Css::capabilities().block_comments);
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use genco::fmt;
+use genco::prelude::*;
+
+#[test]
+fn test_nullable_keeps_import() -> fmt::Result {
+    let a = dart::import("package:app/a.dart", "A");
+    let b = dart::import("package:app/b.dart", "B").with_alias("b");
+
+    let toks: dart::Tokens = quote! {
+        $(a.nullable()) a;
+        $(dart::nullable(b)) b;
+    };
+
+    assert_eq!(
+        vec![
+            "import \"package:app/a.dart\";",
+            "import \"package:app/b.dart\" as b;",
+            "",
+            "A? a;",
+            "b.B? b;",
+        ],
+        toks.to_file_vec()?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_late_nullable_field() -> fmt::Result {
+    let a = dart::import("package:app/a.dart", "A");
+
+    let toks: dart::Tokens = quote!($(dart::field(a.nullable(), "a").late()));
+
+    assert_eq!(
+        vec!["import \"package:app/a.dart\";", "", "late A? a;"],
+        toks.to_file_vec()?
+    );
+
+    Ok(())
+}
@@ -0,0 +1,72 @@
+use genco::fmt;
+use genco::prelude::*;
+
+struct Greeter;
+
+impl std::fmt::Display for Greeter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let tokens: java::Tokens = quote! {
+            class Greeter {
+                void greet() {}
+            }
+        };
+
+        write!(f, "// header")?;
+        f.write_str("\n")?;
+
+        let mut w = fmt::FmtWriter::new(f);
+        let fmt = fmt::Config::from_lang::<Java>();
+        tokens.format_file(&mut w.as_formatter(&fmt), &java::Config::default())?;
+        Ok(())
+    }
+}
+
+#[test]
+fn test_format_into_string() -> fmt::Result {
+    let tokens: js::Tokens = quote! {
+        function foo() {
+            return 1;
+        }
+    };
+
+    let mut w = fmt::FmtWriter::new(String::from("// prefix\n"));
+    let fmt = fmt::Config::from_lang::<JavaScript>();
+    tokens.format_file(&mut w.as_formatter(&fmt), &js::Config::default())?;
+
+    assert_eq!(
+        "// prefix\nfunction foo() {\n    return 1;\n}\n",
+        w.into_inner()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_format_into_display() {
+    assert_eq!(
+        "// header\nclass Greeter {\n    void greet() {}\n}\n",
+        Greeter.to_string()
+    );
+}
+
+#[test]
+fn test_without_trailing_newline() -> fmt::Result {
+    let list = java::import("java.util", "List");
+    let tokens: java::Tokens = quote!($list<String> a;);
+
+    let fmt = fmt::Config::from_lang::<Java>()
+        .with_line_ending(fmt::LineEnding::CrLf)
+        .with_trailing_newline(false);
+
+    let mut w = fmt::FmtWriter::new(String::new());
+    tokens.format_file(&mut w.as_formatter(&fmt), &java::Config::default())?;
+    assert_eq!(
+        "import java.util.List;\r\n\r\nList<String> a;",
+        w.into_inner()
+    );
+
+    let mut w = fmt::VecWriter::new();
+    tokens.format_file(&mut w.as_formatter(&fmt), &java::Config::default())?;
+    assert_eq!(tokens.to_file_vec()?, w.into_vec());
+    Ok(())
+}
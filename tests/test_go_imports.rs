@@ -0,0 +1,106 @@
+use genco::fmt;
+use genco::prelude::*;
+
+fn format(tokens: &go::Tokens) -> fmt::Result<Vec<String>> {
+    let mut w = fmt::VecWriter::new();
+    let fmt = fmt::Config::from_lang::<Go>();
+    let config = go::Config::default().with_import_grouping(true);
+    tokens.format_file(&mut w.as_formatter(&fmt), &config)?;
+    Ok(w.into_vec())
+}
+
+#[test]
+fn test_only_external_imports() -> fmt::Result {
+    let a = go::import("github.com/pkg/errors", "New");
+    let b = go::import("example.com/fmt", "Sprintf");
+
+    let tokens: go::Tokens = quote!($a $b);
+
+    assert_eq!(
+        vec![
+            "import (",
+            "    \"example.com/fmt\"",
+            "    \"github.com/pkg/errors\"",
+            ")",
+            "",
+            "errors.New fmt.Sprintf",
+        ],
+        format(&tokens)?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_only_std_imports() -> fmt::Result {
+    let a = go::import("fmt", "Println");
+    let b = go::import("net/http", "Get");
+
+    let tokens: go::Tokens = quote!($a $b);
+
+    assert_eq!(
+        vec![
+            "import (",
+            "    \"fmt\"",
+            "    \"net/http\"",
+            ")",
+            "",
+            "fmt.Println http.Get",
+        ],
+        format(&tokens)?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_grouped_aliased_imports() -> fmt::Result {
+    let a = go::import("fmt", "Println");
+    let b = go::import("example.com/api/v1", "Thing").with_alias("apiv1");
+    let c = go::import("example.com/other/v1", "Thing");
+
+    let tokens: go::Tokens = quote!($a $b $c);
+
+    assert_eq!(
+        vec![
+            "import (",
+            "    \"fmt\"",
+            "",
+            "    apiv1 \"example.com/api/v1\"",
+            "    \"example.com/other/v1\"",
+            ")",
+            "",
+            "fmt.Println apiv1.Thing v1.Thing",
+        ],
+        format(&tokens)?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_grouped_blank_imports() -> fmt::Result {
+    let open = go::import("database/sql", "Open");
+
+    let tokens: go::Tokens = quote! {
+        $(register(go::blank_import("github.com/lib/pq")))
+        $(register(go::blank_import("embed")))
+        $open
+    };
+
+    assert_eq!(
+        vec![
+            "import (",
+            "    \"database/sql\"",
+            "    _ \"embed\"",
+            "",
+            "    _ \"github.com/lib/pq\"",
+            ")",
+            "",
+            "sql.Open",
+        ],
+        format(&tokens)?
+    );
+
+    Ok(())
+}
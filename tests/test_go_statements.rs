@@ -0,0 +1,116 @@
+use genco::fmt;
+use genco::prelude::*;
+
+#[test]
+fn test_keyword_spacing() -> fmt::Result {
+    // Calls starting with a language item or a quoted string must still be
+    // separated from the keyword by exactly one space.
+    let close = go::import("io", "Closer");
+
+    let tokens: go::Tokens = quote! {
+        $(go::defer(&close))
+        $(go::defer(quote!($[' ']$[' ']run())))
+        $(go::go_stmt(quote!($(quoted("a")))))
+        x := 1; $(go::go_stmt(quote!(f())))
+    };
+
+    assert_eq!(
+        vec![
+            "defer io.Closer",
+            "defer run()",
+            "go \"a\"",
+            "x := 1; go f()",
+        ],
+        tokens.to_vec()?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_var_block_aliased_types() -> fmt::Result {
+    let reader = go::import("io", "Reader").with_alias("stdio");
+
+    let tokens: go::Tokens = quote! {
+        $(go::var_block([
+            ("r", Some(quote!($reader)), Some(quote!(nil))),
+            ("count", Some(quote!(int)), Some(quote!(0))),
+        ]))
+
+        $(go::var_block(Vec::<(&str, Option<&str>, Option<&str>)>::new()))
+    };
+
+    assert_eq!(
+        vec![
+            "import stdio \"io\"",
+            "",
+            "var (",
+            "    r     stdio.Reader = nil",
+            "    count int          = 0",
+            ")",
+            "",
+            "var ()",
+        ],
+        tokens.to_file_vec()?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_composite_types() -> genco::fmt::Result {
+    use genco::lang::go::ChanDir;
+
+    let bar = go::import("foo", "Bar");
+
+    let tokens: go::Tokens = quote! {
+        func run(in $(go::channel(ChanDir::Recv, go::fixed_array(2, go::pointer(&bar))))) $(go::pointer(bar))
+    };
+
+    assert_eq!(
+        vec![
+            "import \"foo\"",
+            "",
+            "func run(in <-chan [2]*foo.Bar) *foo.Bar"
+        ],
+        tokens.to_file_vec()?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_struct_tag_escapes() -> fmt::Result {
+    let tag = go::struct_tag([("sep", "a\tb\\c"), ("empty", "")]);
+    let tokens: go::Tokens = quote!(Field string $tag);
+
+    assert_eq!(
+        "Field string `sep:\"a\\tb\\\\c\" empty:\"\"`",
+        tokens.to_string()?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_func_type() -> genco::fmt::Result {
+    let reader = go::import("io", "Reader");
+    let callback = go::func_type(["int"], Vec::<go::Tokens>::new());
+
+    let toks: go::Tokens = quote! {
+        var open $(go::func_type(["string"], [quote!($reader), quote!(error)]))
+        var each $(go::func_type([callback], ["bool"]))
+    };
+
+    assert_eq!(
+        vec![
+            "import \"io\"",
+            "",
+            "var open func(string) (io.Reader, error)",
+            "var each func(func(int)) bool",
+        ],
+        toks.to_file_vec()?
+    );
+
+    Ok(())
+}
@@ -0,0 +1,145 @@
+use genco::fmt;
+use genco::prelude::*;
+
+#[test]
+fn test_text_escaping() -> fmt::Result {
+    let toks: html::Tokens = quote!(<p>$(html::text("a & b < c > d \"e\" 'f'"))</p>);
+
+    assert_eq!(
+        "<p>a &amp; b &lt; c &gt; d \"e\" 'f'</p>",
+        toks.to_string()?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_attribute_escaping() -> fmt::Result {
+    let toks: html::Tokens = quote!(<a title=$(quoted("a & b < c > d \"e\" 'f'"))></a>);
+
+    assert_eq!(
+        "<a title=\"a &amp; b &lt; c &gt; d &quot;e&quot; &#39;f&#39;\"></a>",
+        toks.to_string()?
+    );
+
+    let element = html::element("a")
+        .attr("title", "\"quoted\" & 'single'")
+        .child(html::text("<link>"));
+
+    let toks: html::Tokens = quote!($element);
+
+    assert_eq!(
+        "<a title=\"&quot;quoted&quot; &amp; &#39;single&#39;\">&lt;link&gt;</a>",
+        toks.to_string()?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_void_elements() -> fmt::Result {
+    let toks: html::Tokens = quote! {
+        $(html::element("br"))
+        $(html::element("IMG").attr("src", "a.png").child(html::text("ignored")))
+        $(html::element("p").child(html::element("hr")))
+        $(html::element("div"))
+    };
+
+    assert_eq!(
+        vec!["<br>", "<IMG src=\"a.png\">", "<p><hr></p>", "<div></div>",],
+        toks.to_file_vec()?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_nested_elements() -> fmt::Result {
+    let form = html::element("form")
+        .attr("action", "/search?q=a&b")
+        .child(html::element("label").child(html::text("Query:")))
+        .child(html::element("input").attr("name", "q").flag("required"))
+        .child(
+            html::element("div")
+                .child(html::element("button").child(html::text("Go")))
+                .child(
+                    html::element("button")
+                        .flag("disabled")
+                        .child(html::text("Stop")),
+                ),
+        );
+
+    let toks: html::Tokens = quote!($form);
+
+    assert_eq!(
+        vec![
+            "<form action=\"/search?q=a&amp;b\">",
+            "  <label>Query:</label>",
+            "  <input name=\"q\" required>",
+            "  <div>",
+            "    <button>Go</button>",
+            "    <button disabled>Stop</button>",
+            "  </div>",
+            "</form>",
+        ],
+        toks.to_file_vec()?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_comments() -> fmt::Result {
+    use genco::lang::Lang;
+    use genco::tokens::{line_comment, region};
+
+    let toks: html::Tokens = quote! {
+        $(line_comment(["generated"]))
+        $(region("Links", quote!(<a></a>)))
+    };
+
+    assert_eq!(
+        vec![
+            "<!-- generated -->",
+            "<!-- #region Links -->",
+            "<a></a>",
+            "<!-- #endregion -->",
+        ],
+        toks.to_file_vec()?
+    );
+
+    assert!(Html::capabilities().block_comments);
+    Ok(())
+}
+
+#[test]
+fn test_invalid_names() {
+    for (name, character) in [
+        ("a b", ' '),
+        ("a\"", '"'),
+        ("a'", '\''),
+        ("a>", '>'),
+        ("a/", '/'),
+        ("a=", '='),
+    ] {
+        let toks: html::Tokens = quote!($(html::element(name)));
+
+        assert_eq!(
+            Err(fmt::Error::UnsupportedChar {
+                character,
+                index: 1
+            }),
+            toks.to_string()
+        );
+
+        let toks: html::Tokens = quote!($(html::element("p").flag(name)));
+
+        assert_eq!(
+            Err(fmt::Error::UnsupportedChar {
+                character,
+                index: 1
+            }),
+            toks.to_string()
+        );
+    }
+}
@@ -34,3 +34,122 @@ fn test_indentation_rules() -> genco::fmt::Result {
 
     Ok(())
 }
+
+#[test]
+fn test_crlf_line_endings() -> genco::fmt::Result {
+    use genco::fmt;
+
+    let tokens: Tokens = quote! {
+        fn foo() {
+            a();
+
+            b();
+        }
+    };
+
+    let fmt = fmt::Config::from_lang::<()>().with_line_ending(fmt::LineEnding::CrLf);
+
+    let mut w = fmt::VecWriter::new();
+    tokens.format_file(&mut w.as_formatter(&fmt), &())?;
+    assert_eq!(
+        vec!["fn foo() {", "    a();", "", "    b();", "}"],
+        w.into_vec()
+    );
+
+    let mut w = fmt::FmtWriter::new(String::new());
+    tokens.format_file(&mut w.as_formatter(&fmt), &())?;
+    assert_eq!(
+        "fn foo() {\r\n    a();\r\n\r\n    b();\r\n}\r\n",
+        w.into_inner()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_tab_indentation() -> genco::fmt::Result {
+    use genco::fmt;
+
+    // NB: the last level is indented by two spaces. Every nesting level is
+    // rendered as exactly one tab, regardless of its width in the template.
+    let tokens: Tokens<()> = quote! {
+    all: build
+        cargo build
+        cargo test
+          echo done
+    clean:
+        cargo clean
+    };
+
+    let fmt = fmt::Config::from_lang::<()>().with_indentation(fmt::Indentation::Tab);
+    let mut w = fmt::VecWriter::new();
+    tokens.format(&mut w.as_formatter(&fmt), &(), &())?;
+
+    assert_eq!(
+        vec![
+            "all: build",
+            "\tcargo build",
+            "\tcargo test",
+            "\t\techo done",
+            "clean:",
+            "\tcargo clean",
+        ],
+        w.into_vec()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_minify() -> genco::fmt::Result {
+    use genco::fmt;
+    use genco::tokens::{region, soft_break};
+
+    let tokens: js::Tokens = quote! {
+        const a = [1,$(soft_break())2];
+
+
+        $(region("Generated", quote! {
+            function b() {
+                return $(quoted("multi\nline"));
+            }
+        }))
+        c();
+    };
+
+    let fmt = fmt::Config::from_lang::<JavaScript>()
+        .with_minify(true)
+        .with_max_width(10);
+
+    let mut w = fmt::FmtWriter::new(String::new());
+    tokens.format_file(&mut w.as_formatter(&fmt), &js::Config::default())?;
+
+    assert_eq!(
+        "const a = [1, 2]; function b() { return \"multi\\nline\"; } c();\n",
+        w.into_inner()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_max_blank_lines() -> genco::fmt::Result {
+    use genco::fmt;
+
+    let mut tokens = rust::Tokens::new();
+    tokens.append_verbatim("\n\nfirst\n\n\n\nsecond\n\n");
+
+    let render = |fmt: &fmt::Config| -> fmt::Result<Vec<String>> {
+        let mut w = fmt::VecWriter::new();
+        tokens.format_file(&mut w.as_formatter(fmt), &rust::Config::default())?;
+        Ok(w.into_vec())
+    };
+
+    let fmt = fmt::Config::from_lang::<Rust>();
+    assert_eq!(vec!["first", "", "", "", "second"], render(&fmt)?);
+
+    let fmt = fmt.with_max_blank_lines(1);
+    assert_eq!(vec!["first", "", "second"], render(&fmt)?);
+
+    Ok(())
+}
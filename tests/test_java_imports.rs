@@ -0,0 +1,61 @@
+use genco::fmt;
+use genco::prelude::*;
+
+#[test]
+fn test_only_static_imports() -> fmt::Result {
+    let assert_equals = java::static_import("org.junit.Assert", "assertEquals");
+
+    let tokens: java::Tokens = quote!($assert_equals(1, 1););
+
+    assert_eq!(
+        vec![
+            "import static org.junit.Assert.assertEquals;",
+            "",
+            "assertEquals(1, 1);",
+        ],
+        tokens.to_file_vec()?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_static_import_conflicting_with_type() -> fmt::Result {
+    let ty = java::import("com.example", "Status");
+    let member = java::static_import("com.example.Codes", "Status");
+
+    let tokens: java::Tokens = quote!($ty s = $member;);
+
+    assert_eq!(
+        vec![
+            "import com.example.Status;",
+            "",
+            "Status s = com.example.Codes.Status;",
+        ],
+        tokens.to_file_vec()?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_annotation_import() -> fmt::Result {
+    let nullable = java::import("javax.annotation", "Nullable");
+
+    let tokens: java::Tokens = quote! {
+        $(java::annotation(&nullable)) String a;
+        $(java::annotation(nullable).with_argument("reason", quoted("legacy"))) String b;
+    };
+
+    assert_eq!(
+        vec![
+            "import javax.annotation.Nullable;",
+            "",
+            "@Nullable String a;",
+            "@Nullable(reason = \"legacy\") String b;",
+        ],
+        tokens.to_file_vec()?
+    );
+
+    Ok(())
+}
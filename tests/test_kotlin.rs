@@ -0,0 +1,76 @@
+use genco::fmt;
+use genco::prelude::*;
+
+#[test]
+fn test_import_deduplication() -> fmt::Result {
+    let a = kotlin::import("java.time", "Instant");
+    let b = kotlin::import("java.time", "Instant");
+    let c = kotlin::import("java.time", "Duration");
+    let string = kotlin::import("kotlin", "String");
+
+    let tokens: kotlin::Tokens = quote! {
+        fun since(start: $(&a), end: $b): $(&c) = $c.between(start, end)
+        fun describe(at: $a): $string = at.toString()
+    };
+
+    assert_eq!(
+        vec![
+            "import java.time.Duration",
+            "import java.time.Instant",
+            "",
+            "fun since(start: Instant, end: Instant): Duration = Duration.between(start, end)",
+            "fun describe(at: Instant): String = at.toString()",
+        ],
+        tokens.to_file_vec()?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_aliased_and_plain_import() -> fmt::Result {
+    let plain = kotlin::import("com.example", "Node");
+    let aliased = kotlin::import("com.example", "Node").with_alias("ExampleNode");
+
+    let tokens: kotlin::Tokens = quote!($plain $aliased);
+
+    assert_eq!(
+        vec![
+            "import com.example.Node",
+            "import com.example.Node as ExampleNode",
+            "",
+            "Node ExampleNode",
+        ],
+        tokens.to_file_vec()?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_data_class() -> fmt::Result {
+    let empty = kotlin::data_class("Empty", Vec::<(&str, kotlin::Tokens)>::new());
+    let a = kotlin::import("com.example.a", "Node");
+
+    let tokens: kotlin::Tokens = quote! {
+        $empty
+
+        $(kotlin::data_class("Edge", [("from", &a), ("to", &a)]))
+    };
+
+    assert_eq!(
+        vec![
+            "import com.example.a.Node",
+            "",
+            "data class Empty()",
+            "",
+            "data class Edge(",
+            "    val from: Node,",
+            "    val to: Node,",
+            ")",
+        ],
+        tokens.to_file_vec()?
+    );
+
+    Ok(())
+}
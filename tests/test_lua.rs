@@ -0,0 +1,75 @@
+use genco::fmt;
+use genco::prelude::*;
+use genco::tokens::raw_quoted;
+
+#[test]
+fn test_require_dedup() -> fmt::Result {
+    let a = lua::require("pl.path");
+    let b = lua::require("pl.path");
+    let c = lua::require("pl.path").with_alias("plpath");
+
+    let tokens: lua::Tokens = quote! {
+        $a.join($b.currentdir(), $c.sep)
+    };
+
+    assert_eq!(
+        vec![
+            "local path = require(\"pl.path\")",
+            "local plpath = require(\"pl.path\")",
+            "",
+            "path.join(path.currentdir(), plpath.sep)",
+        ],
+        tokens.to_file_vec()?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_long_brackets() -> fmt::Result {
+    let cases = [
+        ("a]]b", "[=[a]]b]=]"),
+        ("a]=]b", "[[a]=]b]]"),
+        ("ends]", "[=[ends]]=]"),
+        ("ends]=", "[[ends]=]]"),
+        ("\nleading", "\"\\nleading\""),
+        ("crlf\r\n", "\"crlf\\r\\n\""),
+    ];
+
+    for (input, expected) in cases {
+        let tokens: lua::Tokens = quote!($(raw_quoted(input)));
+        assert_eq!(expected, tokens.to_string()?);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_require_local_collisions() -> fmt::Result {
+    let a = lua::require("a.url");
+    let b = lua::require("b.url");
+    let c = lua::require("c.fs").with_alias("path");
+    let d = lua::require("pl.path");
+
+    let tokens: lua::Tokens = quote! {
+        $(&a).parse($(&b).escape(s))
+        $c.dir($d.sep)
+        $b.unescape(s)
+    };
+
+    assert_eq!(
+        vec![
+            "local url = require(\"a.url\")",
+            "local b_url = require(\"b.url\")",
+            "local path = require(\"c.fs\")",
+            "local pl_path = require(\"pl.path\")",
+            "",
+            "url.parse(b_url.escape(s))",
+            "path.dir(pl_path.sep)",
+            "b_url.unescape(s)",
+        ],
+        tokens.to_file_vec()?
+    );
+
+    Ok(())
+}
@@ -0,0 +1,32 @@
+use genco::prelude::*;
+
+#[test]
+fn test_recipe_lines() -> genco::fmt::Result {
+    let install = make::target(
+        "install",
+        ["build"],
+        quote! {
+            if [ -d build ]; then
+                cp -r build $$(PREFIX)
+            fi
+
+            @echo $(quoted("installed to $(PREFIX)"))
+        },
+    );
+
+    let tokens: make::Tokens = quote!($install);
+
+    assert_eq!(
+        vec![
+            "install: build",
+            "\tif [ -d build ]; then",
+            "\t\tcp -r build $(PREFIX)",
+            "\tfi",
+            "",
+            "\t@echo 'installed to $$(PREFIX)'",
+        ],
+        tokens.to_file_vec()?
+    );
+
+    Ok(())
+}
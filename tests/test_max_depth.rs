@@ -0,0 +1,45 @@
+use genco::fmt;
+use genco::prelude::*;
+
+genco::impl_lang! {
+    Nesting {
+        type Config = ();
+        type Format = ();
+        type Item = Nested;
+    }
+
+    Nested {
+        fn format(&self, out: &mut fmt::Formatter<'_>, config: &(), format: &()) -> fmt::Result {
+            let mut tokens = Tokens::<Nesting>::new();
+
+            match self.depth.checked_sub(1) {
+                Some(depth) => tokens.append(Nested { depth }),
+                None => tokens.append("leaf"),
+            }
+
+            tokens.format(out, config, format)
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+struct Nested {
+    depth: usize,
+}
+
+fn format(tokens: &Tokens<Nesting>, max_depth: usize) -> fmt::Result<Vec<String>> {
+    let fmt = fmt::Config::from_lang::<Nesting>().with_max_depth(max_depth);
+    let mut w = fmt::VecWriter::new();
+    tokens.format_file(&mut w.as_formatter(&fmt), &())?;
+    Ok(w.into_vec())
+}
+
+#[test]
+fn test_self_nesting_lang_item() -> fmt::Result {
+    let tokens: Tokens<Nesting> = quote!($(Nested { depth: 2 }));
+    assert_eq!(vec!["leaf"], format(&tokens, 3)?);
+
+    let tokens: Tokens<Nesting> = quote!($(Nested { depth: 3 }));
+    assert_eq!(Err(fmt::Error::DepthExceeded), format(&tokens, 3));
+    Ok(())
+}
@@ -0,0 +1,59 @@
+use genco::fmt;
+use genco::prelude::*;
+
+#[test]
+fn test_grouped_from_imports() -> fmt::Result {
+    let list = python::import("typing", "List");
+    let dict = python::import("typing", "Dict");
+    let os = python::import_module("os");
+    let path = python::import("os", "path");
+
+    let tokens: python::Tokens = quote! {
+        def f(a: $list[int]) -> $dict[str, int]:
+            return $os.environ, $path
+    };
+
+    assert_eq!(
+        vec![
+            "from os import path",
+            "from typing import Dict, List",
+            "import os",
+            "",
+            "def f(a: List[int]) -> Dict[str, int]:",
+            "    return os.environ, path",
+        ],
+        tokens.to_file_vec()?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_same_name_from_different_modules() -> fmt::Result {
+    let a = python::import("a", "Node");
+    let b = python::import("b", "Node");
+    let c = python::import("b", "Other");
+
+    let tokens: python::Tokens = quote!($(&b) $a $b $c);
+
+    assert_eq!(
+        vec![
+            "from a import Node",
+            "from b import Other",
+            "import b",
+            "",
+            "b.Node Node b.Node Other",
+        ],
+        tokens.to_file_vec()?
+    );
+
+    let tokens: python::Tokens =
+        quote!($(python::import("a", "X").with_alias("Y")) $(python::import("b", "Y")));
+
+    assert_eq!(
+        vec!["from a import X as Y", "import b", "", "Y b.Y"],
+        tokens.to_file_vec()?
+    );
+
+    Ok(())
+}
@@ -64,6 +64,52 @@ fn test_escape() -> genco::fmt::Result {
     Ok(())
 }
 
+#[test]
+fn test_empty_conditional_spacing() -> genco::fmt::Result {
+    let a = false;
+    let b = true;
+
+    let tokens: rust::Tokens = quote! {
+        $(if a => pub) $(if a { const } else { static }) $(if a => mut) X: u32 = 0;
+        fn foo($(if b => x: u32)$(if a => , y: u32)) {}
+    };
+
+    assert_eq!(
+        vec!["static X: u32 = 0;", "fn foo(x: u32) {}"],
+        tokens.to_file_vec()?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_literal_hash() -> genco::fmt::Result {
+    let tokens: rust::Tokens = quote! {
+        #[derive(Debug)]
+        #![allow(unused)]
+        struct Foo;
+    };
+
+    assert_eq!(
+        vec!["#[derive(Debug)]", "#![allow(unused)]", "struct Foo;"],
+        tokens.to_file_vec()?
+    );
+
+    let tokens: c::Tokens = quote! {
+        #include <stdio.h>
+        #define ANSWER 42
+    };
+
+    assert_eq!(
+        vec!["#include <stdio.h>", "#define ANSWER 42"],
+        tokens.to_file_vec()?
+    );
+
+    let tokens: python::Tokens = quote!(x = 1 # comment);
+    assert_eq!("x = 1 # comment", tokens.to_string()?);
+    Ok(())
+}
+
 #[test]
 fn test_scope() -> genco::fmt::Result {
     let tokens: rust::Tokens = quote! {
@@ -77,3 +123,51 @@ fn test_scope() -> genco::fmt::Result {
 
     Ok(())
 }
+
+#[test]
+fn test_indexed_interpolation() -> genco::fmt::Result {
+    let fields = ["a", "b", "c"];
+    let values = [1u32, 2, 3];
+
+    let tokens: rust::Tokens = quote! {
+        $(for i in 0..fields.len() join ($['\r']) => self.$(fields[i]) = $(values[i]);)
+    };
+
+    assert_eq!(
+        vec!["self.a = 1;", "self.b = 2;", "self.c = 3;"],
+        tokens.to_vec()?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_join_element_counts() -> genco::fmt::Result {
+    fn list(items: &[&str]) -> rust::Tokens {
+        quote!([$(for x in items join (, ) => $(*x))])
+    }
+
+    fn statements(items: &[&str]) -> rust::Tokens {
+        quote! {
+            start();
+            $(for x in items join ($['\r']) => $(*x)();)
+            end();
+        }
+    }
+
+    assert_eq!("[]", list(&[]).to_string()?);
+    assert_eq!("[a]", list(&["a"]).to_string()?);
+    assert_eq!("[a, b, c]", list(&["a", "b", "c"]).to_string()?);
+
+    assert_eq!(vec!["start();", "end();"], statements(&[]).to_vec()?);
+    assert_eq!(
+        vec!["start();", "a();", "end();"],
+        statements(&["a"]).to_vec()?
+    );
+    assert_eq!(
+        vec!["start();", "a();", "b();", "end();"],
+        statements(&["a", "b"]).to_vec()?
+    );
+
+    Ok(())
+}
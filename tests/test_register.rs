@@ -28,3 +28,28 @@ fn test_register() -> genco::fmt::Result {
 
     Ok(())
 }
+
+#[test]
+fn test_register_js_side_effects() -> genco::fmt::Result {
+    let styles = js::import_side_effect("./styles.css");
+    let react = js::import_default("react", "React");
+
+    let tokens: js::Tokens = quote! {
+        $(register(styles.clone()))
+        $(register(js::import_side_effect("react")))
+        $(register(styles))
+        $react
+    };
+
+    assert_eq!(
+        vec![
+            "import \"./styles.css\";",
+            "import React from \"react\";",
+            "",
+            "React",
+        ],
+        tokens.to_file_vec()?
+    );
+
+    Ok(())
+}
@@ -0,0 +1,124 @@
+use genco::fmt;
+use genco::prelude::*;
+
+#[test]
+fn test_function_collision() -> fmt::Result {
+    let a = rust::import("std::fs", "read_to_string");
+    let b = rust::import("std::io", "read_to_string");
+
+    let tokens: rust::Tokens = quote!($a(); $b(););
+
+    assert_eq!(
+        vec![
+            "use std::fs::read_to_string;",
+            "use std::io::read_to_string as io_read_to_string;",
+            "",
+            "read_to_string(); io_read_to_string();",
+        ],
+        tokens.to_file_vec()?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_qualified_does_not_collide() -> fmt::Result {
+    let a = rust::import("std::fmt", "Result");
+    let b = rust::import("std::io", "Result").qualified();
+
+    let tokens: rust::Tokens = quote!($a $b);
+
+    assert_eq!(
+        vec![
+            "use std::fmt::Result;",
+            "use std::io;",
+            "",
+            "Result io::Result",
+        ],
+        tokens.to_file_vec()?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_snake_case_module_prefix() -> fmt::Result {
+    let a = rust::import("crate::wire_format", "Message");
+    let b = rust::import("crate::model", "Message");
+
+    let tokens: rust::Tokens = quote!($a $b);
+
+    assert_eq!(
+        vec![
+            "use crate::model::Message;",
+            "use crate::wire_format::Message as WireFormatMessage;",
+            "",
+            "WireFormatMessage Message",
+        ],
+        tokens.to_file_vec()?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_derive_imports() -> fmt::Result {
+    let serialize = rust::import("serde", "Serialize");
+    let other = rust::import("other", "Serialize");
+
+    let tokens: rust::Tokens = quote! {
+        $(rust::attr_derive([quote!(Debug), quote!($(&serialize))]))
+        struct A;
+
+        impl $serialize for B {}
+
+        $(rust::attr_derive([other]))
+        struct C;
+    };
+
+    assert_eq!(
+        vec![
+            "use other::Serialize;",
+            "use serde::Serialize as SerdeSerialize;",
+            "",
+            "#[derive(Debug, SerdeSerialize)]",
+            "struct A;",
+            "",
+            "impl SerdeSerialize for B {}",
+            "",
+            "#[derive(Serialize)]",
+            "struct C;",
+        ],
+        tokens.to_file_vec()?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_macro_call_imports() -> fmt::Result {
+    use genco::lang::rust::Delimiter;
+
+    let log = rust::import("log", "info");
+    let tracing = rust::import("tracing", "info");
+
+    let tokens: rust::Tokens = quote! {
+        $(rust::macro_call(log, Delimiter::Paren, quote!($(quoted("a")))));
+        $(rust::macro_call(tracing, Delimiter::Paren, quote!($(quoted("b")))));
+        $(rust::macro_call("empty", Delimiter::Brace, rust::Tokens::new()))
+    };
+
+    assert_eq!(
+        vec![
+            "use log::info;",
+            "use tracing::info as tracing_info;",
+            "",
+            "info!(\"a\");",
+            "tracing_info!(\"b\");",
+            "empty! {}",
+        ],
+        tokens.to_file_vec()?
+    );
+
+    Ok(())
+}
@@ -0,0 +1,141 @@
+use genco::fmt;
+use genco::prelude::*;
+
+fn format(tokens: &rust::Tokens, max_width: usize) -> fmt::Result<Vec<String>> {
+    let mut w = fmt::VecWriter::new();
+    let fmt = fmt::Config::from_lang::<Rust>().with_max_width(max_width);
+    tokens.format_file(&mut w.as_formatter(&fmt), &rust::Config::default())?;
+    Ok(w.into_vec())
+}
+
+#[test]
+fn test_use_group_fits() -> fmt::Result {
+    let a = rust::import("crate::model", "Alpha");
+    let b = rust::import("crate::model", "Beta");
+    let c = rust::import("crate::model", "Gamma").with_alias("G");
+
+    let tokens: rust::Tokens = quote!($a $b $c);
+
+    // `use crate::model::{Alpha, Beta, Gamma as G};` is exactly 44 characters.
+    assert_eq!(
+        vec![
+            "use crate::model::{Alpha, Beta, Gamma as G};",
+            "",
+            "Alpha Beta G",
+        ],
+        format(&tokens, 44)?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_use_group_overflows() -> fmt::Result {
+    let a = rust::import("crate::model", "Alpha");
+    let b = rust::import("crate::model", "Beta");
+    let c = rust::import("crate::model", "Gamma").with_alias("G");
+
+    let tokens: rust::Tokens = quote!($a $b $c);
+
+    assert_eq!(
+        vec![
+            "use crate::model::{",
+            "    Alpha,",
+            "    Beta,",
+            "    Gamma as G,",
+            "};",
+            "",
+            "Alpha Beta G",
+        ],
+        format(&tokens, 43)?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_use_group_self_import() -> fmt::Result {
+    let a = rust::import("std::fmt", "Debug");
+    let b = rust::import("std::fmt", "Display").qualified();
+
+    let tokens: rust::Tokens = quote!($(&a) $b);
+
+    assert_eq!(
+        vec![
+            "use std::fmt::{",
+            "    self,",
+            "    Debug,",
+            "};",
+            "",
+            "Debug fmt::Display",
+        ],
+        format(&tokens, 20)?
+    );
+
+    // Single imports are never wrapped.
+    let tokens: rust::Tokens = quote!($a);
+    assert_eq!(
+        vec!["use std::fmt::Debug;", "", "Debug"],
+        format(&tokens, 10)?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_editions() -> fmt::Result {
+    let ser = rust::import("serde", "Serialize");
+    let to_string = rust::import("serde_json", "to_string").qualified();
+    let debug = rust::import("std::fmt", "Debug").qualified();
+    let vec = rust::import("alloc::vec", "Vec");
+    let helper = rust::import("crate", "helper").qualified();
+
+    let tokens: rust::Tokens = quote! {
+        #[derive($ser, $debug)]
+        struct Foo($vec<u8>);
+        $to_string(&foo);
+        $helper();
+    };
+
+    let format = |edition| -> fmt::Result<Vec<String>> {
+        let mut w = fmt::VecWriter::new();
+        let fmt = fmt::Config::from_lang::<Rust>();
+        let config = rust::Config::default().with_edition(edition);
+        tokens.format_file(&mut w.as_formatter(&fmt), &config)?;
+        Ok(w.into_vec())
+    };
+
+    assert_eq!(
+        vec![
+            "extern crate alloc;",
+            "extern crate serde;",
+            "extern crate serde_json;",
+            "",
+            "use alloc::vec::Vec;",
+            "use serde::Serialize;",
+            "use std::fmt;",
+            "",
+            "#[derive(Serialize, fmt::Debug)]",
+            "struct Foo(Vec<u8>);",
+            "::serde_json::to_string(&foo);",
+            "crate::helper();",
+        ],
+        format(rust::Edition::Rust2015)?
+    );
+
+    assert_eq!(
+        vec![
+            "use alloc::vec::Vec;",
+            "use serde::Serialize;",
+            "use std::fmt;",
+            "",
+            "#[derive(Serialize, fmt::Debug)]",
+            "struct Foo(Vec<u8>);",
+            "serde_json::to_string(&foo);",
+            "crate::helper();",
+        ],
+        format(rust::Edition::Rust2018)?
+    );
+
+    Ok(())
+}
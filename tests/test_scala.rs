@@ -0,0 +1,53 @@
+use genco::fmt;
+use genco::prelude::*;
+
+#[test]
+fn test_grouped_imports() -> fmt::Result {
+    let a = scala::import("a.b", "C");
+    let b = scala::import("a.b", "D");
+    let c = scala::import("a.b", "E").with_alias("F");
+    let d = scala::import("x.y", "C");
+
+    let tokens: scala::Tokens = quote! {
+        val values: Seq[$a] = Seq($b, $c, $d)
+    };
+
+    assert_eq!(
+        vec![
+            "import a.b.{C, D, E => F}",
+            "",
+            "val values: Seq[C] = Seq(D, F, x.y.C)",
+        ],
+        tokens.to_file_vec()?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_package_imports() -> fmt::Result {
+    let local = scala::import("com.example", "Local");
+    let string = scala::import("java.lang", "String");
+    let renamed = scala::import("java.lang", "String").with_alias("JString");
+
+    let tokens: scala::Tokens = quote!($local $string $renamed);
+
+    let config = scala::Config::default().with_package("com.example");
+    let fmt = fmt::Config::from_lang::<Scala>();
+
+    let mut w = fmt::VecWriter::new();
+    tokens.format_file(&mut w.as_formatter(&fmt), &config)?;
+
+    assert_eq!(
+        vec![
+            "package com.example",
+            "",
+            "import java.lang.{String => JString}",
+            "",
+            "Local String JString",
+        ],
+        w.into_vec()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,67 @@
+#![cfg(feature = "serde")]
+
+use genco::fmt;
+use genco::prelude::*;
+
+#[test]
+fn test_roundtrip() -> Result<(), Box<dyn std::error::Error>> {
+    let tokens: Tokens = quote! {
+        fn hello() {
+            println!($(quoted("Hello World")));
+        }
+    };
+
+    let json = serde_json::to_string(&tokens)?;
+    let restored: Tokens = serde_json::from_str(&json)?;
+
+    assert_eq!(
+        vec!["fn hello() {", "    println!(\"Hello World\");", "}"],
+        restored.to_vec()?
+    );
+    assert_eq!(tokens.to_vec()?, restored.to_vec()?);
+
+    Ok(())
+}
+
+#[test]
+fn test_lang_items_error() -> fmt::Result {
+    let map = rust::import("std::collections", "HashMap");
+    let tokens: rust::Tokens = quote!(let m = $map::new(););
+
+    assert!(serde_json::to_string(&tokens).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_roundtrip_layout() -> Result<(), Box<dyn std::error::Error>> {
+    use genco::tokens::{region, soft_break, SourceLocation};
+
+    let mut tokens = python::Tokens::new();
+
+    tokens.append_with_source(
+        SourceLocation::new("input.idl", 3),
+        quote! {
+            def foo(a,$(soft_break())b):
+                $(region("Body", quote! {
+                    return $(quoted("it's $a"))
+                }))
+        },
+    );
+
+    let json = serde_json::to_string(&tokens)?;
+    let restored: python::Tokens = serde_json::from_str(&json)?;
+
+    assert_eq!(tokens.to_file_vec()?, restored.to_file_vec()?);
+    Ok(())
+}
+
+#[test]
+fn test_register_and_deferred_error() {
+    let mut tokens = java::Tokens::new();
+    tokens.register(java::import("java.util", "List"));
+    assert!(serde_json::to_string(&tokens).is_err());
+
+    let deferred = genco::tokens::with_config(|_: &java::Config| java::Tokens::new());
+    let tokens: java::Tokens = quote!($deferred);
+    assert!(serde_json::to_string(&tokens).is_err());
+}
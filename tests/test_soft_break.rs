@@ -0,0 +1,85 @@
+use genco::fmt;
+use genco::prelude::*;
+use genco::tokens::soft_break;
+
+fn format(tokens: &rust::Tokens, max_width: usize) -> fmt::Result<Vec<String>> {
+    let mut w = fmt::VecWriter::new();
+    let fmt = fmt::Config::from_lang::<Rust>().with_max_width(max_width);
+    tokens.format_file(&mut w.as_formatter(&fmt), &rust::Config::default())?;
+    Ok(w.into_vec())
+}
+
+#[test]
+fn test_soft_break_indented() -> fmt::Result {
+    let args = ["first", "second", "third", "fourth"];
+
+    let tokens: rust::Tokens = quote! {
+        fn main() {
+            call($(for a in args join (,$(soft_break())) => $a));
+            done();
+        }
+    };
+
+    assert_eq!(
+        vec![
+            "fn main() {",
+            "    call(first, second,",
+            "        third, fourth);",
+            "    done();",
+            "}",
+        ],
+        format(&tokens, 24)?
+    );
+
+    assert_eq!(
+        vec![
+            "fn main() {",
+            "    call(first, second, third, fourth);",
+            "    done();",
+            "}",
+        ],
+        format(&tokens, 80)?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_soft_break_overflowing_token() -> fmt::Result {
+    let tokens: rust::Tokens = quote!(a($(soft_break())a_very_long_argument_name, b));
+
+    assert_eq!(
+        vec!["a(", "    a_very_long_argument_name, b)"],
+        format(&tokens, 10)?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_soft_break_collapses_spaces() -> fmt::Result {
+    let tokens: rust::Tokens = quote!(a $(soft_break()) b);
+    assert_eq!("a b", tokens.to_string()?);
+    assert_eq!(vec!["a", "    b"], format(&tokens, 2)?);
+    Ok(())
+}
+
+#[test]
+fn test_soft_break_literal_tab_width() -> fmt::Result {
+    // "a\tb" spans 5 columns with tab stops every 4 columns, and 9 columns
+    // with tab stops every 8 columns.
+    let tokens: rust::Tokens = quote!($("a\tb"),$(soft_break())c);
+
+    let render = |tab_width| -> fmt::Result<Vec<String>> {
+        let mut w = fmt::VecWriter::new();
+        let fmt = fmt::Config::from_lang::<Rust>()
+            .with_max_width(8)
+            .with_tab_width(tab_width);
+        tokens.format_file(&mut w.as_formatter(&fmt), &rust::Config::default())?;
+        Ok(w.into_vec())
+    };
+
+    assert_eq!(vec!["a\tb, c"], render(4)?);
+    assert_eq!(vec!["a\tb,", "    c"], render(8)?);
+    Ok(())
+}
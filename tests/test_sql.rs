@@ -0,0 +1,50 @@
+use genco::fmt;
+use genco::prelude::*;
+
+fn format(tokens: &sql::Tokens, dialect: sql::Dialect) -> fmt::Result<Vec<String>> {
+    let config = sql::Config::default().with_dialect(dialect);
+    let fmt = fmt::Config::from_lang::<Sql>();
+
+    let mut w = fmt::VecWriter::new();
+    tokens.format_file(&mut w.as_formatter(&fmt), &config)?;
+    Ok(w.into_vec())
+}
+
+#[test]
+fn test_ident_quoting() -> fmt::Result {
+    let tokens: sql::Tokens = quote! {
+        $(sql::ident("a\"b")) $(sql::ident("a`b")) $(sql::ident("a]b")) $(sql::ident("a[b"))
+    };
+
+    assert_eq!(
+        vec!["\"a\"\"b\" \"a`b\" \"a]b\" \"a[b\""],
+        format(&tokens, sql::Dialect::Standard)?
+    );
+
+    assert_eq!(
+        vec!["`a\"b` `a``b` `a]b` `a[b`"],
+        format(&tokens, sql::Dialect::MySql)?
+    );
+
+    assert_eq!(
+        vec!["[a\"b] [a`b] [a]]b] [a[b]"],
+        format(&tokens, sql::Dialect::SqlServer)?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_string_quoting() -> fmt::Result {
+    let tokens: sql::Tokens = quote! {
+        INSERT INTO $(sql::ident("notes")) VALUES ($(quoted("it's")), $(quoted("''")), $(quoted("a\\b")));
+    };
+
+    let expected = vec!["INSERT INTO `notes` VALUES ('it''s', '''''', 'a\\\\b');"];
+    assert_eq!(expected, format(&tokens, sql::Dialect::MySql)?);
+
+    let expected = vec!["INSERT INTO \"notes\" VALUES ('it''s', '''''', 'a\\b');"];
+    assert_eq!(expected, format(&tokens, sql::Dialect::Standard)?);
+
+    Ok(())
+}
@@ -1,3 +1,4 @@
+use genco::fmt;
 use genco::prelude::*;
 
 #[test]
@@ -25,3 +26,125 @@ fn test_string_in_string_in() -> genco::fmt::Result {
     assert_eq!("`Hello ${`${\"World\"}`}`", t.to_string()?);
     Ok(())
 }
+
+#[test]
+fn test_python_f_string() -> genco::fmt::Result {
+    let t: python::Tokens = quote!($[str](Hello $(World)));
+    assert_eq!("f\"Hello {World}\"", t.to_string()?);
+
+    let t: python::Tokens = quote!($[str](Hello { World }));
+    assert_eq!("\"Hello { World }\"", t.to_string()?);
+
+    let t: python::Tokens = quote!($[str]({ x } $(y)));
+    assert_eq!("f\"{{ x }} {y}\"", t.to_string()?);
+
+    let t: python::Tokens = quote!($[str](Hello $($(quoted("World")))));
+    assert_eq!("f\"Hello {'World'}\"", t.to_string()?);
+
+    let t: python::Tokens = quote!($[str](Hello $($(quoted("a\\b")))));
+    assert_eq!(
+        Err(genco::fmt::Error::UnsupportedChar {
+            character: '\\',
+            index: 1
+        }),
+        t.to_string()
+    );
+
+    let mut w = genco::fmt::VecWriter::new();
+    let fmt = genco::fmt::Config::from_lang::<Python>();
+    let config = python::Config::default().with_version(3, 12);
+    t.format_file(&mut w.as_formatter(&fmt), &config)?;
+    assert_eq!(vec!["f\"Hello {\"a\\\\b\"}\""], w.into_vec());
+    Ok(())
+}
+
+#[test]
+fn test_rust_raw_quoted() -> genco::fmt::Result {
+    let t: rust::Tokens = quote!($(rust::raw_quoted("path\\to\\file")));
+    assert_eq!("r\"path\\to\\file\"", t.to_string()?);
+
+    let t: rust::Tokens = quote!($(rust::raw_quoted("a \"# b")));
+    assert_eq!("r##\"a \"# b\"##", t.to_string()?);
+
+    let t: rust::Tokens = quote!($(rust::raw_quoted("a \"## b \"#")));
+    assert_eq!("r###\"a \"## b \"#\"###", t.to_string()?);
+
+    let t: rust::Tokens = quote!($(rust::raw_quoted("first\nsecond")));
+    assert_eq!("r\"first\nsecond\"", t.to_string()?);
+
+    let t: rust::Tokens = quote!($(rust::raw_quoted("carriage\r\nreturn")));
+    assert_eq!("\"carriage\\r\\nreturn\"", t.to_string()?);
+    Ok(())
+}
+
+#[test]
+fn test_swift_string_interpolation() -> genco::fmt::Result {
+    let t: swift::Tokens = quote!($[str](Hello $(name) $[const]("\\(x)")));
+    assert_eq!("\"Hello \\(name) \\\\(x)\"", t.to_string()?);
+
+    let t: swift::Tokens = quote!($[str](Hello $($(quoted("World")))));
+    assert_eq!("\"Hello \\(\"World\")\"", t.to_string()?);
+    Ok(())
+}
+
+#[test]
+fn test_ruby_quote_style() -> genco::fmt::Result {
+    let t: ruby::Tokens = quote!($(quoted("it's #{x}\n")));
+    assert_eq!("'it\\'s #{x}\n'", t.to_string()?);
+
+    let fmt = fmt::Config::from_lang::<Ruby>().with_quote_style(fmt::QuoteStyle::Double);
+    let mut w = fmt::VecWriter::new();
+    t.format(
+        &mut w.as_formatter(&fmt),
+        &ruby::Config::default(),
+        &ruby::Format::default(),
+    )?;
+    assert_eq!(vec!["\"it's \\#{x}\\n\""], w.into_vec());
+
+    let t: ruby::Tokens = quote!($[str](Hello $($(quoted("World")))));
+    assert_eq!("\"Hello #{'World'}\"", t.to_string()?);
+    Ok(())
+}
+
+#[test]
+fn test_format_args() -> genco::fmt::Result {
+    use genco::tokens::{Item, ItemStr};
+
+    let (a, b) = ("<a>", "\"b\"");
+    let t: js::Tokens = quote!($(format_args!("{a}{b}")) $(quoted(format!("{a}{b}"))));
+    assert_eq!("<a>\"b\" \"<a>\\\"b\\\"\"", t.to_string()?);
+
+    let t: js::Tokens = quote!($(format_args!("static")));
+    assert!(matches!(
+        t.iter().next(),
+        Some(Item::Literal(ItemStr::Static("static")))
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn test_concat_strings_empty() -> fmt::Result {
+    use genco::tokens::concat_strings;
+
+    let t: c::Tokens = quote!(puts($(concat_strings(Vec::<String>::new()))););
+    assert_eq!("puts(\"\");", t.to_string()?);
+
+    let t: java::Tokens = quote!(print($(concat_strings(Vec::<String>::new()))););
+    assert_eq!("print(\"\");", t.to_string()?);
+    Ok(())
+}
+
+#[test]
+fn test_to_file_vec_multiline_literal() -> fmt::Result {
+    let tokens: rust::Tokens = quote!(let s = $(rust::raw_quoted("a\nb")););
+
+    let fmt = fmt::Config::from_lang::<Rust>();
+    let mut w = fmt::VecWriter::new();
+    tokens.format_file(&mut w.as_formatter(&fmt), &rust::Config::default())?;
+
+    let expected = vec!["let s = r\"a\nb\";"];
+    assert_eq!(expected, w.into_vec());
+    assert_eq!(expected, tokens.to_file_vec()?);
+    Ok(())
+}
@@ -632,3 +632,67 @@ fn test_lines() -> fmt::Result {
 
     Ok(())
 }
+
+#[test]
+fn test_prepend() -> fmt::Result {
+    let hash_map = rust::import("std::collections", "HashMap");
+    let debug = rust::import("std::fmt", "Debug");
+
+    let mut tokens: rust::Tokens = quote!(let m = $hash_map::new(););
+    tokens.prepend(quote!(let d: &dyn $debug = &m;));
+
+    assert_eq!(2, tokens.walk_imports().count());
+
+    let mut tokens: rust::Tokens = quote!(a);
+    tokens.push();
+    tokens.prepend(quote!(b$['\n']));
+
+    assert_eq!(
+        vec![Literal(Static("b")), Line, Literal(Static("a")), Push],
+        tokens
+    );
+    Ok(())
+}
+
+#[test]
+fn test_deferred_send_sync() {
+    fn assert_send_sync<T: Send + Sync>(_: &T) {}
+
+    let a: rust::Tokens = quote!($(genco::tokens::with_config(|_: &rust::Config| quote!(a))));
+    let b: rust::Tokens = quote!($(genco::tokens::with_config(|_: &rust::Config| quote!(b))));
+
+    assert_send_sync(&a);
+    assert_eq!(a, b);
+    assert_eq!(std::cmp::Ordering::Equal, a.cmp(&b));
+}
+
+#[test]
+fn test_append_verbatim_lines() -> fmt::Result {
+    let mut body = rust::Tokens::new();
+    body.append("a();");
+    body.append_verbatim("first\n\n\n  \nlast\t\n");
+    body.append("b();");
+
+    let tokens: rust::Tokens = quote! {
+        fn main() {
+            $body
+        }
+    };
+
+    assert_eq!(
+        vec![
+            "fn main() {",
+            "    a();",
+            "    first",
+            "",
+            "",
+            "      ",
+            "    last\t",
+            "    b();",
+            "}",
+        ],
+        tokens.to_file_vec()?
+    );
+
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use genco::fmt;
+use genco::prelude::*;
+use genco::tokens::{topo_order, Declaration};
+
+#[test]
+fn test_topo_order_without_cycles() -> fmt::Result {
+    let declarations = vec![
+        Declaration::new(quote!(typedef struct { b_t b; } a_t;))
+            .defines("a_t")
+            .depends_on("b_t")
+            .depends_on("uint32_t"),
+        Declaration::new(quote!(typedef struct { c_t c; } b_t;))
+            .defines("b_t")
+            .depends_on("c_t"),
+        Declaration::new(quote!(typedef int c_t;)).defines("c_t"),
+        Declaration::new(quote!(typedef int d_t;)).defines("d_t"),
+    ];
+
+    let mut forwarded = Vec::new();
+
+    let tokens: c::Tokens = quote! {
+        $(topo_order(declarations, |name, _| forwarded.push(name.to_string())))
+    };
+
+    assert!(forwarded.is_empty());
+
+    assert_eq!(
+        vec![
+            "typedef int c_t;",
+            "",
+            "typedef struct { c_t c; } b_t;",
+            "",
+            "typedef struct { b_t b; } a_t;",
+            "",
+            "typedef int d_t;",
+        ],
+        tokens.to_file_vec()?
+    );
+
+    Ok(())
+}
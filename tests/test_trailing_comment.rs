@@ -0,0 +1,161 @@
+use genco::fmt;
+use genco::prelude::*;
+use genco::tokens::{line_comment, region, trailing_comment};
+
+#[test]
+fn test_code_after_trailing_comment() -> fmt::Result {
+    let mut tokens = rust::Tokens::new();
+    tokens.append(trailing_comment(quote!(let x = 5;), "explanation"));
+    tokens.append("let y = 6;");
+
+    assert_eq!(
+        vec!["let x = 5; // explanation", "let y = 6;"],
+        tokens.to_file_vec()?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_trailing_comment_line_breaks() -> fmt::Result {
+    let tokens: java::Tokens = quote! {
+        $(trailing_comment(quote!(int x = 5;), "\r\n first \r\n\n second \n"))
+        $(trailing_comment(quote!(int y = 6;), "\n"))
+    };
+
+    assert_eq!(
+        vec!["int x = 5; // first second", "int y = 6; //"],
+        tokens.to_file_vec()?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_line_comment_prefixes() -> fmt::Result {
+    let tokens: sql::Tokens = quote! {
+        $(line_comment(["up", "down"]))
+        SELECT 1;
+    };
+
+    assert_eq!(vec!["-- up", "-- down", "SELECT 1;"], tokens.to_file_vec()?);
+
+    let tokens: java::Tokens = quote! {
+        class A {
+            $(line_comment(vec![String::from("a\r\nb")]))
+        }
+    };
+
+    assert_eq!(
+        vec!["class A {", "    // a", "    // b", "}"],
+        tokens.to_file_vec()?
+    );
+
+    Ok(())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+struct Blocks;
+
+impl genco::lang::Lang for Blocks {
+    type Config = ();
+    type Format = ();
+    type Item = ();
+
+    fn line_comment_prefix() -> Option<&'static str> {
+        None
+    }
+
+    fn block_comment_delimiters() -> Option<(&'static str, &'static str)> {
+        Some(("/*", "*/"))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+struct Uncommented;
+
+impl genco::lang::Lang for Uncommented {
+    type Config = ();
+    type Format = ();
+    type Item = ();
+
+    fn line_comment_prefix() -> Option<&'static str> {
+        None
+    }
+}
+
+#[test]
+fn test_block_comment_fallback() -> fmt::Result {
+    let tokens: Tokens<Blocks> = quote! {
+        $(line_comment(["first\n\nsecond"]))
+        $(trailing_comment(quote!(a), "explanation")) b
+        $(region("Generated", quote!(c)))
+    };
+
+    assert_eq!(
+        vec![
+            "/* first */",
+            "/* */",
+            "/* second */",
+            "a /* explanation */ b",
+            "/* #region Generated */",
+            "c",
+            "/* #endregion */",
+        ],
+        tokens.to_file_vec()?
+    );
+
+    let tokens: Tokens<Uncommented> = quote! {
+        $(line_comment(["first"]))
+        $(trailing_comment(quote!(a), "explanation")) b
+        $(region("Generated", quote!(c)))
+    };
+
+    assert_eq!(vec!["a b", "c"], tokens.to_file_vec()?);
+
+    Ok(())
+}
+
+#[test]
+fn test_comments_dropped_when_minified() -> fmt::Result {
+    let tokens: rust::Tokens = quote! {
+        fn main() {
+            $(line_comment(["first", "second"]))
+            $(trailing_comment(quote!(let x = 5;), "explanation"))
+            let y = 6;
+        }
+    };
+
+    let fmt = fmt::Config::from_lang::<Rust>()
+        .with_minify(true)
+        .with_trailing_newline(false);
+
+    let mut w = fmt::FmtWriter::new(String::new());
+    tokens.format_file(&mut w.as_formatter(&fmt), &rust::Config::default())?;
+
+    assert_eq!("fn main() { let x = 5; let y = 6; }", w.into_inner());
+    Ok(())
+}
+
+#[test]
+fn test_editor_fold_label_escaping() -> fmt::Result {
+    let tokens: java::Tokens = quote! {
+        $(region("say \"hi\" & <bye>\nnow", quote!(int a = 1;)))
+    };
+
+    let fmt = fmt::Config::from_lang::<Java>().with_region_style(fmt::RegionStyle::EditorFold);
+
+    let mut w = fmt::VecWriter::new();
+    tokens.format_file(&mut w.as_formatter(&fmt), &java::Config::default())?;
+
+    assert_eq!(
+        vec![
+            "// <editor-fold desc=\"say &quot;hi&quot; &amp; &lt;bye>&#10;now\">",
+            "int a = 1;",
+            "// </editor-fold>",
+        ],
+        w.into_vec()
+    );
+
+    Ok(())
+}
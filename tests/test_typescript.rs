@@ -0,0 +1,76 @@
+use genco::fmt;
+use genco::prelude::*;
+
+#[test]
+fn test_import_deduplication() -> fmt::Result {
+    let a = typescript::import("./model", "User");
+    let b = typescript::import("./model", "User");
+    let c = typescript::import("./model", "Group").into_type_only();
+    let d = typescript::import("./model", "Group").into_type_only();
+
+    let tokens: typescript::Tokens = quote! {
+        const user: $c = new $a();
+        const other: $d = new $b();
+    };
+
+    assert_eq!(
+        vec![
+            "import {User} from \"./model\";",
+            "import type {Group} from \"./model\";",
+            "",
+            "const user: Group = new User();",
+            "const other: Group = new User();",
+        ],
+        tokens.to_file_vec()?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_type_import_shadowed_by_value() -> fmt::Result {
+    let ty = typescript::import("./model", "User").into_type_only();
+    let value = typescript::import("./model", "User");
+
+    let tokens: typescript::Tokens = quote!(const user: $ty = new $value(););
+
+    assert_eq!(
+        vec![
+            "import {User} from \"./model\";",
+            "",
+            "const user: User = new User();",
+        ],
+        tokens.to_file_vec()?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_default_and_named_imports() -> fmt::Result {
+    let react = typescript::import("react", "React").into_default();
+    let use_state = typescript::import("react", "useState");
+    let fc = typescript::import("react", "FC").into_type_only();
+    let express = typescript::import("express", "express").into_default();
+
+    let tokens: typescript::Tokens = quote! {
+        const App: $fc = () => $react.createElement("div");
+        const [count, setCount] = $use_state<Array<number>>([]);
+        const app = $express();
+    };
+
+    assert_eq!(
+        vec![
+            "import express from \"express\";",
+            "import React, {useState} from \"react\";",
+            "import type {FC} from \"react\";",
+            "",
+            "const App: FC = () => React.createElement(\"div\");",
+            "const [count, setCount] = useState<Array<number>>([]);",
+            "const app = express();",
+        ],
+        tokens.to_file_vec()?
+    );
+
+    Ok(())
+}
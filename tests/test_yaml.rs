@@ -0,0 +1,77 @@
+use genco::fmt;
+use genco::prelude::*;
+
+#[test]
+fn test_scalar_quoting() -> fmt::Result {
+    let values = [
+        "plain",
+        "no",
+        "Off",
+        "~",
+        "null",
+        "3.14",
+        ".inf",
+        "-1",
+        "2024-01-01",
+        "- item",
+        "a #b",
+        "a#b",
+        "trailing ",
+        "it's",
+        "tab\there",
+        "über",
+    ];
+
+    let tokens: yaml::Tokens = quote! {
+        $(for v in values => - $(yaml::scalar(v))$['\r'])
+    };
+
+    assert_eq!(
+        vec![
+            "- plain",
+            "- 'no'",
+            "- 'Off'",
+            "- '~'",
+            "- 'null'",
+            "- '3.14'",
+            "- '.inf'",
+            "- '-1'",
+            "- '2024-01-01'",
+            "- '- item'",
+            "- 'a #b'",
+            "- a#b",
+            "- 'trailing '",
+            "- it's",
+            "- \"tab\\there\"",
+            "- über",
+        ],
+        tokens.to_file_vec()?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_block_fallbacks() -> fmt::Result {
+    let tokens: yaml::Tokens = quote! {
+        a: $(yaml::block("one\n\ntwo"))
+        b: $(yaml::block("one\n\n\ntwo"))
+        c: $(yaml::block("trailing\n\n"))
+        d: $(yaml::block(""))
+    };
+
+    assert_eq!(
+        vec![
+            "a: |-",
+            "  one",
+            "",
+            "  two",
+            "b: \"one\\n\\n\\ntwo\"",
+            "c: \"trailing\\n\\n\"",
+            "d: \"\"",
+        ],
+        tokens.to_file_vec()?
+    );
+
+    Ok(())
+}